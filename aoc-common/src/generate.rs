@@ -0,0 +1,112 @@
+//! Generating synthetic puzzle inputs of any size from a seed, to stress the
+//! solvers with more than the single real input of each day.
+
+use crate::Solution;
+use std::ops::RangeInclusive;
+
+/// A solution that can generate valid puzzle inputs.
+pub trait Generate: Solution {
+    /// The size of the author's input, by whatever measure `generate` uses.
+    const SIZE: usize;
+
+    /// Generates a valid puzzle input of roughly the given size, which means
+    /// something different for each day: the number of lines, programs,
+    /// steps and so on. The same seed and size always give the same input.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// A small and fast pseudo random number generator (SplitMix64), so the
+/// inputs generated from a seed never change between versions.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from `0` up to but not including `n`, which must not be 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "can't pick a number below 0");
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+
+    /// A number in the inclusive range.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {}..={}", start, end);
+        let span = (end as i128 - start as i128 + 1) as u128;
+        (start as i128 + ((u128::from(self.next_u64()) * span) >> 64) as i128) as i64
+    }
+
+    /// True one in every `n` times.
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    /// A random item of the slice, which must not be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Shuffles the slice in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    /// A word of lowercase letters, with a length in the range.
+    pub fn word(&mut self, len: RangeInclusive<usize>) -> String {
+        let len = self.range(*len.start() as i64..=*len.end() as i64);
+        (0..len)
+            .map(|_| (b'a' + self.below(26) as u8) as char)
+            .collect()
+    }
+}
+
+/// Generates the input of a solution from a seed.
+pub fn generate<S: Generate>(seed: u64, size: usize) -> String {
+    S::generate(&mut Rng::new(seed), size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let numbers = (0..100).map(|_| a.next_u64()).collect::<Vec<_>>();
+        assert_eq!(numbers, (0..100).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(Rng::new(43).next_u64(), numbers[0]);
+        // The sequence must never change, or the generated inputs would.
+        assert_eq!(Rng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!(rng.below(3) < 3);
+            assert!((-5..=5).contains(&rng.range(-5..=5)));
+            assert!((2..=4).contains(&rng.word(2..=4).len()));
+        }
+        assert_eq!(rng.range(i64::MIN..=i64::MIN), i64::MIN);
+        let mut items = (0..20).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,188 @@
+//! Loading the puzzle input from a file, stdin or the per-user cache
+//! directory.
+
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// Where to read the puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// Read the input from the given path.
+    Path(PathBuf),
+    /// Read the input from stdin.
+    Stdin,
+    /// Read the input from `dayNN.txt` in the cache directory.
+    Cache,
+}
+
+impl InputSource {
+    /// Determines the source from a command line argument: `-` means stdin,
+    /// anything else is a path and no argument means the cache directory.
+    pub fn from_arg<S: AsRef<str>>(arg: Option<S>) -> InputSource {
+        match arg {
+            Some(ref arg) if arg.as_ref() == "-" => InputSource::Stdin,
+            Some(arg) => InputSource::Path(PathBuf::from(arg.as_ref())),
+            None => InputSource::Cache,
+        }
+    }
+}
+
+/// An error while loading the puzzle input.
+#[derive(Debug)]
+pub enum InputError {
+    /// There is no input for the day in the cache directory.
+    Missing { day: u8, path: PathBuf },
+    /// The cache directory could not be determined.
+    NoCacheDir { day: u8 },
+    /// Reading the input failed.
+    Io { source: String, error: io::Error },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            InputError::Missing { day, ref path } => write!(
+                f,
+                "No input for day {}: {} does not exist. Save your puzzle input there, \
+                 or pass the path to it or `-` to read it from stdin.",
+                day,
+                path.display()
+            ),
+            InputError::NoCacheDir { day } => write!(
+                f,
+                "No input for day {}: unable to determine the cache directory. Set \
+                 AOC_CACHE_DIR, or pass the path to the input or `-` to read it from stdin.",
+                day
+            ),
+            InputError::Io {
+                ref source,
+                ref error,
+            } => write!(f, "Unable to read the input from {}: {}", source, error),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+// Determines the cache directory from the environment, looking at
+// `AOC_CACHE_DIR` first and falling back to `~/.cache/aoc/2017`.
+fn cache_dir_from<F: Fn(&str) -> Option<String>>(var: F) -> Option<PathBuf> {
+    if let Some(dir) = var("AOC_CACHE_DIR") {
+        return Some(PathBuf::from(dir));
+    }
+    let cache = match var("XDG_CACHE_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(var("HOME")?).join(".cache"),
+    };
+    Some(cache.join("aoc").join("2017"))
+}
+
+/// The directory holding the cached puzzle inputs, `~/.cache/aoc/2017` unless
+/// overridden by `AOC_CACHE_DIR`.
+pub fn cache_dir() -> Option<PathBuf> {
+    cache_dir_from(|name| env::var(name).ok().filter(|value| !value.is_empty()))
+}
+
+/// The path of the cached input for a given day.
+pub fn cache_path(day: u8) -> Option<PathBuf> {
+    cache_dir().map(|dir| dir.join(format!("day{:02}.txt", day)))
+}
+
+/// Loads the input for a day from the given source.
+pub fn load_input(day: u8, source: &InputSource) -> Result<String, InputError> {
+    match *source {
+        InputSource::Path(ref path) => fs::read_to_string(path).map_err(|error| InputError::Io {
+            source: path.display().to_string(),
+            error,
+        }),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|error| InputError::Io {
+                    source: "stdin".to_owned(),
+                    error,
+                })?;
+            Ok(input)
+        }
+        InputSource::Cache => {
+            let path = cache_path(day).ok_or(InputError::NoCacheDir { day })?;
+            if !path.exists() {
+                return Err(InputError::Missing { day, path });
+            }
+            load_input(day, &InputSource::Path(path))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg(None::<&str>), InputSource::Cache);
+        assert_eq!(InputSource::from_arg(Some("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(Some("input.txt")),
+            InputSource::Path(PathBuf::from("input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_cache_dir_from() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|&&(k, _)| k == name)
+                    .map(|&(_, v)| v.to_owned())
+            }
+        };
+        assert_eq!(
+            cache_dir_from(env(&[("HOME", "/home/aoc")])),
+            Some(PathBuf::from("/home/aoc/.cache/aoc/2017"))
+        );
+        assert_eq!(
+            cache_dir_from(env(&[("HOME", "/home/aoc"), ("XDG_CACHE_HOME", "/cache")])),
+            Some(PathBuf::from("/cache/aoc/2017"))
+        );
+        assert_eq!(
+            cache_dir_from(env(&[("HOME", "/home/aoc"), ("AOC_CACHE_DIR", "/inputs")])),
+            Some(PathBuf::from("/inputs"))
+        );
+        assert_eq!(cache_dir_from(env(&[])), None);
+    }
+
+    #[test]
+    fn test_load_input_from_path() {
+        let path = env::temp_dir().join("aoc-common-test-input.txt");
+        fs::write(&path, "1122").unwrap();
+        let input = load_input(1, &InputSource::Path(path.clone())).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(input, "1122");
+    }
+
+    #[test]
+    fn test_load_input_missing_path() {
+        let path = PathBuf::from("/this/path/does/not/exist.txt");
+        let error = load_input(1, &InputSource::Path(path)).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("Unable to read the input from /this/path/does/not/exist.txt"));
+    }
+
+    #[test]
+    fn test_missing_message() {
+        let error = InputError::Missing {
+            day: 8,
+            path: PathBuf::from("/cache/day08.txt"),
+        };
+        assert!(error
+            .to_string()
+            .starts_with("No input for day 8: /cache/day08.txt does not exist."));
+    }
+}
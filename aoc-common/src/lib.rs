@@ -0,0 +1,26 @@
+//! Shared helpers for the Advent of Code 2017 solutions: the `Solution`
+//! trait, loading the puzzle input, timing each part and reporting the
+//! results, along with generating inputs, visualizing and tracing solvers.
+
+pub mod generate;
+#[cfg(not(target_arch = "wasm32"))]
+mod input;
+pub mod parse;
+#[cfg(not(target_arch = "wasm32"))]
+mod run;
+mod solution;
+pub mod trace;
+pub mod visualize;
+
+pub use generate::{Generate, Rng};
+#[cfg(not(target_arch = "wasm32"))]
+pub use input::{cache_dir, cache_path, load_input, InputError, InputSource};
+pub use parse::ParseError;
+pub use solution::{check_params, param, Answer, Solution};
+pub use trace::{Event, Trace, Tracer};
+pub use visualize::Visualize;
+
+// Everything reading files, stdin or the clock is left out of WebAssembly
+// builds, which solve the input they are given and nothing else.
+#[cfg(not(target_arch = "wasm32"))]
+pub use run::{format_result, get_input, main, run, run_part, timed};
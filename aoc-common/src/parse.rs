@@ -0,0 +1,148 @@
+//! A shared error type for the parsers of each day, pointing at the line and
+//! column of the offending text.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// An error while parsing the puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line of the input, starting at 1.
+    pub line: usize,
+    /// The column on the line, starting at 1.
+    pub column: usize,
+    /// The offending text.
+    pub text: String,
+    /// What was wrong with the text.
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new<T: Into<String>, M: Into<String>>(
+        line: usize,
+        column: usize,
+        text: T,
+        message: M,
+    ) -> ParseError {
+        ParseError {
+            line,
+            column,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}: `{}`",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A single line of the input, knowing its own line number so errors can
+/// point at where in the input they happened.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    /// The line number, starting at 1.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// The column of `token` on the line, starting at 1. `token` should be a
+    /// slice of the line, otherwise it is searched for.
+    pub fn column(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize).wrapping_sub(start);
+        // Distinct strings never overlap, so this only holds for slices.
+        if offset <= self.text.len() && offset + token.len() <= self.text.len() {
+            offset + 1
+        } else {
+            self.text.find(token).map_or(1, |offset| offset + 1)
+        }
+    }
+
+    /// An error pointing at `token` on the line.
+    pub fn error<M: Into<String>>(&self, token: &str, message: M) -> ParseError {
+        ParseError::new(self.number, self.column(token), token, message)
+    }
+
+    /// An error for the whole line.
+    pub fn invalid<M: Into<String>>(&self, message: M) -> ParseError {
+        self.error(self.text, message)
+    }
+
+    /// Parses `token` into a `T`, `what` describes the token in the error.
+    pub fn parse<T: FromStr>(&self, token: &'a str, what: &str) -> Result<T, ParseError> {
+        token
+            .parse()
+            .map_err(|_| self.error(token, format!("invalid {}", what)))
+    }
+
+    /// Splits the line on the first occurrence of `separator`.
+    pub fn split_once(&self, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(separator)
+            .ok_or_else(|| self.invalid(format!("expected `{}`", separator)))
+    }
+}
+
+/// Iterates the lines of the input along with their line numbers.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::new(3, 7, "x", "invalid depth");
+        assert_eq!(error.to_string(), "line 3, column 7: invalid depth: `x`");
+    }
+
+    #[test]
+    fn test_lines() {
+        let lines = lines("a\n\nb").collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[2].number, 3);
+        assert_eq!(lines[2].text, "b");
+    }
+
+    #[test]
+    fn test_parse() {
+        let line = lines("ok\n0: 3\n1: x").nth(2).unwrap();
+        let (depth, range) = line.split_once(": ").unwrap();
+        assert_eq!(line.parse::<u32>(depth, "depth"), Ok(1));
+        assert_eq!(
+            line.parse::<u32>(range, "range"),
+            Err(ParseError::new(3, 4, "x", "invalid range"))
+        );
+    }
+
+    #[test]
+    fn test_split_once() {
+        let line = lines("0 3").next().unwrap();
+        assert_eq!(
+            line.split_once(": "),
+            Err(ParseError::new(1, 1, "0 3", "expected `: `"))
+        );
+    }
+
+    #[test]
+    fn test_column_of_copy() {
+        let line = lines("ab cd").next().unwrap();
+        assert_eq!(line.column(&String::from("cd")), 4);
+    }
+}
@@ -0,0 +1,80 @@
+//! Running a solution on the host: reading the input from a file, stdin or
+//! the cache directory, timing each part and printing the results.
+
+use crate::{load_input, InputSource, ParseError, Solution};
+use std::env;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::Read;
+use std::process;
+use std::time::{Duration, Instant};
+
+/// Reads the puzzle input from `input.txt` in the current directory, which is
+/// where the tests of each day keep the author's input.
+pub fn get_input() -> String {
+    let mut input = String::new();
+    File::open("input.txt")
+        .and_then(|mut file| file.read_to_string(&mut input))
+        .expect("Unable to read input.txt");
+    input
+}
+
+/// Runs `f`, returning the result along with how long it took.
+pub fn timed<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let before = Instant::now();
+    let result = f();
+    (result, before.elapsed())
+}
+
+/// Formats the result of a part the same way for every day.
+pub fn format_result<T: Display>(part: u8, result: T, took: Duration) -> String {
+    format!("part{}: {}\ttook: {:?}", part, result, took)
+}
+
+/// Runs and times a single part of a puzzle, printing the result.
+pub fn run_part<T: Display, F: FnOnce() -> T>(part: u8, f: F) {
+    let (result, took) = timed(f);
+    println!("{}", format_result(part, result, took));
+}
+
+/// Parses the input and runs both parts of a solution, printing the results.
+pub fn run<S: Solution>(input: &str) -> Result<(), ParseError> {
+    let input = S::parse(input)?;
+    run_part(1, || S::part1(&input));
+    run_part(2, || S::part2(&input));
+    Ok(())
+}
+
+/// The entry point of each day's binary. The input is read from the path given
+/// as the first argument, from stdin if that is `-` and from the cache
+/// directory if no argument is given.
+pub fn main<S: Solution>() {
+    let source = InputSource::from_arg(env::args().nth(1));
+    let input = load_input(S::DAY, &source).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    if let Err(e) = run::<S>(&input) {
+        eprintln!("Unable to parse the input for day {}: {}", S::DAY, e);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timed() {
+        let (result, _) = timed(|| 21 * 2);
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    fn test_format_result() {
+        assert_eq!(
+            format_result(1, 1158, Duration::from_millis(3)),
+            "part1: 1158\ttook: 3ms"
+        );
+    }
+}
@@ -0,0 +1,196 @@
+//! The interface implemented by every day.
+
+use crate::ParseError;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// The answer to a single part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// The part has no puzzle to solve, like the second part of day 25.
+    None,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(ref s) => write!(f, "{}", s),
+            Answer::None => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Number(n as i64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl<'a> From<&'a str> for Answer {
+    fn from(s: &'a str) -> Answer {
+        Answer::Text(s.to_owned())
+    }
+}
+
+/// A solution for a single day, split into parsing the input and solving
+/// each of the two parts from the parsed input.
+pub trait Solution {
+    /// The day of the puzzle, from 1 to 25.
+    const DAY: u8;
+
+    /// The parsed puzzle input shared by both parts.
+    type Input;
+
+    /// Parses the raw puzzle input, failing with the position of the first
+    /// malformed part of it.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Solves the first part of the puzzle.
+    fn part1(input: &Self::Input) -> Answer;
+
+    /// Solves the second part of the puzzle.
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Solves the given part, either 1 or 2.
+    fn solve(input: &Self::Input, part: u8) -> Answer {
+        match part {
+            1 => Self::part1(input),
+            2 => Self::part2(input),
+            _ => panic!("Unknown part: {}", part),
+        }
+    }
+
+    /// Solves the given part of a smaller version of the puzzle, like the
+    /// examples of some puzzle texts, changed by the parameters by name. Only
+    /// the days whose examples need it take any parameters.
+    fn solve_with(input: &Self::Input, part: u8, params: &[(&str, i64)]) -> Result<Answer, String> {
+        check_params(params, part, &[])?;
+        Ok(Self::solve(input, part))
+    }
+}
+
+/// Fails on the first of the parameters that isn't one of `known`, the ones
+/// the part takes, for implementing `Solution::solve_with`.
+pub fn check_params(params: &[(&str, i64)], part: u8, known: &[&str]) -> Result<(), String> {
+    match params.iter().find(|(name, _)| !known.contains(name)) {
+        Some((name, _)) => Err(format!("unknown parameter `{}` for part {}", name, part)),
+        None => Ok(()),
+    }
+}
+
+/// The value of the parameter `name`, or `default` if it isn't given, failing
+/// if it is outside `range`.
+pub fn param(
+    params: &[(&str, i64)],
+    name: &str,
+    default: i64,
+    range: RangeInclusive<i64>,
+) -> Result<i64, String> {
+    match params.iter().find(|(key, _)| *key == name) {
+        None => Ok(default),
+        Some(&(_, value)) if range.contains(&value) => Ok(value),
+        Some(&(_, value)) => Err(format!(
+            "`{}` must be from {} to {}, not {}",
+            name,
+            range.start(),
+            range.end(),
+            value
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Example;
+
+    impl Solution for Example {
+        const DAY: u8 = 1;
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+            input
+                .char_indices()
+                .map(|(i, c)| {
+                    c.to_digit(10)
+                        .ok_or_else(|| ParseError::new(1, i + 1, c, "invalid digit"))
+                })
+                .collect()
+        }
+
+        fn part1(input: &Vec<u32>) -> Answer {
+            input.iter().sum::<u32>().into()
+        }
+
+        fn part2(input: &Vec<u32>) -> Answer {
+            format!("{:?}", input).into()
+        }
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(42_usize).to_string(), "42");
+        assert_eq!(Answer::from(-3).to_string(), "-3");
+        assert_eq!(Answer::from("hmvwl").to_string(), "hmvwl");
+        assert_eq!(Answer::None.to_string(), "-");
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = Example::parse("1234").unwrap();
+        assert_eq!(Example::solve(&input, 1), Answer::Number(10));
+        assert_eq!(Example::solve(&input, 2), Answer::from("[1, 2, 3, 4]"));
+    }
+
+    #[test]
+    fn test_solve_with() {
+        let input = Example::parse("1234").unwrap();
+        assert_eq!(Example::solve_with(&input, 1, &[]), Ok(Answer::Number(10)));
+        assert_eq!(
+            Example::solve_with(&input, 2, &[("size", 5)]),
+            Err("unknown parameter `size` for part 2".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_param() {
+        let params = [("size", 5), ("iterations", 0)];
+        assert_eq!(check_params(&params, 1, &["iterations", "size"]), Ok(()));
+        assert_eq!(
+            check_params(&params, 1, &["size"]),
+            Err("unknown parameter `iterations` for part 1".to_owned())
+        );
+        assert_eq!(param(&params, "size", 256, 2..=256), Ok(5));
+        assert_eq!(param(&params, "programs", 16, 1..=16), Ok(16));
+        assert_eq!(
+            param(&params, "iterations", 5, 1..=18),
+            Err("`iterations` must be from 1 to 18, not 0".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Example::parse("12x4").unwrap_err(),
+            ParseError::new(1, 3, "x", "invalid digit")
+        );
+    }
+}
@@ -0,0 +1,149 @@
+//! Following how a solver reaches its answer, as a stream of structured
+//! events for each step it takes, which the runner can dump to compare two
+//! inputs or two implementations.
+
+use crate::{Answer, Solution};
+
+/// The value of a field of an event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Number(i64),
+    Text(String),
+    List(Vec<Value>),
+}
+
+macro_rules! number_values {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Value {
+                fn from(value: $t) -> Value {
+                    Value::Number(value as i64)
+                }
+            }
+        )*
+    };
+}
+
+number_values!(i32, i64, u8, u32, u64, usize);
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Value {
+        Value::Text(value.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Value {
+        Value::Text(value)
+    }
+}
+
+impl<T: Clone + Into<Value>> From<&[T]> for Value {
+    fn from(values: &[T]) -> Value {
+        Value::List(values.iter().cloned().map(Into::into).collect())
+    }
+}
+
+/// A single step of a solver, like `set` with the register and its new value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub name: &'static str,
+    pub fields: Vec<(&'static str, Value)>,
+}
+
+impl Event {
+    pub fn new(name: &'static str) -> Event {
+        Event {
+            name,
+            fields: vec![],
+        }
+    }
+
+    /// Adds a field to the event.
+    pub fn with(mut self, key: &'static str, value: impl Into<Value>) -> Event {
+        self.fields.push((key, value.into()));
+        self
+    }
+
+    /// The value of a field, if the event has it.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.fields.iter().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+}
+
+/// Receives the events of a solver, in the order they happen.
+pub trait Tracer {
+    fn event(&mut self, event: Event);
+
+    /// Whether the events are wanted at all, so that the solvers can skip
+    /// building them in their hot loops when they aren't.
+    fn enabled(&self) -> bool {
+        true
+    }
+}
+
+impl<F: FnMut(Event)> Tracer for F {
+    fn event(&mut self, event: Event) {
+        self(event)
+    }
+}
+
+/// Collects the events, mostly for tests.
+impl Tracer for Vec<Event> {
+    fn event(&mut self, event: Event) {
+        self.push(event)
+    }
+}
+
+/// Ignores the events, for solving without tracing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoTrace;
+
+impl Tracer for NoTrace {
+    fn event(&mut self, _: Event) {}
+
+    fn enabled(&self) -> bool {
+        false
+    }
+}
+
+/// A solution that can report the steps it takes.
+pub trait Trace: Solution {
+    /// Solves a part like `part1` and `part2`, passing each step to the
+    /// tracer. Parts without any steps worth reporting are solved without
+    /// events.
+    fn trace(input: &Self::Input, part: u8, tracer: &mut dyn Tracer) -> Answer;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event() {
+        let event = Event::new("set")
+            .with("register", "a")
+            .with("value", -3)
+            .with("banks", &[1u32, 2][..]);
+        assert_eq!(event.get("register"), Some(&Value::Text("a".to_owned())));
+        assert_eq!(event.get("value"), Some(&Value::Number(-3)));
+        assert_eq!(
+            event.get("banks"),
+            Some(&Value::List(vec![Value::Number(1), Value::Number(2)]))
+        );
+        assert_eq!(event.get("missing"), None);
+    }
+
+    #[test]
+    fn test_tracers() {
+        let mut events = vec![];
+        events.event(Event::new("a"));
+        let mut names = vec![];
+        let mut tracer = |event: Event| names.push(event.name);
+        tracer.event(Event::new("b"));
+        NoTrace.event(Event::new("c"));
+        assert!(events.enabled() && tracer.enabled() && !NoTrace.enabled());
+        assert_eq!(events, [Event::new("a")]);
+        assert_eq!(names, ["b"]);
+    }
+}
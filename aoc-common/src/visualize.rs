@@ -0,0 +1,51 @@
+//! Animating the days that simulate a process step by step, a frame of text
+//! per step, for the runner to draw in the terminal.
+
+use crate::Solution;
+use std::fmt::Display;
+
+/// A solution whose simulation can be watched step by step.
+pub trait Visualize: Solution {
+    /// The frames of the simulation, starting with the state before the first
+    /// step. Frames are lines of text, which may be styled with ANSI escapes
+    /// like [`highlight`]. The frames end with the simulation, which can be
+    /// very long.
+    fn frames(input: &Self::Input) -> Box<dyn Iterator<Item = String> + '_>;
+}
+
+/// Draws the text in bold red, for the part of the frame that just changed.
+pub fn highlight<T: Display>(text: T) -> String {
+    format!("\x1b[1;31m{}\x1b[0m", text)
+}
+
+/// Draws the text faint, for the parts of the frame in the background.
+pub fn faint<T: Display>(text: T) -> String {
+    format!("\x1b[2m{}\x1b[0m", text)
+}
+
+/// Removes the ANSI escapes from a frame, leaving the plain text.
+pub fn strip_styles(frame: &str) -> String {
+    let mut result = String::with_capacity(frame.len());
+    let mut chars = frame.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip the escape up to and including the final letter.
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_styles() {
+        assert_eq!(highlight(5), "\x1b[1;31m5\x1b[0m");
+        let frame = format!("[{}] {}", highlight('@'), faint("."));
+        assert_eq!(strip_styles(&frame), "[@] .");
+    }
+}
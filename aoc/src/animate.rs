@@ -0,0 +1,115 @@
+//! Drawing the frames of a simulation in the terminal, one over the other.
+
+use crate::days::Day;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+/// How to play a simulation.
+#[derive(Debug, Clone, Copy)]
+pub struct Playback {
+    /// How long to show each frame.
+    pub delay: Duration,
+    /// Stop after this many frames.
+    pub steps: Option<u64>,
+}
+
+// Draws a frame over the previous one, clearing the screen before the first
+// frame and whatever is left of the previous frame after each line.
+fn draw(out: &mut impl Write, frame: &str, first: bool) -> io::Result<()> {
+    if first {
+        write!(out, "\x1b[2J")?;
+    }
+    write!(out, "\x1b[H")?;
+    for line in frame.lines() {
+        writeln!(out, "{}\x1b[K", line)?;
+    }
+    write!(out, "\x1b[J")?;
+    out.flush()
+}
+
+/// Plays the simulation of a day on the input, returning the number of
+/// frames drawn.
+pub fn play(
+    day: &Day,
+    input: &str,
+    playback: Playback,
+    out: &mut impl Write,
+) -> Result<u64, String> {
+    let visualize = day
+        .visualize
+        .ok_or_else(|| format!("Day {} has no visualization", day.day))?;
+    let mut drawn = 0;
+    let mut error = None;
+    visualize(input, &mut |frame| {
+        if let Err(e) = draw(out, &frame, drawn == 0) {
+            error = Some(e);
+            return false;
+        }
+        drawn += 1;
+        if playback.steps.is_some_and(|steps| drawn >= steps) {
+            return false;
+        }
+        thread::sleep(playback.delay);
+        true
+    })
+    .map_err(|e| crate::parse_error(day, e))?;
+    match error {
+        Some(e) => Err(format!("Unable to draw day {}: {}", day.day, e)),
+        None => Ok(drawn),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    fn play_to_string(day: u8, input: &str, steps: Option<u64>) -> Result<(u64, String), String> {
+        let playback = Playback {
+            delay: Duration::ZERO,
+            steps,
+        };
+        let mut out = vec![];
+        let drawn = play(days::get(day).unwrap(), input, playback, &mut out)?;
+        Ok((drawn, String::from_utf8(out).unwrap()))
+    }
+
+    #[test]
+    fn test_draw() {
+        let mut out = vec![];
+        draw(&mut out, "ab\nc\n", true).unwrap();
+        draw(&mut out, "d\n", false).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[2J\x1b[Hab\x1b[K\nc\x1b[K\n\x1b[J\x1b[Hd\x1b[K\n\x1b[J"
+        );
+    }
+
+    #[test]
+    fn test_play() {
+        let (drawn, out) = play_to_string(3, "10", None).unwrap();
+        assert_eq!(drawn, 10);
+        assert_eq!(out.matches("\x1b[H").count(), 10);
+        assert!(out.contains("Square 10 at (2, 1)"));
+    }
+
+    #[test]
+    fn test_play_steps() {
+        let (drawn, out) = play_to_string(3, "10", Some(4)).unwrap();
+        assert_eq!(drawn, 4);
+        assert!(out.contains("Square 4 at") && !out.contains("Square 5 at"));
+    }
+
+    #[test]
+    fn test_play_errors() {
+        assert_eq!(
+            play_to_string(1, "1122", None).unwrap_err(),
+            "Day 1 has no visualization"
+        );
+        assert_eq!(
+            play_to_string(3, "x", None).unwrap_err(),
+            "Unable to parse the input for day 3: line 1, column 1: invalid square: `x`"
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! Checking the computed answers against a file of known answers.
+//!
+//! The file is TOML with a table per day and a key per part:
+//!
+//! ```toml
+//! [day01]
+//! part1 = 1158
+//! part2 = 1132
+//!
+//! [day10]
+//! part2 = "96de9657665675b51cd03f0b3528ba26"
+//! ```
+
+use aoc_common::Answer;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use toml::{Table, Value};
+
+/// How a computed answer compares to the known answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    /// The answer is wrong, along with the known answer.
+    Wrong(String),
+    /// There is no known answer.
+    Unknown,
+}
+
+impl Status {
+    /// The status without the known answer, for machine-readable output.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Status::Correct => "correct",
+            Status::Wrong(_) => "wrong",
+            Status::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Status::Wrong(ref expected) => write!(f, "wrong (expected {})", expected),
+            ref status => write!(f, "{}", status.name()),
+        }
+    }
+}
+
+/// The known answers, keyed by day and part.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+// Parses a key like `day07` or `part2` into its number, from 1 to `max`.
+fn parse_key(key: &str, prefix: &str, max: u8) -> Option<u8> {
+    let n = key.strip_prefix(prefix)?.parse().ok()?;
+    if (1..=max).contains(&n) {
+        Some(n)
+    } else {
+        None
+    }
+}
+
+/// A known answer, which is either a number or a string, as it is printed.
+pub fn expected(value: &Value) -> Option<String> {
+    match *value {
+        Value::Integer(n) => Some(n.to_string()),
+        Value::String(ref s) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+impl Answers {
+    /// Parses the answers from TOML.
+    pub fn parse(content: &str) -> Result<Answers, String> {
+        let table = content.parse::<Table>().map_err(|e| e.to_string())?;
+        let mut answers = BTreeMap::new();
+        for (day_key, parts) in &table {
+            let day = parse_key(day_key, "day", 25)
+                .ok_or_else(|| format!("Unknown day `{}`, expected `day01` to `day25`", day_key))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("Expected a table of parts for `{}`", day_key))?;
+            for (part_key, answer) in parts {
+                let part = parse_key(part_key, "part", 2).ok_or_else(|| {
+                    format!(
+                        "Unknown part `{}.{}`, expected `part1` or `part2`",
+                        day_key, part_key
+                    )
+                })?;
+                let answer = expected(answer).ok_or_else(|| {
+                    format!(
+                        "Expected a number or a string for `{}.{}`",
+                        day_key, part_key
+                    )
+                })?;
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(Answers { answers })
+    }
+
+    /// Loads the answers from a file.
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Unable to read {}: {}", path.display(), e))?;
+        Answers::parse(&content).map_err(|e| format!("Invalid answers {}: {}", path.display(), e))
+    }
+
+    /// Checks an answer against the known answer for the day and part.
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Status {
+        match self.answers.get(&(day, part)) {
+            Some(expected) if *expected == answer.to_string() => Status::Correct,
+            Some(expected) => Status::Wrong(expected.clone()),
+            None => Status::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[day08]
+part1 = 5075
+
+[day10]
+part2 = "96de9657665675b51cd03f0b3528ba26"
+"#;
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.check(8, 1, &Answer::Number(5075)), Status::Correct);
+        assert_eq!(
+            answers.check(8, 1, &Answer::Number(42)),
+            Status::Wrong("5075".to_owned())
+        );
+        assert_eq!(answers.check(8, 2, &Answer::Number(7310)), Status::Unknown);
+        assert_eq!(
+            answers.check(10, 2, &Answer::from("96de9657665675b51cd03f0b3528ba26")),
+            Status::Correct
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Status::Correct.to_string(), "correct");
+        assert_eq!(
+            Status::Wrong("5075".to_owned()).to_string(),
+            "wrong (expected 5075)"
+        );
+        assert_eq!(Status::Unknown.to_string(), "unknown");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Answers::parse("[day26]\npart1 = 1").is_err());
+        assert!(Answers::parse("[day01]\npart3 = 1").is_err());
+        assert!(Answers::parse("[day01]\npart1 = 1.5").is_err());
+        assert!(Answers::parse("day01 = 1").is_err());
+    }
+
+    #[test]
+    fn test_repository_answers() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../answers.toml");
+        let answers = Answers::load(&path).unwrap();
+        assert_eq!(answers.check(25, 1, &Answer::Number(3099)), Status::Correct);
+    }
+}
@@ -0,0 +1,205 @@
+//! Benchmarks: statistics over repeated timings of each day, and comparing
+//! them against a baseline to catch regressions.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+/// The stages of each day that are timed.
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Statistics over the timings of a single stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+// The nearest-rank percentile of the sorted samples.
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    let rank = (p * sorted.len()).div_ceil(100);
+    sorted[rank.max(1) - 1]
+}
+
+impl Stats {
+    /// Calculates the statistics of the samples, of which there must be at
+    /// least one.
+    pub fn new(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        Stats {
+            min: sorted[0],
+            median: percentile(&sorted, 50),
+            p90: percentile(&sorted, 90),
+            p99: percentile(&sorted, 99),
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+/// The statistics of a single stage of a day.
+pub struct Measurement {
+    pub day: u8,
+    pub stage: &'static str,
+    pub stats: Stats,
+}
+
+impl Measurement {
+    // The key of the measurement in the baseline file.
+    fn key(&self) -> String {
+        format!("day{:02}.{}", self.day, self.stage)
+    }
+}
+
+/// The median time in nanoseconds of each stage, keyed like `day01.part1`.
+pub type Baseline = BTreeMap<String, u64>;
+
+/// Loads a baseline, a missing file is an empty baseline.
+pub fn load_baseline(path: &Path) -> Result<Baseline, String> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(Baseline::new()),
+        Err(e) => return Err(format!("Unable to read {}: {}", path.display(), e)),
+    };
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Invalid baseline {}: {}", path.display(), e))
+}
+
+/// Updates the baseline with the medians of the measurements and writes it.
+pub fn save_baseline(
+    path: &Path,
+    mut baseline: Baseline,
+    measurements: &[Measurement],
+) -> Result<(), String> {
+    for m in measurements {
+        baseline.insert(m.key(), m.stats.median.as_nanos() as u64);
+    }
+    let content = serde_json::to_string_pretty(&baseline).unwrap() + "\n";
+    fs::write(path, content).map_err(|e| format!("Unable to write {}: {}", path.display(), e))
+}
+
+/// The relative change of the median compared to the baseline, if the stage
+/// is in the baseline.
+pub fn change(measurement: &Measurement, baseline: &Baseline) -> Option<f64> {
+    let before = *baseline.get(&measurement.key())?;
+    let after = measurement.stats.median.as_nanos() as f64;
+    Some(after / before.max(1) as f64 - 1.0)
+}
+
+/// Whether the median got slower than the baseline by more than `threshold`,
+/// which is relative (0.1 is 10%).
+pub fn is_regression(measurement: &Measurement, baseline: &Baseline, threshold: f64) -> bool {
+    change(measurement, baseline).is_some_and(|change| change > threshold)
+}
+
+/// Renders the measurements as an aligned table, with the change compared to
+/// the baseline if there is one.
+pub fn table(measurements: &[Measurement], baseline: Option<&Baseline>, threshold: f64) -> String {
+    let mut result =
+        String::from("Day  Stage  Min         Median      p90         p99         Max");
+    if baseline.is_some() {
+        result += "         Change";
+    }
+    result += "\n";
+    for m in measurements {
+        let stats = &m.stats;
+        let mut line = format!(
+            "{:>3}  {:<5}  {:<10}  {:<10}  {:<10}  {:<10}  {:<10}",
+            m.day,
+            m.stage,
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.p90),
+            format!("{:.2?}", stats.p99),
+            format!("{:.2?}", stats.max),
+        );
+        if let Some(baseline) = baseline {
+            match change(m, baseline) {
+                Some(change) => {
+                    line += &format!("  {:+.1}%", change * 100.0);
+                    if change > threshold {
+                        line += "  REGRESSION";
+                    }
+                }
+                None => line += "  new",
+            }
+        }
+        result += line.trim_end();
+        result += "\n";
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    fn measurement(median: u64) -> Measurement {
+        Measurement {
+            day: 15,
+            stage: "part1",
+            stats: Stats::new(&[ms(median)]),
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=100).rev().map(ms).collect::<Vec<_>>();
+        assert_eq!(
+            Stats::new(&samples),
+            Stats {
+                min: ms(1),
+                median: ms(50),
+                p90: ms(90),
+                p99: ms(99),
+                max: ms(100),
+            }
+        );
+        let single = Stats::new(&[ms(7)]);
+        assert_eq!(single.median, ms(7));
+        assert_eq!(single.p99, ms(7));
+    }
+
+    #[test]
+    fn test_regression() {
+        let mut baseline = Baseline::new();
+        baseline.insert("day15.part1".to_owned(), 100_000_000);
+        assert!(!is_regression(&measurement(105), &baseline, 0.1));
+        assert!(is_regression(&measurement(115), &baseline, 0.1));
+        assert!(!is_regression(&measurement(50), &baseline, 0.1));
+        assert!(!is_regression(&measurement(500), &Baseline::new(), 0.1));
+        assert!((change(&measurement(150), &baseline).unwrap() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let path = std::env::temp_dir().join("aoc-bench-test-baseline.json");
+        let _ = fs::remove_file(&path);
+        assert!(load_baseline(&path).unwrap().is_empty());
+        save_baseline(&path, Baseline::new(), &[measurement(3)]).unwrap();
+        let baseline = load_baseline(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(baseline.get("day15.part1"), Some(&3_000_000));
+    }
+
+    #[test]
+    fn test_table() {
+        let mut baseline = Baseline::new();
+        baseline.insert("day15.part1".to_owned(), 100_000_000);
+        assert_eq!(
+            table(&[measurement(120)], Some(&baseline), 0.1),
+            "Day  Stage  Min         Median      p90         p99         Max         Change
+ 15  part1  120.00ms    120.00ms    120.00ms    120.00ms    120.00ms    +20.0%  REGRESSION
+"
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! The registry of every day the runner knows how to solve.
+
+use aoc_common::{Answer, Generate, ParseError, Solution, Trace, Tracer, Visualize};
+use std::time::Duration;
+
+/// The answer for a single part, along with how long it took to solve.
+pub type PartResult = (u8, Answer, Duration);
+
+/// How long it took to parse the input, along with the result of each part.
+pub type DayResult = (Duration, Vec<PartResult>);
+
+/// The timings of parsing, part 1 and part 2 over a number of runs.
+pub type Samples = [Vec<Duration>; 3];
+
+/// Parses the input and solves a part of a smaller version of the puzzle,
+/// failing if the day doesn't take the parameters given. Only the examples
+/// solve those, which are checked by the tests.
+#[cfg(test)]
+pub type SolvingWith = fn(&str, u8, &[(&str, i64)]) -> Result<Result<Answer, String>, ParseError>;
+
+/// Times parsing the input and solving each part the given number of times,
+/// returning the samples of each stage, or an error if a part panicked.
+pub type Benchmark = fn(&str, usize) -> Result<Result<Samples, String>, ParseError>;
+
+/// Parses the input and passes each frame of the simulation to the callback,
+/// until it returns false.
+pub type Visualizer = fn(&str, &mut dyn FnMut(String) -> bool) -> Result<(), ParseError>;
+
+/// Parses the input and solves a part, passing each step to the tracer.
+pub type Tracing = fn(&str, u8, &mut dyn Tracer) -> Result<Answer, ParseError>;
+
+/// A single day and its solution.
+pub struct Day {
+    pub day: u8,
+    /// Parses the input and solves each of the given parts.
+    pub solve: fn(&str, &[u8]) -> Result<DayResult, ParseError>,
+    /// Solves a part with the parameters of an example.
+    #[cfg(test)]
+    pub solve_with: SolvingWith,
+    /// Benchmarks the day.
+    pub bench: Benchmark,
+    /// Generates an input from a seed, of the given size.
+    pub generate: fn(u64, usize) -> String,
+    /// The size of the author's input, for generating inputs like it.
+    pub size: usize,
+    /// Plays the simulation, for the days that can be watched.
+    pub visualize: Option<Visualizer>,
+    /// Solves a part step by step.
+    pub trace: Tracing,
+}
+
+fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<DayResult, ParseError> {
+    let (input, parse_took) = aoc_common::timed(|| S::parse(input));
+    let input = input?;
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let (answer, took) = aoc_common::timed(|| S::solve(&input, part));
+            (part, answer, took)
+        })
+        .collect();
+    Ok((parse_took, parts))
+}
+
+#[cfg(test)]
+fn solve_with<S: Solution>(
+    input: &str,
+    part: u8,
+    params: &[(&str, i64)],
+) -> Result<Result<Answer, String>, ParseError> {
+    Ok(S::solve_with(&S::parse(input)?, part, params))
+}
+
+fn bench<S: Solution>(input: &str, runs: usize) -> Result<Result<Samples, String>, ParseError> {
+    let mut samples = Samples::default();
+    let mut parsed = None;
+    for _ in 0..runs {
+        let (result, took) = aoc_common::timed(|| S::parse(input));
+        samples[0].push(took);
+        parsed = Some(result?);
+    }
+    if let Some(input) = parsed {
+        for part in 1..=2 {
+            let timings = crate::catch_panic(S::DAY, part, || {
+                (0..runs)
+                    .map(|_| aoc_common::timed(|| S::solve(&input, part)).1)
+                    .collect()
+            });
+            match timings {
+                Ok(timings) => samples[part as usize] = timings,
+                Err(e) => return Ok(Err(e)),
+            }
+        }
+    }
+    Ok(Ok(samples))
+}
+
+fn visualize<S: Visualize>(
+    input: &str,
+    draw: &mut dyn FnMut(String) -> bool,
+) -> Result<(), ParseError> {
+    let input = S::parse(input)?;
+    for frame in S::frames(&input) {
+        if !draw(frame) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+fn trace<S: Trace>(input: &str, part: u8, tracer: &mut dyn Tracer) -> Result<Answer, ParseError> {
+    Ok(S::trace(&S::parse(input)?, part, tracer))
+}
+
+const fn day<S: Generate + Trace>() -> Day {
+    Day {
+        day: S::DAY,
+        solve: solve::<S>,
+        #[cfg(test)]
+        solve_with: solve_with::<S>,
+        bench: bench::<S>,
+        generate: aoc_common::generate::generate::<S>,
+        size: S::SIZE,
+        visualize: None,
+        trace: trace::<S>,
+    }
+}
+
+const fn animated<S: Generate + Visualize + Trace>() -> Day {
+    Day {
+        visualize: Some(visualize::<S>),
+        ..day::<S>()
+    }
+}
+
+pub const DAYS: [Day; 25] = [
+    day::<day01::Day01>(),
+    day::<day02::Day02>(),
+    animated::<day03::Day03>(),
+    day::<day04::Day04>(),
+    day::<day05::Day05>(),
+    day::<day06::Day06>(),
+    day::<day07::Day07>(),
+    day::<day08::Day08>(),
+    day::<day09::Day09>(),
+    day::<day10::Day10>(),
+    day::<day11::Day11>(),
+    day::<day12::Day12>(),
+    animated::<day13::Day13>(),
+    day::<day14::Day14>(),
+    day::<day15::Day15>(),
+    day::<day16::Day16>(),
+    animated::<day17::Day17>(),
+    day::<day18::Day18>(),
+    animated::<day19::Day19>(),
+    animated::<day20::Day20>(),
+    day::<day21::Day21>(),
+    animated::<day22::Day22>(),
+    day::<day23::Day23>(),
+    day::<day24::Day24>(),
+    animated::<day25::Day25>(),
+];
+
+/// Looks up a day by its number.
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_in_order() {
+        for (i, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.day as usize, i + 1);
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        assert_eq!(get(18).unwrap().day, 18);
+        assert!(get(26).is_none());
+    }
+
+    #[test]
+    fn test_solve() {
+        let (_, results) = (get(1).unwrap().solve)("1212", &[1, 2]).unwrap();
+        assert_eq!(results[0].1, Answer::Number(0));
+        assert_eq!(results[1].1, Answer::Number(6));
+    }
+
+    #[test]
+    fn test_bench() {
+        let samples = (get(1).unwrap().bench)("1212", 3).unwrap().unwrap();
+        assert!(samples.iter().all(|stage| stage.len() == 3));
+
+        // A panicking part stops the benchmark with an error.
+        struct Panicking;
+        impl Solution for Panicking {
+            const DAY: u8 = 7;
+            type Input = ();
+            fn parse(_: &str) -> Result<(), ParseError> {
+                Ok(())
+            }
+            fn part1(_: &()) -> Answer {
+                Answer::None
+            }
+            fn part2(_: &()) -> Answer {
+                panic!("out of cheese")
+            }
+        }
+        assert_eq!(
+            bench::<Panicking>("", 3).unwrap(),
+            Err("Day 7 part 2 panicked: out of cheese".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        for day in DAYS.iter() {
+            let input = (day.generate)(1, day.size.min(20));
+            assert_eq!(input, (day.generate)(1, day.size.min(20)));
+            // Some days take long whatever the size of the input, so those
+            // are only parsed.
+            let parts: &[u8] = match day.day {
+                14 | 15 | 17 | 21 | 22 | 25 => &[],
+                _ => &[1, 2],
+            };
+            if let Err(e) = (day.solve)(&input, parts) {
+                panic!("day {}: {}\n{}", day.day, e, input);
+            }
+        }
+    }
+
+    #[test]
+    fn test_visualize() {
+        let visualize = get(3).unwrap().visualize.unwrap();
+        let mut frames = vec![];
+        visualize("12", &mut |frame| {
+            frames.push(frame);
+            frames.len() < 5
+        })
+        .unwrap();
+        assert_eq!(frames.len(), 5);
+        assert!(get(1).unwrap().visualize.is_none());
+    }
+
+    #[test]
+    fn test_trace() {
+        // Tracing a day gives the same answers as solving it.
+        for day in DAYS.iter() {
+            let input = (day.generate)(1, day.size.min(20));
+            // Only the first part of the slow days, which is quick enough.
+            let parts: &[u8] = match day.day {
+                14 | 15 | 17 | 21 | 22 | 25 => &[1],
+                _ => &[1, 2],
+            };
+            let (_, results) = (day.solve)(&input, parts).unwrap();
+            for (part, answer, _) in results {
+                let traced = (day.trace)(&input, part, &mut vec![]);
+                assert_eq!(traced.unwrap(), answer, "day {} part {}", day.day, part);
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_parse_error() {
+        let error = (get(13).unwrap().solve)("0: 3\n1 2", &[1]).unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+}
@@ -0,0 +1,275 @@
+//! The examples from the puzzle texts, kept as data in `dayNN/examples.toml`
+//! and checked against the solver of each day.
+//!
+//! Each file has an `[[example]]` table per example, with the input and the
+//! answer of each part the puzzle text gives one for:
+//!
+//! ```toml
+//! [[example]]
+//! input = "1122"
+//! part1 = 3
+//! ```
+//!
+//! Any other number is a parameter of a smaller version of the puzzle, like
+//! `size = 5` for the list of day 10, passed to `Solution::solve_with`.
+
+use crate::answers;
+use crate::days::Day;
+use std::fmt;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use toml::Table;
+
+/// An example input, along with the expected answer of some of the parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answers: Vec<(u8, String)>,
+    /// The parameters the example solves the puzzle with, by name.
+    pub params: Vec<(String, i64)>,
+}
+
+/// Parses the examples of a day from TOML.
+pub fn parse(content: &str) -> Result<Vec<Example>, String> {
+    let table = content.parse::<Table>().map_err(|e| e.to_string())?;
+    let mut examples = vec![];
+    for (key, value) in &table {
+        let tables = match (key.as_str(), value.as_array()) {
+            ("example", Some(tables)) => tables,
+            _ => return Err(format!("Unknown key `{}`, expected `[[example]]`", key)),
+        };
+        for (i, example) in tables.iter().enumerate() {
+            let n = i + 1;
+            let example = example
+                .as_table()
+                .ok_or_else(|| format!("Expected a table for example {}", n))?;
+            let mut input = None;
+            let mut answers = vec![];
+            let mut params = vec![];
+            for (key, value) in example {
+                match key.as_str() {
+                    "input" => {
+                        input = value.as_str().map(str::to_owned);
+                        if input.is_none() {
+                            return Err(format!("Expected a string input for example {}", n));
+                        }
+                    }
+                    "part1" | "part2" => {
+                        let part = if key == "part1" { 1 } else { 2 };
+                        let answer = answers::expected(value).ok_or_else(|| {
+                            format!("Expected a number or a string for {} of example {}", key, n)
+                        })?;
+                        answers.push((part, answer));
+                    }
+                    _ => match value.as_integer() {
+                        Some(value) => params.push((key.clone(), value)),
+                        None => {
+                            return Err(format!(
+                                "Expected a number for the parameter `{}` of example {}",
+                                key, n
+                            ))
+                        }
+                    },
+                }
+            }
+            let input = input.ok_or_else(|| format!("Missing the input of example {}", n))?;
+            if answers.is_empty() {
+                return Err(format!("Missing the answers of example {}", n));
+            }
+            examples.push(Example {
+                input,
+                answers,
+                params,
+            });
+        }
+    }
+    Ok(examples)
+}
+
+/// Loads the examples of a day, or none if the day has no examples file.
+pub fn load(path: &Path) -> Result<Vec<Example>, String> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Unable to read {}: {}", path.display(), e))?;
+    parse(&content).map_err(|e| format!("Invalid examples {}: {}", path.display(), e))
+}
+
+/// What a solver came up with instead of the expected answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Found {
+    Answer(String),
+    ParseError(String),
+    InvalidParams(String),
+    Panic(String),
+}
+
+/// An example whose answer doesn't match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    /// The number of the example in the file, from 1.
+    pub example: usize,
+    pub part: u8,
+    pub expected: String,
+    pub found: Found,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "day {}, example {}, part {}: expected {}, ",
+            self.day, self.example, self.part, self.expected
+        )?;
+        match self.found {
+            Found::Answer(ref answer) => write!(f, "got {}", answer),
+            Found::ParseError(ref e) => write!(f, "but the input didn't parse: {}", e),
+            Found::InvalidParams(ref e) => write!(f, "but the parameters are invalid: {}", e),
+            Found::Panic(ref message) => write!(f, "but the solver panicked: {}", message),
+        }
+    }
+}
+
+// Solves a part, catching a panicking solver so the other examples still run.
+fn solve(day: &Day, example: &Example, part: u8) -> Found {
+    let params = example
+        .params
+        .iter()
+        .map(|(name, value)| (name.as_str(), *value))
+        .collect::<Vec<_>>();
+    match panic::catch_unwind(AssertUnwindSafe(|| {
+        (day.solve_with)(&example.input, part, &params)
+    })) {
+        Ok(Ok(Ok(answer))) => Found::Answer(answer.to_string()),
+        Ok(Ok(Err(e))) => Found::InvalidParams(e),
+        Ok(Err(e)) => Found::ParseError(e.to_string()),
+        Err(payload) => Found::Panic(crate::panic_message(payload.as_ref())),
+    }
+}
+
+/// Solves the parts of each example that have an answer, returning the ones
+/// that don't match.
+pub fn check(day: &Day, examples: &[Example]) -> Vec<Mismatch> {
+    let mut mismatches = vec![];
+    for (i, example) in examples.iter().enumerate() {
+        for (part, expected) in &example.answers {
+            let found = solve(day, example, *part);
+            if found != Found::Answer(expected.clone()) {
+                mismatches.push(Mismatch {
+                    day: day.day,
+                    example: i + 1,
+                    part: *part,
+                    expected: expected.clone(),
+                    found,
+                });
+            }
+        }
+    }
+    mismatches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::{self, DAYS};
+    use crate::pool;
+
+    // The examples file of a day, next to its input.
+    fn path(day: u8) -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(format!("day{:02}", day))
+            .join("examples.toml")
+    }
+
+    #[test]
+    fn test_examples() {
+        // The days are checked at the same time, as a few examples take long.
+        let results = pool::map(&DAYS, pool::default_jobs(), |day| {
+            let examples = load(&path(day.day)).unwrap();
+            (examples.len(), check(day, &examples))
+        });
+        let checked = results.iter().map(|(count, _)| count).sum::<usize>();
+        let mismatches = results
+            .into_iter()
+            .flat_map(|(_, mismatches)| mismatches)
+            .collect::<Vec<_>>();
+        let report = mismatches
+            .iter()
+            .map(Mismatch::to_string)
+            .collect::<Vec<_>>();
+        assert!(
+            report.is_empty(),
+            "{} example answer(s) are wrong:\n{}",
+            report.len(),
+            report.join("\n")
+        );
+        assert!(checked > 50, "only {} examples were checked", checked);
+    }
+
+    #[test]
+    fn test_parse() {
+        let examples = parse("[[example]]\ninput = '1'\npart2 = 2\n\n[[example]]\ninput = '''\na\nb\n'''\nsize = 5\npart1 = \"x\"\n").unwrap();
+        assert_eq!(
+            examples,
+            [
+                Example {
+                    input: "1".to_owned(),
+                    answers: vec![(2, "2".to_owned())],
+                    params: vec![]
+                },
+                Example {
+                    input: "a\nb\n".to_owned(),
+                    answers: vec![(1, "x".to_owned())],
+                    params: vec![("size".to_owned(), 5)]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |content: &str| parse(content).unwrap_err();
+        assert_eq!(
+            error("[day01]\npart1 = 1"),
+            "Unknown key `day01`, expected `[[example]]`"
+        );
+        assert_eq!(
+            error("[[example]]\npart1 = 1"),
+            "Missing the input of example 1"
+        );
+        assert_eq!(
+            error("[[example]]\ninput = '1'\npart1 = 1\n[[example]]\ninput = '2'"),
+            "Missing the answers of example 2"
+        );
+        assert_eq!(
+            error("[[example]]\ninput = '1'\nsize = 'x'\npart1 = 1"),
+            "Expected a number for the parameter `size` of example 1"
+        );
+        assert_eq!(
+            error("[[example]]\ninput = 1\npart1 = 1"),
+            "Expected a string input for example 1"
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let day = days::get(1).unwrap();
+        let examples = parse("[[example]]\ninput = '1122'\npart1 = 3\npart2 = 4\n\n[[example]]\ninput = '12a'\npart1 = 0\n\n[[example]]\ninput = '1122'\nsize = 5\npart1 = 3").unwrap();
+        let mismatches = check(day, &examples)
+            .iter()
+            .map(Mismatch::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            mismatches,
+            [
+                "day 1, example 1, part 2: expected 4, got 0",
+                "day 1, example 2, part 1: expected 0, but the input didn't parse: line 1, column 3: not a digit: `a`",
+                "day 1, example 3, part 1: expected 3, but the parameters are invalid: unknown parameter `size` for part 1"
+            ]
+        );
+    }
+}
@@ -0,0 +1,757 @@
+//! A single runner for every Advent of Code 2017 day.
+
+mod animate;
+mod answers;
+mod bench;
+mod days;
+#[cfg(test)]
+mod examples;
+mod pool;
+mod report;
+mod trace;
+
+use aoc_common::InputSource;
+use clap::{Args, Parser, Subcommand};
+use day02::{ReadOptions, RowChecksum};
+use std::any::Any;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+
+use animate::Playback;
+use answers::{Answers, Status};
+use bench::{Baseline, Measurement, Stats};
+use days::Day;
+use report::{Format, Row};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2017 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solver for a single day or for all of them.
+    Run(RunArgs),
+    /// Time parsing and solving each day over a number of runs.
+    Bench(BenchArgs),
+    /// Print a puzzle input generated from a seed.
+    Generate(GenerateArgs),
+    /// Checksum the spreadsheet of day 2 by other rules than the puzzle's.
+    Checksum(ChecksumArgs),
+}
+
+// Selects the days to run and where to read their input from.
+#[derive(Args)]
+struct DaysArgs {
+    /// The day to run.
+    #[arg(
+        long,
+        value_parser = clap::value_parser!(u8).range(1..=25),
+        required_unless_present = "all",
+        conflicts_with = "all"
+    )]
+    day: Option<u8>,
+
+    /// Run every day.
+    #[arg(long)]
+    all: bool,
+
+    /// Read the puzzle input from this path, or from stdin if it is `-`,
+    /// instead of from the cache directory (`~/.cache/aoc/2017/dayNN.txt`).
+    #[arg(long, value_name = "PATH", requires = "day")]
+    input: Option<String>,
+
+    /// Generate the input of each day from this seed, instead of reading it.
+    #[arg(long, conflicts_with = "input")]
+    seed: Option<u64>,
+
+    /// The size of the generated inputs, instead of the size of the author's
+    /// inputs.
+    #[arg(long, requires = "seed", value_parser = clap::value_parser!(u64).range(1..))]
+    size: Option<u64>,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    days: DaysArgs,
+
+    /// Only run the given part.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// How to print the results.
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// Check the answers against this TOML file, instead of against
+    /// `answers.toml` in the cache directory if it exists.
+    #[arg(long, value_name = "PATH")]
+    answers: Option<PathBuf>,
+
+    /// How many parts to solve at the same time, one per CPU by default.
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    jobs: Option<u64>,
+
+    /// Write the steps each part takes to this file as JSON lines.
+    #[arg(long, value_name = "PATH")]
+    trace: Option<PathBuf>,
+
+    /// Watch the simulation of the day step by step instead of solving it.
+    #[arg(long, conflicts_with_all = ["all", "part", "format", "answers", "jobs", "trace"])]
+    visualize: bool,
+
+    /// How many frames to show per second.
+    #[arg(long, default_value_t = 10.0, value_parser = positive, requires = "visualize")]
+    speed: f64,
+
+    /// Stop after this many frames.
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..), requires = "visualize")]
+    steps: Option<u64>,
+}
+
+// Parses a number larger than zero.
+fn positive(arg: &str) -> Result<f64, String> {
+    match arg.parse::<f64>() {
+        Ok(value) if value > 0.0 && value.is_finite() => Ok(value),
+        _ => Err("expected a number larger than 0".to_owned()),
+    }
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    days: DaysArgs,
+
+    /// How many times to run each stage.
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    runs: u64,
+
+    /// Compare the medians against this baseline file.
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<PathBuf>,
+
+    /// Write the medians to the baseline file, keeping the days not run.
+    #[arg(long, requires = "baseline")]
+    save_baseline: bool,
+
+    /// Flag stages whose median is this many percent slower than the baseline.
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// The day to generate the input of.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The seed to generate the input from.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// The size of the input, which means something different for each day,
+    /// instead of the size of the author's input.
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    size: Option<u64>,
+}
+
+#[derive(Args)]
+struct ChecksumArgs {
+    /// The rules to checksum each row by: max-min, even-division, median,
+    /// gcd or pairwise, or several joined by `+` to add them up.
+    #[arg(required = true, value_parser = checksum_rule)]
+    rules: Vec<String>,
+
+    /// Read the spreadsheet from this path, or from stdin if it is `-`,
+    /// instead of from the cache directory.
+    #[arg(long, value_name = "PATH")]
+    input: Option<String>,
+
+    /// How the cells are separated: whitespace, csv or tsv.
+    #[arg(long, default_value = "whitespace")]
+    format: day02::Format,
+
+    /// The first row names the columns.
+    #[arg(long)]
+    header: bool,
+
+    /// Skip the cells that aren't integers instead of failing, listing them.
+    #[arg(long)]
+    skip_invalid: bool,
+}
+
+// Checks that the rule is known, so a typo fails before reading the input.
+fn checksum_rule(arg: &str) -> Result<String, String> {
+    day02::rule(arg).map(|_| arg.to_owned())
+}
+
+// Generates an input for the day, of the size of the author's input unless
+// another one is given.
+fn generate_input(day: &Day, seed: u64, size: Option<u64>) -> String {
+    (day.generate)(seed, size.map_or(day.size, |size| size as usize))
+}
+
+// Loads the input of each selected day and calls `f` with it, returning the
+// errors of the days whose input could not be loaded or that failed.
+fn for_each_day<F>(args: &DaysArgs, mut f: F) -> Vec<String>
+where
+    F: FnMut(&'static Day, &str) -> Result<(), String>,
+{
+    let selected = match args.day {
+        Some(day) => vec![days::get(day).unwrap()],
+        None => days::DAYS.iter().collect(),
+    };
+    let source = InputSource::from_arg(args.input.as_ref());
+    let mut errors = vec![];
+    for day in selected {
+        let input = match args.seed {
+            Some(seed) => Ok(generate_input(day, seed, args.size)),
+            None => aoc_common::load_input(day.day, &source).map_err(|e| e.to_string()),
+        };
+        let result = input.and_then(|input| f(day, &input));
+        if let Err(e) = result {
+            errors.push(e);
+        }
+    }
+    errors
+}
+
+fn parse_error(day: &Day, e: aoc_common::ParseError) -> String {
+    format!("Unable to parse the input for day {}: {}", day.day, e)
+}
+
+// The message the solver panicked with, if it is a string.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default()
+}
+
+// Calls `f` to solve a part of a day, turning a panic into an error for that
+// part, so it doesn't take down the rest of the run.
+fn catch_panic<T>(day: u8, part: u8, f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        format!(
+            "Day {} part {} panicked: {}",
+            day,
+            part,
+            panic_message(payload.as_ref())
+        )
+    })
+}
+
+// A single part of a day to solve, along with the input of the day.
+struct Task<'a> {
+    day: &'static Day,
+    input: &'a str,
+    part: u8,
+}
+
+// Solves a single part, checking the answer against the known answers if
+// there are any. The input is parsed for each part, so that the parts of a
+// day can be solved at the same time.
+fn solve_task(task: &Task, answers: Option<&Answers>) -> Result<Row, String> {
+    let Task { day, input, part } = *task;
+    let (parse_took, mut results) = catch_panic(day.day, part, || (day.solve)(input, &[part]))?
+        .map_err(|e| parse_error(day, e))?;
+    let (part, answer, took) = results.remove(0);
+    let status = answers.map(|answers| answers.check(day.day, part, &answer));
+    Ok(Row {
+        day: day.day,
+        part,
+        answer,
+        parse_took,
+        took,
+        status,
+    })
+}
+
+// Solves the tasks on up to `jobs` threads, returning the rows in the order
+// of the tasks along with the errors, once for each day whose input could
+// not be parsed and for each part that panicked.
+fn run_tasks(tasks: &[Task], jobs: usize, answers: Option<&Answers>) -> (Vec<Row>, Vec<String>) {
+    let mut rows = vec![];
+    let mut errors = vec![];
+    for result in pool::map(tasks, jobs, |task| solve_task(task, answers)) {
+        match result {
+            Ok(row) => rows.push(row),
+            Err(e) if !errors.contains(&e) => errors.push(e),
+            Err(_) => {}
+        }
+    }
+    (rows, errors)
+}
+
+// Writes the steps of each part of the days to the file as JSON lines.
+fn write_traces(path: &Path, inputs: &[(&Day, String)], parts: &[u8]) -> Vec<String> {
+    let file = match File::create(path) {
+        Ok(file) => file,
+        Err(e) => return vec![format!("Unable to write {}: {}", path.display(), e)],
+    };
+    let mut out = BufWriter::new(file);
+    let mut errors = inputs
+        .iter()
+        .filter_map(|(day, input)| trace::write(day, input, parts, &mut out).err())
+        .collect::<Vec<_>>();
+    if let Err(e) = out.flush() {
+        errors.push(format!("Unable to write {}: {}", path.display(), e));
+    }
+    errors
+}
+
+// Benchmarks a single day, appending the statistics of each stage.
+fn bench_day(
+    day: &Day,
+    input: &str,
+    runs: usize,
+    measurements: &mut Vec<Measurement>,
+) -> Result<(), String> {
+    let samples = (day.bench)(input, runs).map_err(|e| parse_error(day, e))??;
+    for (stage, samples) in bench::STAGES.iter().zip(samples.iter()) {
+        measurements.push(Measurement {
+            day: day.day,
+            stage,
+            stats: Stats::new(samples),
+        });
+    }
+    Ok(())
+}
+
+// Loads the answers given on the command line, or the ones in the cache
+// directory if there are any.
+fn load_answers(path: Option<&PathBuf>) -> Result<Option<Answers>, String> {
+    let path = match path {
+        Some(path) => path.clone(),
+        None => match aoc_common::cache_dir().map(|dir| dir.join("answers.toml")) {
+            Some(path) if path.exists() => path,
+            _ => return Ok(None),
+        },
+    };
+    Answers::load(&path).map(Some)
+}
+
+fn visualize(args: &RunArgs) -> Result<(), Vec<String>> {
+    let playback = Playback {
+        delay: Duration::from_secs_f64(1.0 / args.speed),
+        steps: args.steps,
+    };
+    let errors = for_each_day(&args.days, |day, input| {
+        animate::play(day, input, playback, &mut io::stdout().lock()).map(|_| ())
+    });
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn run(args: &RunArgs) -> Result<(), Vec<String>> {
+    if args.visualize {
+        return visualize(args);
+    }
+    // The known answers are for the author's inputs, not generated ones.
+    let answers = match args.days.seed {
+        Some(_) => None,
+        None => load_answers(args.answers.as_ref()).map_err(|e| vec![e])?,
+    };
+    let mut inputs = vec![];
+    let mut errors = for_each_day(&args.days, |day, input| {
+        inputs.push((day, input.to_owned()));
+        Ok(())
+    });
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let tasks = inputs
+        .iter()
+        .flat_map(|(day, input)| parts.iter().map(move |&part| Task { day, input, part }))
+        .collect::<Vec<_>>();
+    let jobs = args
+        .jobs
+        .map_or_else(pool::default_jobs, |jobs| jobs as usize);
+    let (rows, parse_errors) = run_tasks(&tasks, jobs, answers.as_ref());
+    errors.extend(parse_errors);
+    if let Some(ref path) = args.trace {
+        for e in write_traces(path, &inputs, &parts) {
+            if !errors.contains(&e) {
+                errors.push(e);
+            }
+        }
+    }
+    // Machine-readable output is printed even when empty, so it can always be
+    // parsed.
+    if !rows.is_empty() || args.format != Format::Table {
+        print!("{}", report::render(&rows, args.format));
+    }
+    let wrong = rows
+        .iter()
+        .filter(|row| matches!(row.status, Some(Status::Wrong(_))))
+        .count();
+    if wrong > 0 {
+        errors.push(format!("{} answer(s) are wrong", wrong));
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn bench(args: &BenchArgs) -> Result<(), Vec<String>> {
+    let baseline = match args.baseline {
+        Some(ref path) => Some(bench::load_baseline(path).map_err(|e| vec![e])?),
+        None => None,
+    };
+    let mut measurements = vec![];
+    let mut errors = for_each_day(&args.days, |day, input| {
+        bench_day(day, input, args.runs as usize, &mut measurements)
+    });
+    let threshold = args.threshold / 100.0;
+    print!(
+        "{}",
+        bench::table(&measurements, baseline.as_ref(), threshold)
+    );
+    if let Some(ref baseline) = baseline {
+        let regressions = measurements
+            .iter()
+            .filter(|m| bench::is_regression(m, baseline, threshold))
+            .count();
+        if regressions > 0 {
+            errors.push(format!(
+                "{} stage(s) are more than {}% slower than the baseline",
+                regressions, args.threshold
+            ));
+        }
+    }
+    if args.save_baseline {
+        let path = args.baseline.as_ref().unwrap();
+        let baseline = baseline.unwrap_or_else(Baseline::new);
+        if let Err(e) = bench::save_baseline(path, baseline, &measurements) {
+            errors.push(e);
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+// Reads the spreadsheet once and checksums it by each of the rules, a line
+// per rule, followed by a line for each cell skipped.
+fn checksums(
+    input: &str,
+    rules: &[String],
+    options: ReadOptions,
+) -> Result<(Vec<String>, Vec<String>), String> {
+    let table = day02::read(input, options).map_err(|e| parse_error(days::get(2).unwrap(), e))?;
+    let lines = rules
+        .iter()
+        .map(|spec| {
+            let rule = day02::rule(spec)?;
+            Ok(format!("{}: {}", spec, rule.checksum(&table.rows)))
+        })
+        .collect::<Result<_, String>>()?;
+    let skipped = table.skipped.iter().map(|e| format!("Skipped {}", e));
+    Ok((lines, skipped.collect()))
+}
+
+fn checksum(args: &ChecksumArgs) -> Result<(), Vec<String>> {
+    let source = InputSource::from_arg(args.input.as_ref());
+    let options = ReadOptions {
+        format: args.format,
+        header: args.header,
+        skip_invalid: args.skip_invalid,
+    };
+    let (lines, skipped) = aoc_common::load_input(2, &source)
+        .map_err(|e| e.to_string())
+        .and_then(|input| checksums(&input, &args.rules, options))
+        .map_err(|e| vec![e])?;
+    for line in skipped {
+        eprintln!("{}", line);
+    }
+    for line in lines {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run(args) => run(&args),
+        Command::Bench(args) => bench(&args),
+        Command::Generate(args) => {
+            let day = days::get(args.day).unwrap();
+            println!("{}", generate_input(day, args.seed, args.size));
+            Ok(())
+        }
+        Command::Checksum(args) => checksum(&args),
+    };
+    if let Err(errors) = result {
+        for e in &errors {
+            eprintln!("{}", e);
+        }
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Answer;
+
+    fn tasks<'a>(day: u8, input: &'a str, parts: &[u8]) -> Vec<Task<'a>> {
+        let day = days::get(day).unwrap();
+        parts
+            .iter()
+            .map(|&part| Task { day, input, part })
+            .collect()
+    }
+
+    #[test]
+    fn test_run_single_part() {
+        let (rows, errors) = run_tasks(&tasks(1, "1122", &[1]), 1, None);
+        assert!(errors.is_empty());
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].answer, Answer::Number(3));
+        assert_eq!(rows[0].status, None);
+    }
+
+    #[test]
+    fn test_run_without_second_part() {
+        let blueprint = "Begin in state A.
+Perform a diagnostic checksum after 1 steps.
+
+In state A:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state A.
+  If the current value is 1:
+    - Write the value 0.
+    - Move one slot to the left.
+    - Continue with state A.
+";
+        let (rows, _) = run_tasks(&tasks(25, blueprint, &[2]), 1, None);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].answer, Answer::None);
+    }
+
+    #[test]
+    fn test_run_checks_answers() {
+        let answers = Answers::parse("[day01]\npart1 = 3\npart2 = 1").unwrap();
+        let (rows, _) = run_tasks(&tasks(1, "1122", &[1, 2]), 2, Some(&answers));
+        assert_eq!(rows[0].status, Some(Status::Correct));
+        assert_eq!(rows[1].status, Some(Status::Wrong("1".to_owned())));
+    }
+
+    #[test]
+    fn test_run_parse_error() {
+        let (rows, errors) = run_tasks(&tasks(13, "0: 3\n1: x", &[1, 2]), 2, None);
+        // Both parts fail to parse the input, but it is only reported once.
+        assert_eq!(
+            errors,
+            ["Unable to parse the input for day 13: line 2, column 4: invalid range: `x`"]
+        );
+        assert!(rows.is_empty());
+    }
+
+    // Day 6, except that solving or tracing any part panics.
+    fn panicking() -> &'static Day {
+        Box::leak(Box::new(Day {
+            solve: |_, _| panic!("out of cheese"),
+            trace: |_, _, _| panic!("out of cheese"),
+            ..*days::get(6).unwrap()
+        }))
+    }
+
+    #[test]
+    fn test_run_panic() {
+        let panicking = panicking();
+        let mut all = tasks(1, "1122", &[1]);
+        all.push(Task {
+            day: panicking,
+            input: "",
+            part: 2,
+        });
+        all.extend(tasks(6, "0 2 7 0", &[1]));
+        let (rows, errors) = run_tasks(&all, 2, None);
+        // The other parts are still solved, in order.
+        assert_eq!(errors, ["Day 6 part 2 panicked: out of cheese"]);
+        let solved = rows
+            .iter()
+            .map(|row| (row.day, row.part))
+            .collect::<Vec<_>>();
+        assert_eq!(solved, [(1, 1), (6, 1)]);
+    }
+
+    #[test]
+    fn test_run_in_order() {
+        let mut all = tasks(1, "91212129", &[1, 2]);
+        all.extend(tasks(6, "0 2 7 0", &[1, 2]));
+        all.extend(tasks(13, "0: 3\n1: 2\n4: 4\n6: 4", &[1, 2]));
+        let (rows, _) = run_tasks(&all, 8, None);
+        let answers = rows
+            .iter()
+            .map(|row| (row.day, row.part, row.answer.to_string()))
+            .collect::<Vec<_>>();
+        let expected = [
+            (1, 1, "9"),
+            (1, 2, "6"),
+            (6, 1, "5"),
+            (6, 2, "4"),
+            (13, 1, "24"),
+        ];
+        for (answer, (day, part, expected)) in answers.iter().zip(expected) {
+            assert_eq!(*answer, (day, part, expected.to_owned()));
+        }
+        assert_eq!(answers[5], (13, 2, "10".to_owned()));
+    }
+
+    #[test]
+    fn test_write_traces() {
+        let path = std::env::temp_dir().join("aoc-trace-test.jsonl");
+        let inputs = [
+            (days::get(1).unwrap(), "1212".to_owned()),
+            (days::get(6).unwrap(), "0 2 7 0".to_owned()),
+        ];
+        assert!(write_traces(&path, &inputs, &[2]).is_empty());
+        let trace = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(trace.lines().count(), 5 + 5 + 2);
+        assert!(trace.starts_with("{\"day\":1,\"part\":2,\"event\":\"match\""));
+        assert!(trace.ends_with("{\"day\":6,\"part\":2,\"event\":\"answer\",\"answer\":4}\n"));
+
+        // A panicking part is an error, and the other days are still traced.
+        let inputs = [
+            (panicking(), "0 2 7 0".to_owned()),
+            (days::get(1).unwrap(), "1212".to_owned()),
+        ];
+        assert_eq!(
+            write_traces(&path, &inputs, &[2]),
+            ["Day 6 part 2 panicked: out of cheese"]
+        );
+        let trace = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(trace.lines().count(), 5);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let mut measurements = vec![];
+        bench_day(days::get(1).unwrap(), "1122", 3, &mut measurements).unwrap();
+        let stages = measurements.iter().map(|m| m.stage).collect::<Vec<_>>();
+        assert_eq!(stages, ["parse", "part1", "part2"]);
+    }
+
+    #[test]
+    fn test_cli() {
+        use clap::CommandFactory;
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_generate_input() {
+        let day = days::get(16).unwrap();
+        let input = generate_input(day, 3, None);
+        assert_eq!(input.split(',').count(), day.size);
+        assert_eq!(generate_input(day, 3, Some(10)).split(',').count(), 10);
+        assert_ne!(input, generate_input(day, 4, None));
+    }
+
+    #[test]
+    fn test_cli_seed() {
+        let parse = |args: &[&str]| Cli::try_parse_from([&["aoc", "bench"], args].concat());
+        assert!(parse(&["--all", "--seed", "1", "--size", "100"]).is_ok());
+        assert!(parse(&["--all", "--size", "100"]).is_err());
+        assert!(parse(&["--day", "1", "--seed", "1", "--input", "-"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_visualize() {
+        let parse = |args: &[&str]| Cli::try_parse_from([&["aoc", "run"], args].concat());
+        let Command::Run(args) = parse(&["--day", "3", "--visualize", "--steps", "5"])
+            .unwrap()
+            .command
+        else {
+            panic!("expected the run command");
+        };
+        assert!(args.visualize);
+        assert_eq!((args.speed, args.steps), (10.0, Some(5)));
+        assert!(parse(&["--all", "--visualize"]).is_err());
+        assert!(parse(&["--day", "3", "--speed", "2"]).is_err());
+        assert!(parse(&["--day", "3", "--visualize", "--speed", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_jobs() {
+        let parse = |args: &[&str]| Cli::try_parse_from([&["aoc", "run"], args].concat());
+        assert!(parse(&["--all", "--jobs", "4"]).is_ok());
+        assert!(parse(&["--all", "--jobs", "0"]).is_err());
+        assert!(parse(&["--day", "3", "--visualize", "--jobs", "2"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_trace() {
+        let parse = |args: &[&str]| Cli::try_parse_from([&["aoc", "run"], args].concat());
+        assert!(parse(&["--all", "--trace", "trace.jsonl"]).is_ok());
+        assert!(parse(&["--day", "8", "--part", "1", "--trace", "trace.jsonl"]).is_ok());
+        assert!(parse(&["--day", "3", "--visualize", "--trace", "trace.jsonl"]).is_err());
+    }
+
+    #[test]
+    fn test_checksums() {
+        let rules = ["max-min".to_owned(), "median+gcd".to_owned()];
+        let mut options = ReadOptions {
+            format: day02::Format::Whitespace,
+            header: false,
+            skip_invalid: false,
+        };
+        let (lines, skipped) = checksums("5 1 9 5\n7 5 3\n2 4 6 8\n", &rules, options).unwrap();
+        assert_eq!(lines, ["max-min: 18", "median+gcd: 18"]);
+        assert!(skipped.is_empty());
+        assert_eq!(
+            checksums("5 1 9 5\n7 x 3", &rules, options).unwrap_err(),
+            "Unable to parse the input for day 2: line 2, column 3: invalid number in column 2: `x`"
+        );
+        options.format = day02::Format::Csv;
+        options.header = true;
+        options.skip_invalid = true;
+        let (lines, skipped) = checksums("a,b\n-5,x\n3,,4", &rules, options).unwrap();
+        assert_eq!(lines, ["max-min: 1", "median+gcd: 4"]);
+        assert_eq!(
+            skipped,
+            [
+                "Skipped line 2, column 4: invalid number in column `b`: `x`",
+                "Skipped line 3, column 3: empty cell in column `b`: ``"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cli_checksum() {
+        let parse = |args: &[&str]| Cli::try_parse_from([&["aoc", "checksum"], args].concat());
+        let Command::Checksum(args) = parse(&["max-min+gcd", "median", "--input", "-"])
+            .unwrap()
+            .command
+        else {
+            panic!("expected the checksum command");
+        };
+        assert_eq!(args.rules, ["max-min+gcd", "median"]);
+        assert_eq!(args.format, day02::Format::Whitespace);
+        assert!(parse(&["gcd", "--format", "tsv", "--header", "--skip-invalid"]).is_ok());
+        assert!(parse(&["gcd", "--format", "xlsx"]).is_err());
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["max-min+mean"]).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+//! Running independent tasks on a number of threads.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// The number of threads to use when none is given: one per CPU.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |jobs| jobs.get())
+}
+
+/// Calls `f` with each task on up to `jobs` threads, returning the results in
+/// the order of the tasks. Each thread takes the next task as soon as it is
+/// done with the previous one, so the slow tasks don't hold up the rest.
+pub fn map<T, R, F>(tasks: &[T], jobs: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            let sender = sender.clone();
+            let (next, f) = (&next, &f);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                match tasks.get(i) {
+                    Some(task) => sender.send((i, f(task))).unwrap(),
+                    None => break,
+                }
+            });
+        }
+    });
+    drop(sender);
+    let mut results = receiver.into_iter().collect::<Vec<_>>();
+    results.sort_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Barrier;
+
+    #[test]
+    fn test_map_in_order() {
+        let tasks = (0..100u64).collect::<Vec<_>>();
+        for jobs in [1, 3, 200] {
+            let results = map(&tasks, jobs, |&task| {
+                // Make the first tasks the slowest, so they finish last.
+                thread::sleep(std::time::Duration::from_micros(100 - task));
+                task * 2
+            });
+            assert_eq!(results, (0..200).step_by(2).collect::<Vec<_>>());
+        }
+        assert!(map(&[] as &[u8], 4, |&task| task).is_empty());
+    }
+
+    #[test]
+    fn test_map_concurrently() {
+        // Every task waits for all of them to start, which only finishes if
+        // they run at the same time.
+        let barrier = Barrier::new(4);
+        let results = map(&[1, 2, 3, 4], 4, |&task| {
+            barrier.wait();
+            task
+        });
+        assert_eq!(results, [1, 2, 3, 4]);
+    }
+}
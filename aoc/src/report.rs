@@ -0,0 +1,230 @@
+//! Formats the results of a run as a table, JSON or CSV.
+
+use crate::answers::Status;
+use aoc_common::Answer;
+use clap::ValueEnum;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+/// The result of running a single part of a day.
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    /// How long it took to parse the input for the day.
+    pub parse_took: Duration,
+    /// How long it took to solve the part.
+    pub took: Duration,
+    /// How the answer compares to the known answer, if answers are checked.
+    pub status: Option<Status>,
+}
+
+/// How to print the results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// An aligned table for humans.
+    Table,
+    /// A JSON array with an object per part.
+    Json,
+    /// CSV with a header line and a line per part.
+    Csv,
+}
+
+/// Renders the rows in the given format.
+pub fn render(rows: &[Row], format: Format) -> String {
+    match format {
+        Format::Table => table(rows),
+        Format::Json => json(rows),
+        Format::Csv => csv(rows),
+    }
+}
+
+/// Renders the rows as an aligned table, one line per part. The status of
+/// each answer is only shown when answers are checked.
+pub fn table(rows: &[Row]) -> String {
+    let answers = rows
+        .iter()
+        .map(|row| row.answer.to_string().len())
+        .chain(Some("Answer".len()))
+        .max()
+        .unwrap();
+    let times = rows
+        .iter()
+        .map(|row| format!("{:?}", row.took).chars().count())
+        .chain(Some("Time".len()))
+        .max()
+        .unwrap();
+    let checked = rows.iter().any(|row| row.status.is_some());
+    let mut result = format!("Day  Part  {:<width$}  Time", "Answer", width = answers);
+    if checked {
+        result += &format!("{:width$}  Status", "", width = times - "Time".len());
+    }
+    result += "\n";
+    for row in rows {
+        let mut line = format!(
+            "{:>3}  {:>4}  {:<width$}  {:<times$}",
+            row.day,
+            row.part,
+            row.answer.to_string(),
+            format!("{:?}", row.took),
+            width = answers,
+            times = times
+        );
+        if let Some(ref status) = row.status {
+            line += &format!("  {}", status);
+        }
+        result += line.trim_end();
+        result += "\n";
+    }
+    result
+}
+
+// Numbers stay numbers, so they can be compared as such.
+pub fn answer_to_json(answer: &Answer) -> Value {
+    match *answer {
+        Answer::Number(n) => n.into(),
+        Answer::Text(ref s) => s.as_str().into(),
+        Answer::None => Value::Null,
+    }
+}
+
+/// Renders the rows as a JSON array, with the times in nanoseconds.
+pub fn json(rows: &[Row]) -> String {
+    let rows = rows
+        .iter()
+        .map(|row| {
+            json!({
+                "day": row.day,
+                "part": row.part,
+                "answer": answer_to_json(&row.answer),
+                "parse_time_ns": row.parse_took.as_nanos() as u64,
+                "solve_time_ns": row.took.as_nanos() as u64,
+                "status": row.status.as_ref().map(Status::name),
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&rows).unwrap() + "\n"
+}
+
+// Quotes a CSV field if it contains anything that would break the line up.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// Renders the rows as CSV, with the times in nanoseconds. Parts without an
+/// answer, and answers that weren't checked, get an empty field.
+pub fn csv(rows: &[Row]) -> String {
+    let mut result = String::from("day,part,answer,parse_time_ns,solve_time_ns,status\n");
+    for row in rows {
+        let answer = match row.answer {
+            Answer::None => String::new(),
+            ref answer => csv_field(&answer.to_string()),
+        };
+        result += &format!(
+            "{},{},{},{},{},{}\n",
+            row.day,
+            row.part,
+            answer,
+            row.parse_took.as_nanos(),
+            row.took.as_nanos(),
+            row.status.as_ref().map_or("", Status::name)
+        );
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows() -> Vec<Row> {
+        vec![
+            Row {
+                day: 1,
+                part: 1,
+                answer: Answer::Number(1158),
+                parse_took: Duration::from_nanos(500),
+                took: Duration::from_micros(40),
+                status: Some(Status::Correct),
+            },
+            Row {
+                day: 10,
+                part: 2,
+                answer: Answer::from("96de9657665675b51cd03f0b3528ba26"),
+                parse_took: Duration::from_nanos(700),
+                took: Duration::from_millis(2),
+                status: Some(Status::Wrong("212".to_owned())),
+            },
+            Row {
+                day: 25,
+                part: 2,
+                answer: Answer::None,
+                parse_took: Duration::from_micros(3),
+                took: Duration::from_nanos(60),
+                status: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_table() {
+        let mut rows = rows();
+        rows.truncate(2);
+        assert_eq!(
+            table(&rows),
+            "Day  Part  Answer                            Time  Status
+  1     1  1158                              40µs  correct
+ 10     2  96de9657665675b51cd03f0b3528ba26  2ms   wrong (expected 212)
+"
+        );
+        for row in &mut rows {
+            row.status = None;
+        }
+        assert_eq!(
+            table(&rows),
+            "Day  Part  Answer                            Time
+  1     1  1158                              40µs
+ 10     2  96de9657665675b51cd03f0b3528ba26  2ms
+"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let parsed: Value = serde_json::from_str(&json(&rows())).unwrap();
+        assert_eq!(
+            parsed,
+            json!([
+                {"day": 1, "part": 1, "answer": 1158, "parse_time_ns": 500, "solve_time_ns": 40000,
+                 "status": "correct"},
+                {"day": 10, "part": 2, "answer": "96de9657665675b51cd03f0b3528ba26",
+                 "parse_time_ns": 700, "solve_time_ns": 2000000, "status": "wrong"},
+                {"day": 25, "part": 2, "answer": null, "parse_time_ns": 3000, "solve_time_ns": 60,
+                 "status": null},
+            ])
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            csv(&rows()),
+            "day,part,answer,parse_time_ns,solve_time_ns,status
+1,1,1158,500,40000,correct
+10,2,96de9657665675b51cd03f0b3528ba26,700,2000000,wrong
+25,2,,3000,60,
+"
+        );
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("abc"), "abc");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("a\"b"), "\"a\"\"b\"");
+    }
+}
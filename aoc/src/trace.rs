@@ -0,0 +1,121 @@
+//! Dumping the steps the solvers take as JSON lines, an event per line, so
+//! the traces of two inputs or two implementations can be compared with
+//! `diff`.
+
+use crate::days::Day;
+use crate::report;
+use aoc_common::trace::{Event, Value};
+use serde_json::{Map, Value as Json};
+use std::io::{self, Write};
+
+fn value_to_json(value: &Value) -> Json {
+    match value {
+        Value::Number(n) => (*n).into(),
+        Value::Text(s) => s.as_str().into(),
+        Value::List(values) => values.iter().map(value_to_json).collect(),
+    }
+}
+
+// A JSON object on a single line, starting with the day and part it is from
+// and the name of the event, followed by its fields.
+fn line<'a>(
+    day: u8,
+    part: u8,
+    name: &str,
+    fields: impl Iterator<Item = (&'a str, Json)>,
+) -> String {
+    let mut object = Map::new();
+    object.insert("day".to_owned(), day.into());
+    object.insert("part".to_owned(), part.into());
+    object.insert("event".to_owned(), name.into());
+    for (key, value) in fields {
+        object.insert(key.to_owned(), value);
+    }
+    Json::Object(object).to_string()
+}
+
+/// The event as a JSON line.
+pub fn json_line(day: u8, part: u8, event: &Event) -> String {
+    let fields = event
+        .fields
+        .iter()
+        .map(|(key, value)| (*key, value_to_json(value)));
+    line(day, part, event.name, fields)
+}
+
+/// Solves the parts of a day on the input, writing each step to `out` as a
+/// JSON line, followed by an `answer` line for each part. Returns the number
+/// of lines written.
+pub fn write(day: &Day, input: &str, parts: &[u8], out: &mut impl Write) -> Result<u64, String> {
+    let mut written = 0;
+    let mut error: Option<io::Error> = None;
+    let mut write_line = |line: String| {
+        if error.is_none() {
+            match writeln!(out, "{}", line) {
+                Ok(()) => written += 1,
+                Err(e) => error = Some(e),
+            }
+        }
+    };
+    for &part in parts {
+        let answer = crate::catch_panic(day.day, part, || {
+            (day.trace)(input, part, &mut |event: Event| {
+                write_line(json_line(day.day, part, &event))
+            })
+        })?
+        .map_err(|e| crate::parse_error(day, e))?;
+        let answer = ("answer", report::answer_to_json(&answer));
+        write_line(line(day.day, part, "answer", std::iter::once(answer)));
+    }
+    match error {
+        Some(e) => Err(format!(
+            "Unable to write the trace of day {}: {}",
+            day.day, e
+        )),
+        None => Ok(written),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_json_line() {
+        let event = Event::new("redistribute")
+            .with("bank", 2)
+            .with("banks", &[2u32, 4, 1, 2][..]);
+        assert_eq!(
+            json_line(6, 1, &event),
+            r#"{"day":6,"part":1,"event":"redistribute","bank":2,"banks":[2,4,1,2]}"#
+        );
+    }
+
+    #[test]
+    fn test_write() {
+        let mut out = vec![];
+        let day = days::get(8).unwrap();
+        let written = write(day, "b inc 5 if a > 1\na inc 1 if b < 5", &[1, 2], &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(written, 2 * 4);
+        assert_eq!(out.lines().count(), 8);
+        assert!(out.starts_with(
+            r#"{"day":8,"part":1,"event":"step","pc":0}
+{"day":8,"part":1,"event":"step","pc":1}
+{"day":8,"part":1,"event":"set","register":"a","from":0,"to":1}
+{"day":8,"part":1,"event":"answer","answer":1}
+{"day":8,"part":2,"event":"step","pc":0}"#
+        ));
+    }
+
+    #[test]
+    fn test_write_errors() {
+        let mut out = vec![];
+        assert_eq!(
+            write(days::get(6).unwrap(), "1 x", &[1], &mut out).unwrap_err(),
+            "Unable to parse the input for day 6: line 1, column 3: invalid number of blocks: `x`"
+        );
+        assert!(out.is_empty());
+    }
+}
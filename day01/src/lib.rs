@@ -0,0 +1,853 @@
+//! Day 1: Inverse Captcha.
+//!
+//! Sums the digits of a circular captcha that match the digit a given distance
+//! ahead of them.
+
+use aoc_common::parse::lines;
+use aoc_common::trace::NoTrace;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer};
+use std::error::Error;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::ops::Range;
+
+/// Sums the digits of `captcha` that match the digit `delta` positions ahead,
+/// wrapping around the end. Fails if the captcha contains anything but digits.
+pub fn calculate_captcha(captcha: &str, delta: usize) -> Result<u32, Box<dyn Error>> {
+    calculate_captcha_traced(captcha, delta, &mut NoTrace)
+}
+
+/// Like `calculate_captcha`, passing each digit that matches to the tracer as
+/// a `match` event with its position and the sum so far.
+pub fn calculate_captcha_traced(
+    captcha: &str,
+    delta: usize,
+    tracer: &mut dyn Tracer,
+) -> Result<u32, Box<dyn Error>> {
+    let mut sum = 0;
+    let chars: Vec<char> = captcha.chars().collect();
+    for n in 0..chars.len() {
+        if chars[n] == chars[(n + delta) % chars.len()] {
+            let digit = chars[n]
+                .to_digit(10)
+                .ok_or("Part of the captcha is not a digit")?;
+            sum += digit;
+            if tracer.enabled() {
+                tracer.event(
+                    Event::new("match")
+                        .with("position", n)
+                        .with("digit", digit)
+                        .with("sum", sum),
+                );
+            }
+        }
+    }
+    Ok(sum)
+}
+
+/// A captcha parsed into the values of its digits, to sum the matches at many
+/// distances at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Captcha {
+    digits: Vec<u32>,
+}
+
+impl Captcha {
+    /// Parses a captcha of digits in the given radix, where the letters `a` to
+    /// `z`, in either case, are the digits from 10 up. The captcha is a single
+    /// line, blank lines around it are ignored. Fails if the radix is not
+    /// from 2 to 36.
+    pub fn parse(captcha: &str, radix: u32) -> Result<Captcha, ParseError> {
+        if !(2..=36).contains(&radix) {
+            let message = format!("invalid radix {}, expected 2 to 36", radix);
+            return Err(ParseError::new(1, 1, "", message));
+        }
+        let mut captcha = lines(captcha).filter(|e| !e.text.trim().is_empty());
+        let digits = match captcha.next() {
+            Some(line) => {
+                let text = line.text.trim();
+                text.char_indices()
+                    .map(|(i, c)| {
+                        c.to_digit(radix)
+                            .ok_or_else(|| line.error(&text[i..i + c.len_utf8()], "not a digit"))
+                    })
+                    .collect::<Result<_, _>>()?
+            }
+            None => vec![],
+        };
+        if let Some(line) = captcha.next() {
+            return Err(line.error(line.text.trim(), "expected the captcha on a single line"));
+        }
+        Ok(Captcha { digits })
+    }
+
+    /// The number of digits.
+    pub fn len(&self) -> usize {
+        self.digits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Sums the digits that match the digit `delta` positions ahead, like
+    /// `calculate_captcha`.
+    pub fn sum(&self, delta: usize) -> u64 {
+        self.sums(&[delta])[0]
+    }
+
+    /// The sum for each of the deltas, in the same order, going over the
+    /// digits once.
+    pub fn sums(&self, deltas: &[usize]) -> Vec<u64> {
+        let mut sums = vec![0; deltas.len()];
+        let len = self.digits.len();
+        for (i, &digit) in self.digits.iter().enumerate() {
+            for (sum, &delta) in sums.iter_mut().zip(deltas) {
+                if self.digits[(i + delta % len) % len] == digit {
+                    *sum += u64::from(digit);
+                }
+            }
+        }
+        sums
+    }
+
+    /// The sum for every delta from 1 to half the length, at index `delta -
+    /// 1`. Repeating patterns show up as large sums at their period and its
+    /// multiples.
+    pub fn periods(&self) -> Vec<u64> {
+        self.sums(&(1..=self.len() / 2).collect::<Vec<_>>())
+    }
+}
+
+// How much of a stream is read at a time, small in the tests so they cross
+// chunks.
+const CHUNK: usize = if cfg!(test) { 7 } else { 64 * 1024 };
+
+// A position in a stream of digits, which reads the stream a chunk at a time
+// through `fill`, given the offset to read from. Whitespace is skipped, so
+// the digits can be split over lines.
+struct Digits {
+    chunk: Vec<u8>,
+    next: usize,
+    offset: u64,
+}
+
+impl Digits {
+    fn new(offset: u64) -> Digits {
+        Digits {
+            chunk: vec![],
+            next: 0,
+            offset,
+        }
+    }
+
+    fn next<F>(&mut self, fill: &mut F) -> io::Result<Option<u8>>
+    where
+        F: FnMut(u64, &mut [u8]) -> io::Result<usize>,
+    {
+        loop {
+            if self.next == self.chunk.len() {
+                self.chunk.resize(CHUNK, 0);
+                let read = loop {
+                    match fill(self.offset, &mut self.chunk) {
+                        Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                        result => break result?,
+                    }
+                };
+                self.chunk.truncate(read);
+                self.next = 0;
+                if read == 0 {
+                    return Ok(None);
+                }
+            }
+            let byte = self.chunk[self.next];
+            let offset = self.offset;
+            self.next += 1;
+            self.offset += 1;
+            match byte {
+                b'0'..=b'9' => return Ok(Some(byte - b'0')),
+                _ if byte.is_ascii_whitespace() => {}
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("not a digit: `{}` at byte {}", byte.escape_ascii(), offset),
+                    ))
+                }
+            }
+        }
+    }
+}
+
+/// Like `calculate_captcha`, but reads the captcha from a stream of any
+/// length, keeping only the first `delta` digits and the last `delta` digits
+/// read in memory. Whitespace between the digits is skipped.
+pub fn stream_captcha<R: Read>(mut reader: R, delta: usize) -> io::Result<u64> {
+    let mut fill = |_, buf: &mut [u8]| reader.read(buf);
+    let mut digits = Digits::new(0);
+    let mut first = Vec::with_capacity(delta);
+    // The last `delta` digits, with digit `i` at `i % delta`.
+    let mut ring = vec![0; delta];
+    let mut count = 0;
+    let mut sum = 0;
+    while let Some(digit) = digits.next(&mut fill)? {
+        if delta == 0 {
+            // Every digit matches itself.
+            sum += u64::from(digit);
+            count += 1;
+            continue;
+        }
+        if count < delta {
+            first.push(digit);
+        } else if ring[count % delta] == digit {
+            sum += u64::from(digit);
+        }
+        ring[count % delta] = digit;
+        count += 1;
+    }
+    if count <= delta {
+        // Every digit wraps around, and they're all still in memory.
+        return Ok((0..count)
+            .filter(|&i| first[i] == first[(i + delta) % count])
+            .map(|i| u64::from(first[i]))
+            .sum());
+    }
+    // The last `delta` digits match against the first ones.
+    for (i, &digit) in first.iter().enumerate() {
+        if ring[(count + i) % delta] == digit {
+            sum += u64::from(digit);
+        }
+    }
+    Ok(sum)
+}
+
+/// Like `calculate_captcha` with the digit halfway around, but reads the
+/// captcha from a seekable stream of any length with little memory: once to
+/// count the digits, and then from two places at once, seeking between them.
+/// Whitespace between the digits is skipped.
+pub fn stream_captcha_halfway<R: Read + Seek>(mut reader: R) -> io::Result<u64> {
+    let start = reader.stream_position()?;
+    let mut fill = |offset, buf: &mut [u8]| {
+        reader.seek(SeekFrom::Start(offset))?;
+        reader.read(buf)
+    };
+    let mut count = 0;
+    let mut digits = Digits::new(start);
+    while digits.next(&mut fill)?.is_some() {
+        count += 1;
+    }
+    let half = count / 2;
+    // Digit `i` is matched against digit `i + half`, which wraps around to
+    // the start after the last digit.
+    let mut digits = Digits::new(start);
+    let mut ahead = Digits::new(start);
+    for _ in 0..half {
+        ahead.next(&mut fill)?;
+    }
+    let mut sum = 0;
+    for i in 0..count {
+        if i == count - half {
+            ahead = Digits::new(start);
+        }
+        let missing = || io::Error::new(io::ErrorKind::UnexpectedEof, "the captcha got shorter");
+        let digit = digits.next(&mut fill)?.ok_or_else(missing)?;
+        if ahead.next(&mut fill)?.ok_or_else(missing)? == digit {
+            sum += u64::from(digit);
+        }
+    }
+    Ok(sum)
+}
+
+// The pairs of sums of matching digits reached so far, one bit per pair, up
+// to the sums wanted. Most are empty, so the bits are only allocated once
+// something is added.
+#[derive(Debug, Clone)]
+struct Sums {
+    rows: usize,
+    width: usize,
+    words: usize,
+    // The rows outside are all empty.
+    used: Range<usize>,
+    bits: Vec<u64>,
+}
+
+impl Sums {
+    fn new(rows: usize, width: usize) -> Sums {
+        let words = width.div_ceil(64);
+        Sums {
+            rows,
+            width,
+            words,
+            used: 0..0,
+            bits: vec![],
+        }
+    }
+
+    fn contains(&self, a: usize, b: usize) -> bool {
+        self.used.contains(&a)
+            && b < self.width
+            && self.bits[a * self.words + b / 64] & 1 << (b % 64) != 0
+    }
+
+    fn is_empty(&self) -> bool {
+        self.used.is_empty()
+    }
+
+    fn insert(&mut self, a: usize, b: usize) {
+        if a < self.rows && b < self.width {
+            self.use_rows(a..a + 1);
+            self.bits[a * self.words + b / 64] |= 1 << (b % 64);
+        }
+    }
+
+    // Marks the rows as used, allocating the bits if they aren't yet.
+    fn use_rows(&mut self, rows: Range<usize>) {
+        if rows.is_empty() {
+            return;
+        }
+        if self.bits.is_empty() {
+            self.bits = vec![0; self.rows * self.words];
+        }
+        if self.used.is_empty() {
+            self.used = rows;
+        } else {
+            self.used = self.used.start.min(rows.start)..self.used.end.max(rows.end);
+        }
+    }
+
+    // Adds every pair of `other` with `a` and `b` added to it, dropping the
+    // ones past the sums wanted.
+    fn add_shifted(&mut self, other: &Sums, a: usize, b: usize) {
+        let (skip, shift) = (b / 64, b % 64);
+        let words = self.words;
+        let rows = other.used.start + a..self.rows.min(other.used.end + a);
+        self.use_rows(rows.clone());
+        for row in rows {
+            let from = &other.bits[(row - a) * words..(row - a + 1) * words];
+            let to = &mut self.bits[row * words..(row + 1) * words];
+            for i in skip..words {
+                let mut word = from[i - skip] << shift;
+                if shift > 0 && i > skip {
+                    word |= from[i - skip - 1] >> (64 - shift);
+                }
+                to[i] |= word;
+            }
+            if !self.width.is_multiple_of(64) {
+                to[words - 1] &= (1 << (self.width % 64)) - 1;
+            }
+        }
+    }
+
+    // Drops the pairs with sums below `a` or `b`.
+    fn drop_below(&mut self, a: usize, b: usize) {
+        let (skip, shift) = (b / 64, b % 64);
+        let words = self.words;
+        for row in self.used.start..self.used.end.min(a) {
+            self.bits[row * words..(row + 1) * words].fill(0);
+        }
+        for row in a.max(self.used.start)..self.used.end {
+            let row = &mut self.bits[row * words..(row + 1) * words];
+            row[..skip.min(words)].fill(0);
+            if skip < words {
+                row[skip] &= !0 << shift;
+            }
+        }
+        self.used.start = self.used.start.max(a).min(self.used.end);
+    }
+}
+
+/// The longest captcha `construct_captcha` searches for, as the search takes
+/// time growing with the length times the product of the sums.
+pub const MAX_CONSTRUCTED_LEN: usize = 100;
+
+/// Constructs a captcha of `len` digits whose answer is `part1` for the first
+/// part and `part2` for the second, the inverse of `calculate_captcha`.
+/// Returns `None` only if no such captcha exists, and fails for captchas
+/// longer than `MAX_CONSTRUCTED_LEN`.
+///
+/// The digits are put in an order where each digit is only compared with the
+/// two before it, one of them for each part, except around the ends. On odd
+/// lengths that is the order of stepping halfway around, where two steps come
+/// back to the digit before the first. On even lengths it goes back and forth
+/// between the two halves. The search then tries every pair of first digits,
+/// and keeps every pair of sums reachable with each pair of last digits, so
+/// it is exhaustive. Only every few of those layers are kept, about the
+/// square root of `len` of them, and the ones in between are worked out again
+/// when going back through them for the digits. Captchas of up to a hundred
+/// digits are found in well under a second, but proving that there are none
+/// close to the largest sums can take seconds from a few dozen digits on.
+pub fn construct_captcha(len: usize, part1: u32, part2: u32) -> Result<Option<String>, String> {
+    if len > MAX_CONSTRUCTED_LEN {
+        return Err(format!(
+            "can't construct captchas of more than {} digits",
+            MAX_CONSTRUCTED_LEN
+        ));
+    }
+    let (part1, part2) = (part1 as usize, part2 as usize);
+    match len {
+        0 => return Ok((part1 == 0 && part2 == 0).then(String::new)),
+        // The single digit matches itself both ways.
+        1 => return Ok((part1 == part2 && part1 <= 9).then(|| part1.to_string())),
+        _ if part1 > 9 * len || part2 > 9 * len => return Ok(None),
+        _ => {}
+    }
+    let half = len / 2;
+    let even = len.is_multiple_of(2);
+    // On even lengths the digits halfway around match in pairs, so only half
+    // the sum is searched for.
+    if even && part2 % 2 == 1 {
+        return Ok(None);
+    }
+    let part2 = if even { part2 / 2 } else { part2 };
+    // Where the digits of the search go in the captcha, and whether each is
+    // compared halfway around with the one before it.
+    let (order, halfway): (Vec<usize>, Vec<bool>) = if even {
+        (0..len).map(|k| (k / 2 + k % 2 * half, k % 2 == 1)).unzip()
+    } else {
+        (0..len).map(|k| (k * half % len, true)).unzip()
+    };
+    let empty = Sums::new(part1 + 1, part2 + 1);
+    // The sums for each pair of last digits after digit `k`, from the ones
+    // after the digit before.
+    let next_layer = |previous: &[Sums], k: usize| {
+        let mut layer = vec![empty.clone(); 100];
+        for x in 0..10 {
+            let sources = (0..10)
+                .map(|w| (w, &previous[w * 10 + x]))
+                .filter(|(_, sums)| !sums.is_empty())
+                .collect::<Vec<_>>();
+            for y in 0..10 {
+                let sum2 = if halfway[k] && x == y { y } else { 0 };
+                let sums = &mut layer[x * 10 + y];
+                for &(w, source) in &sources {
+                    // Only the digit two back matching adds to part 1.
+                    sums.add_shifted(source, if w == y { y } else { 0 }, sum2);
+                }
+            }
+        }
+        // Every digit left adds at most 9 to each part, and so do the
+        // comparisons around the ends, twice for part 1.
+        let left = 9 * (len - 1 - k);
+        for sums in &mut layer {
+            sums.drop_below(
+                part1.saturating_sub(left + 18),
+                part2.saturating_sub(left + 9),
+            );
+        }
+        layer
+    };
+    // Layer `i` holds the sums after digit `i + 1`, and every `stride`th one
+    // is kept.
+    let stride = (1..).find(|stride| stride * stride >= len).unwrap();
+    for first in 0..100 {
+        let (a, b) = (first / 10, first % 10);
+        let mut layer = vec![empty.clone(); 100];
+        layer[first].insert(0, if a == b { a } else { 0 });
+        let mut kept = vec![];
+        for i in 0..len - 1 {
+            if i > 0 {
+                layer = next_layer(&layer, i + 1);
+            }
+            if i % stride == 0 {
+                kept.push(layer.clone());
+            }
+        }
+        // The comparisons around the ends, with the first two digits.
+        let wrap = |x: usize, y: usize| {
+            let matching = |p: usize, q: usize| if p == q { p } else { 0 };
+            if even {
+                (matching(x, b) + matching(y, a), 0)
+            } else {
+                (matching(x, a) + matching(y, b), matching(y, a))
+            }
+        };
+        let last = (0..100).find(|&state| {
+            let (sum1, sum2) = wrap(state / 10, state % 10);
+            sum1 <= part1 && sum2 <= part2 && layer[state].contains(part1 - sum1, part2 - sum2)
+        });
+        let Some(last) = last else { continue };
+        // Go back through the layers for the digits that got there, working
+        // out the layers between the ones kept a stride at a time.
+        let mut digits = vec![0; len];
+        (digits[len - 2], digits[len - 1]) = (last / 10, last % 10);
+        let (sum1, sum2) = wrap(last / 10, last % 10);
+        let (mut sum1, mut sum2) = (part1 - sum1, part2 - sum2);
+        let mut stretch: Vec<Vec<Sums>> = vec![];
+        for k in (2..len).rev() {
+            let i = k - 2;
+            let start = i / stride * stride;
+            if stretch.len() <= i - start {
+                stretch = vec![kept[i / stride].clone()];
+                for j in start + 1..=i {
+                    let layer = next_layer(stretch.last().unwrap(), j + 1);
+                    stretch.push(layer);
+                }
+            }
+            let (x, y) = (digits[k - 1], digits[k]);
+            sum2 -= if halfway[k] && x == y { y } else { 0 };
+            let w = (0..10)
+                .find(|&w| {
+                    let matched = if w == y { y } else { 0 };
+                    sum1 >= matched && stretch[i - start][w * 10 + x].contains(sum1 - matched, sum2)
+                })
+                .unwrap();
+            sum1 -= if w == y { y } else { 0 };
+            digits[k - 2] = w;
+            stretch.truncate(i - start);
+        }
+        let mut captcha = vec![b'0'; len];
+        for (&position, &digit) in order.iter().zip(&digits) {
+            captcha[position] = b'0' + digit as u8;
+        }
+        return Ok(Some(String::from_utf8(captcha).unwrap()));
+    }
+    Ok(None)
+}
+
+/// Day 1: Inverse Captcha.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Captcha::parse(input, 10)?;
+        Ok(input.trim().to_owned())
+    }
+
+    fn part1(input: &String) -> Answer {
+        calculate_captcha(input, 1).unwrap().into()
+    }
+
+    fn part2(input: &String) -> Answer {
+        calculate_captcha(input, input.len() / 2).unwrap().into()
+    }
+}
+
+impl Generate for Day01 {
+    const SIZE: usize = 2014;
+
+    /// A captcha of `size` random digits.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| (b'1' + rng.below(9) as u8) as char)
+            .collect()
+    }
+}
+
+impl Trace for Day01 {
+    fn trace(input: &String, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        let delta = if part == 1 { 1 } else { input.len() / 2 };
+        calculate_captcha_traced(input, delta, tracer)
+            .unwrap()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(calculate_captcha("1122", 1).unwrap(), 3);
+        assert_eq!(calculate_captcha("1111", 1).unwrap(), 4);
+        assert_eq!(calculate_captcha("1234", 1).unwrap(), 0);
+        assert_eq!(calculate_captcha("91212129", 1).unwrap(), 9);
+        assert_eq!(calculate_captcha("", 1).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day01::parse("12a4\n").unwrap_err(),
+            ParseError::new(1, 3, "a", "not a digit")
+        );
+        // Errors used to point at the first line, whichever line they were on.
+        assert_eq!(
+            Day01::parse("\n 12a4\n").unwrap_err(),
+            ParseError::new(2, 4, "a", "not a digit")
+        );
+        assert_eq!(
+            Day01::parse("12\n34\n").unwrap_err(),
+            ParseError::new(2, 1, "34", "expected the captcha on a single line")
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        assert_eq!(
+            Day01::trace(&"1122".to_owned(), 1, &mut events),
+            Answer::Number(3)
+        );
+        let matched = |position: usize, digit: u32, sum: u32| {
+            Event::new("match")
+                .with("position", position)
+                .with("digit", digit)
+                .with("sum", sum)
+        };
+        assert_eq!(events, [matched(0, 1, 1), matched(2, 2, 3)]);
+    }
+
+    #[test]
+    fn part1_result() {
+        assert_eq!(calculate_captcha(&get_input(), 1).unwrap(), 1158);
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(calculate_captcha("1212", 2).unwrap(), 6);
+        assert_eq!(calculate_captcha("1221", 2).unwrap(), 0);
+        assert_eq!(calculate_captcha("123425", 3).unwrap(), 4);
+        assert_eq!(calculate_captcha("123123", 3).unwrap(), 12);
+        assert_eq!(calculate_captcha("12131415", 4).unwrap(), 4);
+    }
+
+    #[test]
+    fn part2_result() {
+        let input = get_input();
+        assert_eq!(calculate_captcha(&input, input.len() / 2).unwrap(), 1132);
+    }
+
+    #[test]
+    fn test_captcha_sums() {
+        let captcha = Captcha::parse("123425", 10).unwrap();
+        assert_eq!(captcha.len(), 6);
+        assert_eq!(captcha.sum(3), 4);
+        assert_eq!(captcha.sums(&[0, 1, 3, 9, 6]), [17, 0, 4, 4, 17]);
+        assert_eq!(Captcha::parse("123123", 10).unwrap().periods(), [0, 0, 12]);
+        let empty = Captcha::parse("\n", 10).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.sums(&[0, 1]), [0, 0]);
+        assert!(empty.periods().is_empty());
+        let input = Captcha::parse(&get_input(), 10).unwrap();
+        assert_eq!(input.sums(&[1, input.len() / 2]), [1158, 1132]);
+    }
+
+    #[test]
+    fn test_captcha_radix() {
+        let hex = Captcha::parse("fF0a0A", 16).unwrap();
+        assert_eq!(hex.sums(&[1, 2]), [15, 10]);
+        assert_eq!(Captcha::parse("zz", 36).unwrap().sum(1), 70);
+        assert_eq!(Captcha::parse("1101", 2).unwrap().periods(), [2, 2]);
+        assert_eq!(
+            Captcha::parse("10\n", 16).unwrap(),
+            Captcha::parse("10", 10).unwrap()
+        );
+        assert_eq!(
+            Captcha::parse("1012", 2).unwrap_err(),
+            ParseError::new(1, 4, "2", "not a digit")
+        );
+        assert_eq!(
+            Captcha::parse("abg", 16).unwrap_err(),
+            ParseError::new(1, 3, "g", "not a digit")
+        );
+    }
+
+    #[test]
+    fn test_captcha_invalid_radix() {
+        for radix in [0, 1, 37] {
+            let message = format!("invalid radix {}, expected 2 to 36", radix);
+            assert_eq!(
+                Captcha::parse("1", radix).unwrap_err(),
+                ParseError::new(1, 1, "", message)
+            );
+        }
+    }
+
+    // Checks the captcha against `calculate_captcha`.
+    fn check_constructed(len: usize, part1: u32, part2: u32) -> bool {
+        match construct_captcha(len, part1, part2).unwrap() {
+            Some(captcha) => {
+                assert_eq!(captcha.len(), len);
+                assert_eq!(calculate_captcha(&captcha, 1).unwrap(), part1);
+                assert_eq!(calculate_captcha(&captcha, len / 2).unwrap(), part2);
+                true
+            }
+            None => false,
+        }
+    }
+
+    #[test]
+    fn test_construct_captcha() {
+        assert!(check_constructed(4, 3, 0));
+        assert!(check_constructed(8, 9, 4));
+        assert!(check_constructed(6, 4, 12));
+        assert!(check_constructed(0, 0, 0));
+        assert!(check_constructed(1, 7, 7));
+        assert!(check_constructed(40, 200, 150));
+        assert!(check_constructed(33, 297, 297));
+        // A captcha of nines only matches everywhere, both ways.
+        assert!(!check_constructed(8, 72, 70));
+        // The digits halfway around match in pairs.
+        assert!(!check_constructed(8, 0, 3));
+        assert!(!check_constructed(1, 1, 2));
+        assert!(!check_constructed(5, 46, 0));
+        // The layers between the ones kept are worked out again on the way
+        // back, across several strides.
+        assert!(check_constructed(100, 500, 400));
+        assert!(check_constructed(99, 891, 891));
+        assert_eq!(
+            construct_captcha(101, 0, 0),
+            Err("can't construct captchas of more than 100 digits".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_construct_every_captcha() {
+        // Every pair of sums of the short captchas, found by trying every
+        // captcha, is constructed, and none of the others.
+        for len in 2..=4 {
+            let mut reachable = HashSet::new();
+            for n in 0..10usize.pow(len as u32) {
+                let captcha = format!("{:0len$}", n, len = len);
+                reachable.insert((
+                    calculate_captcha(&captcha, 1).unwrap(),
+                    calculate_captcha(&captcha, len / 2).unwrap(),
+                ));
+            }
+            for part1 in 0..=9 * len as u32 + 1 {
+                for part2 in 0..=9 * len as u32 + 1 {
+                    assert_eq!(
+                        check_constructed(len, part1, part2),
+                        reachable.contains(&(part1, part2)),
+                        "{} digits, {} and {}",
+                        len,
+                        part1,
+                        part2
+                    );
+                }
+            }
+        }
+    }
+
+    // Hands out a single byte per read, to check that nothing assumes full
+    // reads.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match (self.0.split_first(), buf.first_mut()) {
+                (Some((&byte, rest)), Some(first)) => {
+                    *first = byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn test_stream_captcha() {
+        assert_eq!(stream_captcha("91212129".as_bytes(), 1).unwrap(), 9);
+        assert_eq!(stream_captcha("123425\n".as_bytes(), 3).unwrap(), 4);
+        assert_eq!(stream_captcha("".as_bytes(), 1).unwrap(), 0);
+        assert_eq!(stream_captcha("12".as_bytes(), 0).unwrap(), 3);
+        // The digits may be split over lines.
+        assert_eq!(stream_captcha(Trickle(b"11\n2\n2\n"), 1).unwrap(), 3);
+        let input = get_input();
+        assert_eq!(stream_captcha(input.as_bytes(), 1).unwrap(), 1158);
+    }
+
+    #[test]
+    fn test_stream_captcha_halfway() {
+        assert_eq!(
+            stream_captcha_halfway(io::Cursor::new("12131415")).unwrap(),
+            4
+        );
+        assert_eq!(stream_captcha_halfway(io::Cursor::new("")).unwrap(), 0);
+        assert_eq!(stream_captcha_halfway(io::Cursor::new("7")).unwrap(), 7);
+        // Only the rest of the stream is read.
+        let mut cursor = io::Cursor::new("99\n123\n123");
+        cursor.set_position(3);
+        assert_eq!(stream_captcha_halfway(cursor).unwrap(), 12);
+        let input = get_input();
+        assert_eq!(
+            stream_captcha_halfway(io::Cursor::new(input)).unwrap(),
+            1132
+        );
+    }
+
+    #[test]
+    fn test_stream_errors() {
+        let error = stream_captcha("1234 56a8".as_bytes(), 1).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "not a digit: `a` at byte 7");
+        let error = stream_captcha_halfway(io::Cursor::new(b"12\xff")).unwrap_err();
+        assert_eq!(error.to_string(), "not a digit: `\\xff` at byte 2");
+    }
+
+    // A straightforward reference, comparing each digit with the one `delta`
+    // ahead by cycling through the digits.
+    fn naive_captcha(captcha: &str, delta: usize) -> u32 {
+        let digits = captcha.chars().map(|c| c.to_digit(10).unwrap());
+        let ahead = captcha
+            .chars()
+            .cycle()
+            .skip(delta)
+            .map(|c| c.to_digit(10).unwrap());
+        digits
+            .zip(ahead)
+            .filter(|(a, b)| a == b)
+            .map(|(a, _)| a)
+            .sum()
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day01>(7, 1000);
+        assert_eq!(input, generate::<Day01>(7, 1000));
+        assert_ne!(input, generate::<Day01>(8, 1000));
+        assert_eq!(Day01::parse(&input).unwrap().len(), 1000);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_delta_zero_is_digit_sum(captcha in "[0-9]{0,200}") {
+            let sum = captcha.chars().map(|c| c.to_digit(10).unwrap()).sum::<u32>();
+            prop_assert_eq!(calculate_captcha(&captcha, 0).unwrap(), sum);
+            prop_assert_eq!(calculate_captcha(&captcha, captcha.len()).unwrap(), sum);
+        }
+
+        #[test]
+        fn prop_halfway_is_even(captcha in "([0-9]{2}){1,100}") {
+            // Every matching digit is matched by the digit halfway around too.
+            prop_assert_eq!(calculate_captcha(&captcha, captcha.len() / 2).unwrap() % 2, 0);
+        }
+
+        #[test]
+        fn prop_matches_naive(captcha in "[0-9]{1,200}", delta in 0usize..400) {
+            prop_assert_eq!(
+                calculate_captcha(&captcha, delta).unwrap(),
+                naive_captcha(&captcha, delta)
+            );
+        }
+
+        #[test]
+        fn prop_stream_matches(captcha in "[0-9]{0,100}", delta in 0usize..150) {
+            let expected = u64::from(calculate_captcha(&captcha, delta).unwrap());
+            prop_assert_eq!(stream_captcha(Trickle(captcha.as_bytes()), delta).unwrap(), expected);
+            let halfway = u64::from(calculate_captcha(&captcha, captcha.len() / 2).unwrap());
+            prop_assert_eq!(stream_captcha_halfway(io::Cursor::new(&captcha)).unwrap(), halfway);
+        }
+
+        #[test]
+        fn prop_sums_match(captcha in "[0-9]{0,100}", deltas in prop::collection::vec(0usize..150, 0..10)) {
+            let sums = Captcha::parse(&captcha, 10).unwrap().sums(&deltas);
+            for (&delta, &sum) in deltas.iter().zip(&sums) {
+                prop_assert_eq!(sum, u64::from(calculate_captcha(&captcha, delta).unwrap()));
+            }
+        }
+
+        #[test]
+        fn prop_rejects_non_digits(captcha in "[0-9]{0,10}[a-z][0-9]{0,10}") {
+            prop_assert!(calculate_captcha(&captcha, 0).is_err());
+            prop_assert!(Day01::parse(&captcha).is_err());
+        }
+    }
+}
@@ -0,0 +1,738 @@
+//! Day 2: Corruption Checksum.
+//!
+//! Checksums of a spreadsheet of whitespace separated numbers, a row per line.
+//! Spreadsheets exported as CSV or TSV can be read too, see `read`.
+
+use aoc_common::parse::{lines, Line};
+use aoc_common::trace::NoTrace;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer};
+use std::borrow::Cow;
+use std::str::FromStr;
+
+/// The numbers of each row of a spreadsheet.
+pub type Spreadsheet = Vec<Vec<i64>>;
+
+/// How the cells of a row are separated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Any whitespace, like the puzzle input.
+    Whitespace,
+    /// Commas, where a cell may be quoted in double quotes, with `""` for a
+    /// quote inside.
+    Csv,
+    /// Tabs, quoted like CSV.
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "whitespace" => Ok(Format::Whitespace),
+            "csv" => Ok(Format::Csv),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(format!(
+                "unknown format `{}`, expected whitespace, csv or tsv",
+                s
+            )),
+        }
+    }
+}
+
+/// How to read a spreadsheet.
+#[derive(Debug, Clone, Copy)]
+pub struct ReadOptions {
+    pub format: Format,
+    /// Whether the first row names the columns instead of holding numbers.
+    pub header: bool,
+    /// Whether to skip the cells that aren't integers instead of failing.
+    pub skip_invalid: bool,
+}
+
+/// A spreadsheet read by `read`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    /// The names of the columns, if the spreadsheet has a header.
+    pub header: Option<Vec<String>>,
+    pub rows: Spreadsheet,
+    /// The empty rows and the cells left out of the rows, with where they
+    /// are.
+    pub skipped: Vec<ParseError>,
+}
+
+// A cell of a row, as it is on the line and with the quotes taken out.
+struct Cell<'a> {
+    text: &'a str,
+    value: Cow<'a, str>,
+}
+
+// Splits a row into its cells.
+fn cells<'a>(line: &Line<'a>, format: Format) -> Result<Vec<Cell<'a>>, ParseError> {
+    let separator = match format {
+        Format::Whitespace => {
+            let cells = line.text.split_whitespace();
+            return Ok(cells
+                .map(|text| Cell {
+                    text,
+                    value: Cow::Borrowed(text),
+                })
+                .collect());
+        }
+        Format::Csv => ',',
+        Format::Tsv => '\t',
+    };
+    let mut cells = vec![];
+    let mut rest = line.text;
+    loop {
+        let start = rest.trim_start_matches(' ');
+        let Some(quoted) = start.strip_prefix('"') else {
+            // Everything up to the next separator.
+            let (text, next) = match rest.split_once(separator) {
+                Some((text, next)) => (text, Some(next)),
+                None => (rest, None),
+            };
+            let text = text.trim_matches(' ');
+            cells.push(Cell {
+                text,
+                value: Cow::Borrowed(text),
+            });
+            match next {
+                Some(next) => rest = next,
+                None => return Ok(cells),
+            }
+            continue;
+        };
+        let mut value = String::new();
+        let mut chars = quoted.char_indices();
+        let end = loop {
+            match chars.next() {
+                Some((i, '"')) if quoted[i + 1..].starts_with('"') => {
+                    value.push('"');
+                    chars.next();
+                }
+                Some((i, '"')) => break i,
+                Some((_, c)) => value.push(c),
+                None => return Err(line.error(start, "unterminated quote")),
+            }
+        };
+        cells.push(Cell {
+            text: &start[..end + 2],
+            value: Cow::Owned(value),
+        });
+        let after = quoted[end + 1..].trim_start_matches(' ');
+        match after.strip_prefix(separator) {
+            Some(next) => rest = next,
+            None if after.is_empty() => return Ok(cells),
+            None => {
+                let message = format!("expected `{}` after a quote", separator.escape_default());
+                return Err(line.error(after, message));
+            }
+        }
+    }
+}
+
+/// Reads a spreadsheet of integers, failing on the first cell that isn't one
+/// unless told to skip those. Empty rows and empty cells are skipped, and
+/// everything skipped is listed with its line and column in `skipped`.
+pub fn read(input: &str, options: ReadOptions) -> Result<Table, ParseError> {
+    let mut table = Table {
+        header: None,
+        rows: vec![],
+        skipped: vec![],
+    };
+    for line in lines(input.trim_end()) {
+        if line.text.trim().is_empty() {
+            table.skipped.push(line.invalid("empty row"));
+            continue;
+        }
+        let cells = cells(&line, options.format)?;
+        if options.header && table.header.is_none() {
+            let names = cells.iter().map(|cell| cell.value.trim().to_owned());
+            table.header = Some(names.collect());
+            continue;
+        }
+        let mut row = vec![];
+        for (i, cell) in cells.iter().enumerate() {
+            let column = match table.header.as_ref().and_then(|names| names.get(i)) {
+                Some(name) => format!("`{}`", name),
+                None => (i + 1).to_string(),
+            };
+            let value = cell.value.trim();
+            if value.is_empty() {
+                let error = line.error(cell.text, format!("empty cell in column {}", column));
+                table.skipped.push(error);
+                continue;
+            }
+            match value.parse() {
+                Ok(number) => row.push(number),
+                Err(_) => {
+                    let error =
+                        line.error(cell.text, format!("invalid number in column {}", column));
+                    if !options.skip_invalid {
+                        return Err(error);
+                    }
+                    table.skipped.push(error);
+                }
+            }
+        }
+        table.rows.push(row);
+    }
+    Ok(table)
+}
+
+/// A rule for the checksum of a single row. The checksum of a spreadsheet is
+/// the sum over its rows.
+pub trait RowChecksum {
+    /// The checksum of the row, which may be empty.
+    fn row(&self, row: &[i64]) -> i64;
+
+    /// The sum of the checksums of the rows, stopping at the limits of
+    /// `i64`.
+    fn checksum(&self, spreadsheet: &[Vec<i64>]) -> i64 {
+        self.checksum_traced(spreadsheet, &mut NoTrace)
+    }
+
+    /// Like `checksum`, passing the checksum of each row to the tracer as a
+    /// `row` event along with the sum so far.
+    fn checksum_traced(&self, spreadsheet: &[Vec<i64>], tracer: &mut dyn Tracer) -> i64 {
+        let mut sum = 0i64;
+        for (i, row) in spreadsheet.iter().enumerate() {
+            let checksum = self.row(row);
+            sum = sum.saturating_add(checksum);
+            if tracer.enabled() {
+                tracer.event(
+                    Event::new("row")
+                        .with("row", i)
+                        .with("checksum", checksum)
+                        .with("sum", sum),
+                );
+            }
+        }
+        sum
+    }
+}
+
+/// The difference between the largest and the smallest value, for part 1.
+pub struct MaxMin;
+
+impl RowChecksum for MaxMin {
+    fn row(&self, row: &[i64]) -> i64 {
+        match (row.iter().max(), row.iter().min()) {
+            (Some(max), Some(min)) => max.saturating_sub(*min),
+            _ => 0,
+        }
+    }
+}
+
+/// The result of dividing the only two evenly divisible values, for part 2,
+/// where the divisor is the smaller of the two in size. If more values
+/// divide, the result largest in size counts.
+pub struct EvenDivision;
+
+impl RowChecksum for EvenDivision {
+    fn row(&self, row: &[i64]) -> i64 {
+        let mut quotient = 0i64;
+        for c1 in row {
+            for c2 in row {
+                // Zero divides nothing, and only `i64::MIN / -1` overflows.
+                let divides = *c1 != 0 && c2.checked_rem(*c1).unwrap_or(0) == 0;
+                if divides && c1.unsigned_abs() < c2.unsigned_abs() {
+                    let q = c2.checked_div(*c1).unwrap_or(i64::MAX);
+                    if q.unsigned_abs() > quotient.unsigned_abs() {
+                        quotient = q;
+                    }
+                }
+            }
+        }
+        quotient
+    }
+}
+
+/// The middle value, or the lower of the two middle values of a row of even
+/// length.
+pub struct Median;
+
+impl RowChecksum for Median {
+    fn row(&self, row: &[i64]) -> i64 {
+        if row.is_empty() {
+            return 0;
+        }
+        let middle = (row.len() - 1) / 2;
+        let mut row = row.to_vec();
+        let (_, &mut median, _) = row.select_nth_unstable(middle);
+        median
+    }
+}
+
+/// The greatest common divisor of the sizes of the values, where 0 is
+/// divided by anything.
+pub struct Gcd;
+
+impl RowChecksum for Gcd {
+    fn row(&self, row: &[i64]) -> i64 {
+        let gcd = |mut a: u64, mut b: u64| {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        };
+        let gcd = row
+            .iter()
+            .fold(0, |acc, &value| gcd(acc, value.unsigned_abs()));
+        i64::try_from(gcd).unwrap_or(i64::MAX)
+    }
+}
+
+/// The sum of the differences between every pair of values, stopping at
+/// `i64::MAX`.
+pub struct PairwiseDifferences;
+
+impl RowChecksum for PairwiseDifferences {
+    fn row(&self, row: &[i64]) -> i64 {
+        // After sorting, each value is the larger of the pairs with the
+        // values before it and the smaller of the pairs with those after.
+        let mut row = row.to_vec();
+        row.sort_unstable();
+        let len = row.len() as i128;
+        let sum = row
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| (2 * i as i128 - len + 1).saturating_mul(i128::from(value)))
+            .fold(0, i128::saturating_add);
+        i64::try_from(sum).unwrap_or(i64::MAX)
+    }
+}
+
+/// The rules by the names they are given on the command line.
+pub const RULES: [(&str, &dyn RowChecksum); 5] = [
+    ("max-min", &MaxMin),
+    ("even-division", &EvenDivision),
+    ("median", &Median),
+    ("gcd", &Gcd),
+    ("pairwise", &PairwiseDifferences),
+];
+
+/// The sum of the checksums of several rules for each row.
+pub struct Combined(pub Vec<&'static dyn RowChecksum>);
+
+impl RowChecksum for Combined {
+    fn row(&self, row: &[i64]) -> i64 {
+        self.0
+            .iter()
+            .map(|rule| rule.row(row))
+            .fold(0, i64::saturating_add)
+    }
+}
+
+/// Looks up a rule by its name in `RULES`, or the rules joined by `+` to add
+/// them up, like `max-min+gcd`.
+pub fn rule(spec: &str) -> Result<Combined, String> {
+    spec.split('+')
+        .map(|name| {
+            RULES
+                .iter()
+                .find(|&&(n, _)| n == name.trim())
+                .map(|&(_, rule)| rule)
+                .ok_or_else(|| {
+                    let names = RULES.map(|(name, _)| name).join(", ");
+                    format!("unknown checksum `{}`, expected {}", name, names)
+                })
+        })
+        .collect::<Result<_, _>>()
+        .map(Combined)
+}
+
+/// Sums the difference between the largest and the smallest value of each row,
+/// failing on anything that isn't a number.
+pub fn calculate_checksum(spreadsheet: &str) -> Result<i64, ParseError> {
+    Ok(MaxMin.checksum(&Day02::parse(spreadsheet)?))
+}
+
+/// Sums the result of dividing the only two evenly divisible values of each
+/// row, failing on anything that isn't a number.
+pub fn calculate_checksum_part2(spreadsheet: &str) -> Result<i64, ParseError> {
+    Ok(EvenDivision.checksum(&Day02::parse(spreadsheet)?))
+}
+
+/// Day 2: Corruption Checksum.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Spreadsheet;
+
+    fn parse(input: &str) -> Result<Spreadsheet, ParseError> {
+        let options = ReadOptions {
+            format: Format::Whitespace,
+            header: false,
+            skip_invalid: false,
+        };
+        let table = read(input, options)?;
+        // Finding the largest value of an empty row used to panic.
+        match table.skipped.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(table.rows),
+        }
+    }
+
+    fn part1(input: &Spreadsheet) -> Answer {
+        MaxMin.checksum(input).into()
+    }
+
+    fn part2(input: &Spreadsheet) -> Answer {
+        EvenDivision.checksum(input).into()
+    }
+}
+
+impl Generate for Day02 {
+    const SIZE: usize = 16;
+
+    /// A spreadsheet of `size` rows of 16 numbers, with a single pair of
+    /// numbers dividing evenly on each row.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut rows = vec![];
+        for _ in 0..size {
+            // No two different numbers from 1000 up to 2000 divide each other.
+            let mut row = vec![];
+            while row.len() < 15 {
+                let number = rng.range(1000..=1999) as u32;
+                if !row.contains(&number) {
+                    row.push(number);
+                }
+            }
+            loop {
+                let multiple = row[rng.below(row.len())] * rng.range(2..=9) as u32;
+                if row.iter().filter(|&&e| multiple.is_multiple_of(e)).count() == 1 {
+                    row.push(multiple);
+                    break;
+                }
+            }
+            rng.shuffle(&mut row);
+            let row = row.iter().map(u32::to_string).collect::<Vec<_>>();
+            rows.push(row.join("\t"));
+        }
+        rows.join("\n")
+    }
+}
+
+impl Trace for Day02 {
+    fn trace(input: &Spreadsheet, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        match part {
+            1 => MaxMin.checksum_traced(input, tracer).into(),
+            _ => EvenDivision.checksum_traced(input, tracer).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(
+            calculate_checksum(
+                "5 1 9 5
+7 5 3
+2 4 6 8"
+            ),
+            Ok(18)
+        );
+    }
+
+    #[test]
+    fn part1_result() {
+        assert_eq!(calculate_checksum(&get_input()), Ok(45158))
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(
+            calculate_checksum_part2(
+                "5 9 2 8
+9 4 7 3
+3 8 6 5"
+            ),
+            Ok(9)
+        );
+    }
+
+    #[test]
+    fn part2_result() {
+        assert_eq!(calculate_checksum_part2(&get_input()), Ok(294))
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day02::parse("5 1 9 5\n7 x 3").unwrap_err(),
+            ParseError::new(2, 3, "x", "invalid number in column 2")
+        );
+        // Finding the largest value of an empty row used to panic.
+        assert_eq!(
+            Day02::parse("5 1 9 5\n\n2 4 6 8\n").unwrap_err(),
+            ParseError::new(2, 1, "", "empty row")
+        );
+        // The checksums used to skip the cells that weren't numbers.
+        assert_eq!(
+            calculate_checksum("5 1 9 5\n7 x 3"),
+            Err(ParseError::new(2, 3, "x", "invalid number in column 2"))
+        );
+    }
+
+    #[test]
+    fn test_zero() {
+        // Found by fuzzing, dividing by a zero used to panic.
+        assert_eq!(calculate_checksum_part2("5 9 2 8\n0 4 7 3\n3 8 6 5"), Ok(6));
+    }
+
+    #[test]
+    fn test_large_sums() {
+        // Found by fuzzing, the sums used to overflow.
+        let row = "4294967295 0 1\n";
+        assert_eq!(calculate_checksum(&row.repeat(3)), Ok(3 * 4294967295));
+        assert_eq!(calculate_checksum_part2(&row.repeat(3)), Ok(3 * 4294967295));
+    }
+
+    #[test]
+    fn test_rules() {
+        let rows = Day02::parse("5 1 9 5\n7 5 3\n2 4 6 8\n12 18 0").unwrap();
+        let checksums = RULES.map(|(name, rule)| (name, rule.checksum(&rows)));
+        assert_eq!(
+            checksums,
+            [
+                ("max-min", 8 + 4 + 6 + 18),
+                ("even-division", 9 + 4),
+                ("median", 5 + 5 + 4 + 12),
+                ("gcd", 1 + 1 + 2 + 6),
+                ("pairwise", 24 + 8 + 20 + 36),
+            ]
+        );
+        for (_, rule) in RULES {
+            assert_eq!(rule.row(&[]), 0);
+        }
+        assert_eq!(Median.row(&[7]), 7);
+        assert_eq!(Gcd.row(&[0, 0]), 0);
+    }
+
+    #[test]
+    fn test_signed_rules() {
+        let row = [-2, 8, 3, -5];
+        assert_eq!(MaxMin.row(&row), 13);
+        assert_eq!(EvenDivision.row(&row), -4);
+        assert_eq!(Median.row(&row), -2);
+        assert_eq!(Gcd.row(&[-4, 6]), 2);
+        assert_eq!(PairwiseDifferences.row(&row), 10 + 5 + 3 + 5 + 13 + 8);
+        assert_eq!(MaxMin.checksum(&[vec![-3, -1], vec![4]]), 2);
+    }
+
+    #[test]
+    fn test_rules_saturate() {
+        let row = [i64::MAX, i64::MIN, -1, 0];
+        assert_eq!(MaxMin.row(&row), i64::MAX);
+        assert_eq!(EvenDivision.row(&[i64::MIN, -1]), i64::MAX);
+        assert_eq!(Gcd.row(&[i64::MIN, 0]), i64::MAX);
+        assert_eq!(PairwiseDifferences.row(&row), i64::MAX);
+        assert_eq!(MaxMin.checksum(&[row.to_vec(), vec![0, 4]]), i64::MAX);
+    }
+
+    fn options(format: Format, header: bool) -> ReadOptions {
+        ReadOptions {
+            format,
+            header,
+            skip_invalid: false,
+        }
+    }
+
+    #[test]
+    fn test_read_csv() {
+        let csv = "name, \"x, y\" ,z\n\"a\"\"b\",-3,+4\n 7 , \"12\" ,\n\n1,,2\n";
+        // The first cell of the second row is `a"b`.
+        let invalid = ParseError::new(2, 1, "\"a\"\"b\"", "invalid number in column `name`");
+        assert_eq!(read(csv, options(Format::Csv, true)).unwrap_err(), invalid);
+        let options = ReadOptions {
+            skip_invalid: true,
+            ..options(Format::Csv, true)
+        };
+        let table = read(csv, options).unwrap();
+        assert_eq!(
+            table.header,
+            Some(vec!["name".to_owned(), "x, y".to_owned(), "z".to_owned()])
+        );
+        assert_eq!(table.rows, [vec![-3, 4], vec![7, 12], vec![1, 2]]);
+        assert_eq!(
+            table.skipped,
+            [
+                invalid,
+                ParseError::new(3, 12, "", "empty cell in column `z`"),
+                ParseError::new(4, 1, "", "empty row"),
+                ParseError::new(5, 3, "", "empty cell in column `x, y`"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_tsv() {
+        let table = read("5\t1\t\"9\"\t5\n7 \t5\t3", options(Format::Tsv, false)).unwrap();
+        assert_eq!(table.header, None);
+        assert_eq!(MaxMin.checksum(&table.rows), 12);
+        let table = read("5 1\t2", options(Format::Tsv, false));
+        assert_eq!(
+            table.unwrap_err(),
+            ParseError::new(1, 1, "5 1", "invalid number in column 1")
+        );
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let read = |input| read(input, options(Format::Csv, false)).unwrap_err();
+        assert_eq!(
+            read("1,2\n3,x"),
+            ParseError::new(2, 3, "x", "invalid number in column 2")
+        );
+        assert_eq!(
+            read("1,\"2"),
+            ParseError::new(1, 3, "\"2", "unterminated quote")
+        );
+        assert_eq!(
+            read("1,\"2\"3,4"),
+            ParseError::new(1, 6, "3,4", "expected `,` after a quote")
+        );
+        assert_eq!(
+            super::read("\"1\" 2", options(Format::Tsv, false)).unwrap_err(),
+            ParseError::new(1, 5, "2", "expected `\\t` after a quote")
+        );
+    }
+
+    #[test]
+    fn test_combined_rules() {
+        let rows = Day02::parse("5 1 9 5\n7 5 3\n2 4 6 8").unwrap();
+        assert_eq!(rule("max-min").unwrap().checksum(&rows), 18);
+        assert_eq!(rule("max-min+median").unwrap().checksum(&rows), 18 + 14);
+        assert_eq!(rule("gcd + gcd").unwrap().checksum(&rows), 8);
+        assert_eq!(
+            rule("max-min+mean").err().unwrap(),
+            "unknown checksum `mean`, expected max-min, even-division, median, gcd, pairwise"
+        );
+        assert!(rule("").is_err());
+    }
+
+    fn spreadsheet<T: ToString>(rows: &[Vec<T>]) -> String {
+        rows.iter()
+            .map(|row| {
+                row.iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>()
+                    .join("\t")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let spreadsheet = vec![vec![5, 9, 2, 8], vec![9, 4, 7, 3]];
+        assert_eq!(
+            Day02::trace(&spreadsheet, 2, &mut events),
+            Answer::Number(7)
+        );
+        let row = |row: usize, checksum: i64, sum: i64| {
+            Event::new("row")
+                .with("row", row)
+                .with("checksum", checksum)
+                .with("sum", sum)
+        };
+        assert_eq!(events, [row(0, 4, 4), row(1, 3, 7)]);
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = Day02::parse(&generate::<Day02>(3, 50)).unwrap();
+        assert_eq!(input.len(), 50);
+        for row in &input {
+            // Every row divides into 2 up to 9.
+            let quotient = EvenDivision.row(row);
+            assert!((2..=9).contains(&quotient), "{:?}", row);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_rules_match_naive(row in prop::collection::vec(-10_000i64..10_000, 0..30)) {
+            let mut sorted = row.clone();
+            sorted.sort();
+            let pairs = row
+                .iter()
+                .flat_map(|&a| row.iter().map(move |&b| (a - b).abs()))
+                .sum::<i64>();
+            prop_assert_eq!(PairwiseDifferences.row(&row), pairs / 2);
+            if !row.is_empty() {
+                prop_assert_eq!(Median.row(&row), sorted[(row.len() - 1) / 2]);
+            }
+            let gcd = Gcd.row(&row);
+            prop_assert!(row.iter().all(|&value| value % gcd.max(1) == 0));
+            prop_assert_eq!(
+                rule("max-min+pairwise").unwrap().row(&row),
+                MaxMin.row(&row) + PairwiseDifferences.row(&row)
+            );
+        }
+
+        #[test]
+        fn prop_checksum_matches_sorted(
+            rows in prop::collection::vec(prop::collection::vec(-10_000i64..10_000, 1..16), 0..16)
+        ) {
+            let expected = rows
+                .iter()
+                .map(|row| {
+                    let mut row = row.clone();
+                    row.sort();
+                    row[row.len() - 1] - row[0]
+                })
+                .sum::<i64>();
+            prop_assert_eq!(calculate_checksum(&spreadsheet(&rows)), Ok(expected));
+        }
+
+        #[test]
+        fn prop_checksum_ignores_order(
+            mut rows in prop::collection::vec(prop::collection::vec(1u32..10_000, 1..16), 0..16)
+        ) {
+            let before = (
+                calculate_checksum(&spreadsheet(&rows)),
+                calculate_checksum_part2(&spreadsheet(&rows)),
+            );
+            rows.reverse();
+            rows.iter_mut().for_each(|row| row.reverse());
+            let after = (
+                calculate_checksum(&spreadsheet(&rows)),
+                calculate_checksum_part2(&spreadsheet(&rows)),
+            );
+            prop_assert_eq!(before, after);
+        }
+
+        #[test]
+        fn prop_even_division(
+            rows in prop::collection::vec((2u32..1000, 2u32..50, 0usize..8), 1..16)
+        ) {
+            // Each row is a number and a multiple of it, among primes too large
+            // to divide anything else in the row.
+            let primes = [1009, 1013, 1019, 1021, 1031, 1033];
+            let mut expected = 0;
+            let rows = rows
+                .iter()
+                .map(|&(n, factor, at)| {
+                    expected += i64::from(factor);
+                    let mut row = primes.to_vec();
+                    row.insert(at.min(row.len()), n);
+                    row.insert((at * 3).min(row.len()), n * factor);
+                    row
+                })
+                .collect::<Vec<_>>();
+            prop_assert_eq!(calculate_checksum_part2(&spreadsheet(&rows)), Ok(expected));
+        }
+    }
+}
@@ -0,0 +1,325 @@
+//! Day 3: Spiral Memory.
+//!
+//! Squares numbered in a spiral outwards from square 1.
+
+use aoc_common::parse::lines;
+use aoc_common::trace::NoTrace;
+use aoc_common::visualize::{faint, highlight};
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer, Visualize};
+use grid::{render_window, Direction, Point, SparseGrid};
+
+// The direction to move in after `point`, turning left at the corners of
+// each ring of the spiral.
+fn next_direction(point: Point, dir: Direction) -> Direction {
+    let Point { x, y } = point;
+    let corner = match dir {
+        Direction::Right => x == y + 1,
+        Direction::Up => x == -y,
+        Direction::Left => x == y,
+        Direction::Down => -x == y,
+    };
+    if corner {
+        dir.turn_left()
+    } else {
+        dir
+    }
+}
+
+/// The Manhattan distance from square `input` back to square 1.
+pub fn calculate_manhattan_distance(input: u32) -> u32 {
+    calculate_manhattan_distance_traced(input, &mut NoTrace)
+}
+
+/// Like `calculate_manhattan_distance`, passing each square of the spiral to
+/// the tracer as a `square` event with its position.
+pub fn calculate_manhattan_distance_traced(input: u32, tracer: &mut dyn Tracer) -> u32 {
+    let mut dir = Direction::Right;
+    let mut point = Point::ORIGIN;
+    for square in 2..=input {
+        dir = next_direction(point, dir);
+        point = point.step(dir);
+        if tracer.enabled() {
+            tracer.event(
+                Event::new("square")
+                    .with("square", square)
+                    .with("x", point.x)
+                    .with("y", point.y),
+            );
+        }
+    }
+    point.manhattan_distance(Point::ORIGIN)
+}
+
+/// The first value written that is larger than `input`, when each square is
+/// filled with the sum of its already filled neighbours.
+pub fn calculate_manhattan_distance_part2(input: u32) -> u32 {
+    calculate_manhattan_distance_part2_traced(input, &mut NoTrace)
+}
+
+/// Like `calculate_manhattan_distance_part2`, passing each value written to
+/// the tracer as a `write` event with the position of its square.
+pub fn calculate_manhattan_distance_part2_traced(input: u32, tracer: &mut dyn Tracer) -> u32 {
+    let mut grid = SparseGrid::new();
+    grid.insert(Point::ORIGIN, 1);
+    let mut dir = Direction::Right;
+    let mut point = Point::ORIGIN;
+    loop {
+        dir = next_direction(point, dir);
+        point = point.step(dir);
+
+        // Calculate the value for the point.
+        let value = point
+            .neighbours8()
+            .filter_map(|neighbour| grid.get(neighbour))
+            .sum();
+
+        if tracer.enabled() {
+            tracer.event(
+                Event::new("write")
+                    .with("x", point.x)
+                    .with("y", point.y)
+                    .with("value", value),
+            );
+        }
+
+        // Check if we've hit the limit yet.
+        if value > input {
+            return value;
+        }
+
+        // Add the value to the grid.
+        grid.insert(point, value);
+    }
+}
+
+/// Day 3: Spiral Memory.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = u32;
+
+    fn parse(input: &str) -> Result<u32, ParseError> {
+        let line = lines(input.trim())
+            .next()
+            .ok_or_else(|| ParseError::new(1, 1, "", "missing square"))?;
+        line.parse(line.text, "square")
+    }
+
+    fn part1(input: &u32) -> Answer {
+        calculate_manhattan_distance(*input).into()
+    }
+
+    fn part2(input: &u32) -> Answer {
+        calculate_manhattan_distance_part2(*input).into()
+    }
+}
+
+impl Visualize for Day03 {
+    /// Walks the spiral out to the input square, a square per frame.
+    fn frames(input: &u32) -> Box<dyn Iterator<Item = String> + '_> {
+        let mut visited = SparseGrid::new();
+        let mut dir = Direction::Right;
+        let mut point = Point::ORIGIN;
+        Box::new((1..=*input).map(move |square| {
+            if square > 1 {
+                dir = next_direction(point, dir);
+                point = point.step(dir);
+            }
+            visited.insert(point, square);
+            // Squares are two characters wide, to look square in a terminal.
+            let window = render_window(point, 31, 21, |p| match visited.get(p) {
+                _ if p == point => highlight("[]"),
+                Some(1) => "()".to_owned(),
+                Some(_) => faint("[]"),
+                None => "  ".to_owned(),
+            });
+            format!(
+                "Square {} at ({}, {}), {} steps from square 1\n{}",
+                square,
+                point.x,
+                point.y,
+                point.manhattan_distance(Point::ORIGIN),
+                window
+            )
+        }))
+    }
+}
+
+impl Generate for Day03 {
+    const SIZE: usize = 277678;
+
+    /// A square from half of `size` up to `size`.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let square = rng.range(size as i64 / 2 + 1..=size.max(1) as i64);
+        square.to_string()
+    }
+}
+
+impl Trace for Day03 {
+    fn trace(input: &u32, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        match part {
+            1 => calculate_manhattan_distance_traced(*input, tracer).into(),
+            _ => calculate_manhattan_distance_part2_traced(*input, tracer).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::trace::Value;
+    use aoc_common::visualize::strip_styles;
+    use proptest::prelude::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(calculate_manhattan_distance(1), 0);
+        assert_eq!(calculate_manhattan_distance(12), 3);
+        assert_eq!(calculate_manhattan_distance(23), 2);
+        assert_eq!(calculate_manhattan_distance(1024), 31);
+    }
+
+    #[test]
+    fn part1_result() {
+        assert_eq!(calculate_manhattan_distance(277678), 475);
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(calculate_manhattan_distance_part2(1), 2);
+        assert_eq!(calculate_manhattan_distance_part2(2), 4);
+        assert_eq!(calculate_manhattan_distance_part2(3), 4);
+        assert_eq!(calculate_manhattan_distance_part2(4), 5);
+        assert_eq!(calculate_manhattan_distance_part2(5), 10);
+    }
+
+    #[test]
+    fn part2_result() {
+        assert_eq!(calculate_manhattan_distance_part2(277678), 279138);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        assert_eq!(Day03::trace(&4, 2, &mut events), Answer::Number(5));
+        let write = |x: i64, y: i64, value: u32| {
+            Event::new("write")
+                .with("x", x)
+                .with("y", y)
+                .with("value", value)
+        };
+        assert_eq!(
+            events,
+            [
+                write(1, 0, 1),
+                write(1, -1, 2),
+                write(0, -1, 4),
+                write(-1, -1, 5)
+            ]
+        );
+        events.clear();
+        assert_eq!(Day03::trace(&3, 1, &mut events), Answer::Number(2));
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].get("y"), Some(&Value::Number(-1)));
+    }
+
+    #[test]
+    fn test_frames() {
+        let frames = Day03::frames(&12)
+            .map(|frame| strip_styles(&frame))
+            .collect::<Vec<_>>();
+        assert_eq!(frames.len(), 12);
+        assert!(frames[11].starts_with("Square 12 at (2, -1), 3 steps from square 1\n"));
+        // The window is centred on the last square, with square 1 two
+        // squares to the left and one down.
+        let rows = frames[11].lines().skip(1).collect::<Vec<_>>();
+        assert_eq!(&rows[10][22..34], "  [][][][]  ");
+        assert_eq!(&rows[11][22..34], "  []()[][]  ");
+    }
+
+    // The squares of the spiral in order, walking each ring as four sides.
+    fn naive_spiral(squares: usize) -> Vec<(i32, i32)> {
+        let mut result = vec![(0, 0)];
+        let mut ring = 1;
+        while result.len() < squares {
+            let (mut x, mut y) = (ring, ring - 1);
+            result.push((x, y));
+            for (dx, dy, count) in [
+                (0, -1, 2 * ring - 1),
+                (-1, 0, 2 * ring),
+                (0, 1, 2 * ring),
+                (1, 0, 2 * ring),
+            ] {
+                for _ in 0..count {
+                    x += dx;
+                    y += dy;
+                    result.push((x, y));
+                }
+            }
+            ring += 1;
+        }
+        result.truncate(squares);
+        result
+    }
+
+    #[test]
+    fn test_naive_spiral() {
+        assert_eq!(
+            naive_spiral(10),
+            [
+                (0, 0),
+                (1, 0),
+                (1, -1),
+                (0, -1),
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+                (2, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let square = Day03::parse(&generate::<Day03>(seed, 1000)).unwrap();
+            assert!((501..=1000).contains(&square));
+        }
+        assert_eq!(Day03::parse(&generate::<Day03>(0, 0)).unwrap(), 1);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_distance_matches_naive(square in 1u32..5000) {
+            let (x, y) = naive_spiral(square as usize)[square as usize - 1];
+            prop_assert_eq!(calculate_manhattan_distance(square), (x.abs() + y.abs()) as u32);
+        }
+
+        #[test]
+        fn prop_stress_test_matches_naive(input in 0u32..1_000_000) {
+            let mut values = HashMap::new();
+            values.insert((0, 0), 1);
+            let mut expected = 1;
+            for &(x, y) in &naive_spiral(1000)[1..] {
+                let mut value = 0;
+                for dx in -1..=1 {
+                    for dy in -1..=1 {
+                        value += values.get(&(x + dx, y + dy)).unwrap_or(&0);
+                    }
+                }
+                values.insert((x, y), value);
+                if value > input {
+                    expected = value;
+                    break;
+                }
+            }
+            prop_assert!(expected > input);
+            prop_assert_eq!(calculate_manhattan_distance_part2(input), expected);
+        }
+    }
+}
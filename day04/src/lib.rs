@@ -0,0 +1,224 @@
+//! Day 4: High-Entropy Passphrases.
+
+use aoc_common::trace::NoTrace;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer};
+use std::collections::HashSet;
+
+/// Whether the passphrase contains no duplicate words.
+pub fn valid_passphrase(passphrase: &str) -> bool {
+    let mut visited = HashSet::new();
+    for word in passphrase.split_whitespace() {
+        if visited.contains(word) {
+            return false;
+        }
+        visited.insert(word);
+    }
+    true
+}
+
+/// Whether the passphrase contains no two words that are anagrams of each
+/// other.
+pub fn valid_passphrase_part2(passphrase: &str) -> bool {
+    let mut visited = HashSet::new();
+    for word in passphrase.split_whitespace() {
+        let mut word = word.chars().collect::<Vec<_>>();
+        word.sort();
+        let word = word.iter().collect::<String>();
+        if visited.contains(&word) {
+            return false;
+        }
+        visited.insert(word);
+    }
+    true
+}
+
+/// Counts the passphrases, one per line, that are valid according to `func`.
+pub fn valid_passphrases(passphrases: &str, func: &dyn Fn(&str) -> bool) -> usize {
+    valid_passphrases_traced(passphrases, func, &mut NoTrace)
+}
+
+/// Like `valid_passphrases`, passing each passphrase checked to the tracer as
+/// a `passphrase` event with its line, whether it is valid and the count so
+/// far.
+pub fn valid_passphrases_traced(
+    passphrases: &str,
+    func: &dyn Fn(&str) -> bool,
+    tracer: &mut dyn Tracer,
+) -> usize {
+    let mut count = 0;
+    for (i, passphrase) in passphrases.lines().enumerate() {
+        let valid = func(passphrase);
+        count += valid as usize;
+        if tracer.enabled() {
+            tracer.event(
+                Event::new("passphrase")
+                    .with("line", i + 1)
+                    .with("valid", valid as u8)
+                    .with("count", count),
+            );
+        }
+    }
+    count
+}
+
+/// Day 4: High-Entropy Passphrases.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &String) -> Answer {
+        valid_passphrases(input, &valid_passphrase).into()
+    }
+
+    fn part2(input: &String) -> Answer {
+        valid_passphrases(input, &valid_passphrase_part2).into()
+    }
+}
+
+impl Generate for Day04 {
+    const SIZE: usize = 511;
+
+    /// `size` passphrases, some repeating a word and some with anagrams.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut lines = vec![];
+        for _ in 0..size {
+            let mut words = (0..rng.range(3..=10))
+                .map(|_| rng.word(2..=7))
+                .collect::<Vec<_>>();
+            if rng.one_in(4) {
+                let word = rng.choose(&words).clone();
+                words.push(word);
+            }
+            if rng.one_in(4) {
+                let mut anagram = rng.choose(&words).chars().collect::<Vec<_>>();
+                rng.shuffle(&mut anagram);
+                words.push(anagram.into_iter().collect());
+            }
+            rng.shuffle(&mut words);
+            lines.push(words.join(" "));
+        }
+        lines.join("\n")
+    }
+}
+
+impl Trace for Day04 {
+    fn trace(input: &String, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        match part {
+            1 => valid_passphrases_traced(input, &valid_passphrase, tracer).into(),
+            _ => valid_passphrases_traced(input, &valid_passphrase_part2, tracer).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_result() {
+        assert_eq!(valid_passphrases(&get_input(), &valid_passphrase), 337);
+    }
+
+    #[test]
+    fn part1_examples() {
+        assert!(valid_passphrase("aa bb cc dd ee"));
+        assert!(!valid_passphrase("aa bb cc dd aa"));
+        assert!(valid_passphrase("aa bb cc dd aaa"));
+    }
+
+    #[test]
+    fn part2_result() {
+        assert_eq!(
+            valid_passphrases(&get_input(), &valid_passphrase_part2),
+            231
+        );
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert!(valid_passphrase_part2("abcde fghij"));
+        assert!(!valid_passphrase_part2("abcde xyz ecdab"));
+        assert!(valid_passphrase_part2("a ab abc abd abf abj"));
+        assert!(valid_passphrase_part2("iiii oiii ooii oooi oooo"));
+        assert!(!valid_passphrase_part2("oiii ioii iioi iiio"));
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let input = "abcde fghij\nabcde xyz ecdab".to_owned();
+        assert_eq!(Day04::trace(&input, 2, &mut events), Answer::Number(1));
+        let passphrase = |line: usize, valid: u8, count: usize| {
+            Event::new("passphrase")
+                .with("line", line)
+                .with("valid", valid)
+                .with("count", count)
+        };
+        assert_eq!(events, [passphrase(1, 1, 1), passphrase(2, 0, 1)]);
+    }
+
+    fn naive_valid(passphrase: &str, same: fn(&str, &str) -> bool) -> bool {
+        let words = passphrase.split_whitespace().collect::<Vec<_>>();
+        (0..words.len()).all(|i| (i + 1..words.len()).all(|j| !same(words[i], words[j])))
+    }
+
+    fn is_anagram(a: &str, b: &str) -> bool {
+        let mut a = a.chars().collect::<Vec<_>>();
+        let mut b = b.chars().collect::<Vec<_>>();
+        a.sort();
+        b.sort();
+        a == b
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day04>(1, 400);
+        assert_eq!(input.lines().count(), 400);
+        // Some passphrases repeat a word, and some more have anagrams.
+        let valid = valid_passphrases(&input, &valid_passphrase);
+        let valid_part2 = valid_passphrases(&input, &valid_passphrase_part2);
+        assert!(0 < valid_part2 && valid_part2 < valid && valid < 400);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_naive(words in prop::collection::vec("[a-d]{1,3}", 0..8)) {
+            let passphrase = words.join(" ");
+            prop_assert_eq!(
+                valid_passphrase(&passphrase),
+                naive_valid(&passphrase, |a, b| a == b)
+            );
+            prop_assert_eq!(
+                valid_passphrase_part2(&passphrase),
+                naive_valid(&passphrase, is_anagram)
+            );
+        }
+
+        #[test]
+        fn prop_anagram_free_implies_duplicate_free(
+            lines in prop::collection::vec(prop::collection::vec("[a-c]{1,3}", 0..6), 0..20)
+        ) {
+            let passphrases = lines
+                .iter()
+                .map(|words| words.join(" "))
+                .collect::<Vec<_>>()
+                .join("\n");
+            for passphrase in passphrases.lines() {
+                prop_assert!(!valid_passphrase_part2(passphrase) || valid_passphrase(passphrase));
+            }
+            prop_assert!(
+                valid_passphrases(&passphrases, &valid_passphrase_part2)
+                    <= valid_passphrases(&passphrases, &valid_passphrase)
+            );
+        }
+    }
+}
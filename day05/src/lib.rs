@@ -0,0 +1,175 @@
+//! Day 5: A Maze of Twisty Trampolines, All Alike.
+
+use aoc_common::parse::lines;
+use aoc_common::trace::NoTrace;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer};
+
+/// Counts the steps it takes to jump out of the list of offsets, one per line.
+/// Every jump increments its offset, or with `part2` decrements offsets of
+/// three or more.
+pub fn execute(instructions: &str, part2: bool) -> u32 {
+    execute_traced(instructions, part2, &mut NoTrace)
+}
+
+/// Like `execute`, passing each jump to the tracer as a `jump` event with
+/// where it jumps from and by how much.
+pub fn execute_traced(instructions: &str, part2: bool, tracer: &mut dyn Tracer) -> u32 {
+    // Parse the input, validate and unwrap all the integers.
+    let mut instructions = instructions
+        .lines()
+        .flat_map(|e| e.parse::<i32>())
+        .collect::<Vec<_>>();
+
+    let mut pc: i32 = 0; // program pointer
+    let mut steps = 0; // step counter
+    let max_pc = instructions.len() as i32;
+
+    // Loop until we're outside the program space.
+    while pc >= 0 && pc < max_pc {
+        // Borrow a mutable reference to the instruction.
+        let inst = instructions.get_mut(pc as usize).unwrap();
+        if tracer.enabled() {
+            tracer.event(Event::new("jump").with("pc", pc).with("offset", *inst));
+        }
+        // Increment and move the PC based on the instruction..
+        pc += *inst;
+        // Increment the instruction value.
+        if part2 && *inst >= 3 {
+            *inst -= 1;
+        } else {
+            *inst += 1;
+        }
+        // Increment the step counter.
+        steps += 1;
+    }
+    steps
+}
+
+/// Day 5: A Maze of Twisty Trampolines, All Alike.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        for line in lines(input).filter(|e| !e.text.is_empty()) {
+            line.parse::<i32>(line.text, "offset")?;
+        }
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &String) -> Answer {
+        execute(input, false).into()
+    }
+
+    fn part2(input: &String) -> Answer {
+        execute(input, true).into()
+    }
+}
+
+impl Generate for Day05 {
+    const SIZE: usize = 1043;
+
+    /// `size` offsets, jumping further back the further in the list they are.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size as i64)
+            .map(|i| rng.range(-i..=2).to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Trace for Day05 {
+    fn trace(input: &String, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        execute_traced(input, part == 2, tracer).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_result() {
+        assert_eq!(execute(&get_input(), false), 342669);
+    }
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(execute("0\n3\n0\n1\n-3", false), 5);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let input = "0\n3\n0\n1\n-3".to_owned();
+        assert_eq!(Day05::trace(&input, 1, &mut events), Answer::Number(5));
+        let jump = |pc: i32, offset: i32| Event::new("jump").with("pc", pc).with("offset", offset);
+        assert_eq!(
+            events,
+            [jump(0, 0), jump(0, 1), jump(1, 3), jump(4, -3), jump(1, 4)]
+        );
+    }
+
+    #[test]
+    fn part2_result() {
+        assert_eq!(execute(&get_input(), true), 25136209);
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(execute("0\n3\n0\n1\n-3", true), 10);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day05::parse("0\n3\n+-3").unwrap_err(),
+            ParseError::new(3, 1, "+-3", "invalid offset")
+        );
+    }
+
+    fn instructions(offsets: &[i32]) -> String {
+        offsets
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = Day05::parse(&generate::<Day05>(5, 100)).unwrap();
+        assert_eq!(input.lines().count(), 100);
+        // Every offset is at least 2 ahead of the start, so the jumps escape.
+        assert!(execute(&input, false) > 0 && execute(&input, true) > 0);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_forward_jumps(offsets in prop::collection::vec(1i32..5, 1..100)) {
+            // Only jumping forward visits each offset at most once.
+            let mut expected = 0;
+            let mut pc = 0;
+            while pc < offsets.len() {
+                pc += offsets[pc] as usize;
+                expected += 1;
+            }
+            let instructions = instructions(&offsets);
+            prop_assert_eq!(execute(&instructions, false), expected);
+            prop_assert_eq!(execute(&instructions, true), expected);
+        }
+
+        #[test]
+        fn prop_zero_offsets(len in 1usize..100) {
+            // Each offset is first jumped with 0, then with 1.
+            let instructions = instructions(&vec![0; len]);
+            prop_assert_eq!(execute(&instructions, false), 2 * len as u32);
+            prop_assert_eq!(execute(&instructions, true), 2 * len as u32);
+        }
+    }
+}
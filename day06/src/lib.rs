@@ -0,0 +1,249 @@
+//! Day 6: Memory Reallocation.
+
+use aoc_common::parse::lines;
+use aoc_common::trace::NoTrace;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer};
+use std::collections::HashMap;
+
+/// Redistributes the blocks of the memory banks until a configuration repeats,
+/// returning the number of redistributions, or the length of the loop if
+/// `find_loop_length` is set.
+pub fn debug_steps(memory_banks: Vec<u32>, find_loop_length: bool) -> u32 {
+    debug_steps_traced(memory_banks, find_loop_length, &mut NoTrace)
+}
+
+/// Like `debug_steps`, passing each redistribution to the tracer as a
+/// `redistribute` event with the bank emptied, how many blocks it had and the
+/// banks afterwards, and finally the `repeat` of a configuration seen before.
+pub fn debug_steps_traced(
+    memory_banks: Vec<u32>,
+    find_loop_length: bool,
+    tracer: &mut (impl Tracer + ?Sized),
+) -> u32 {
+    let mut memory = memory_banks.clone();
+    let mut steps = 0;
+    let mut seen = HashMap::new();
+    seen.insert(memory_banks.clone(), 0);
+
+    loop {
+        // Find the index and memory value of the first highest memory
+        let (mut hm_index, mut hm_value): (usize, u32);
+        {
+            let hm_tuple = memory
+                .iter()
+                .enumerate()
+                .rev()
+                .max_by_key(|&(_, e)| e)
+                .unwrap();
+            hm_index = hm_tuple.0.to_owned();
+            hm_value = hm_tuple.1.to_owned();
+        }
+
+        // Reset the memory value of the high memory index.
+        memory[hm_index] = 0;
+        let (bank, blocks) = (hm_index, hm_value);
+
+        // Push memory values onto the rest of the memory bank until the old value is 0.
+        while hm_value > 0 {
+            // Push the index pointer.
+            hm_index = (hm_index + 1) % memory.len();
+            // Increase the memory value.
+            memory[hm_index] += 1;
+            // Subtract from the high memory value.
+            hm_value -= 1;
+        }
+
+        // Increment the step counter.
+        steps += 1;
+        if tracer.enabled() {
+            tracer.event(
+                Event::new("redistribute")
+                    .with("step", steps)
+                    .with("bank", bank)
+                    .with("blocks", blocks)
+                    .with("banks", &memory[..]),
+            );
+        }
+
+        // If we're back at a previously observed memory state, then stop.
+        if let Some(&first) = seen.get(&memory) {
+            tracer.event(
+                Event::new("repeat")
+                    .with("step", steps)
+                    .with("first", first),
+            );
+            break;
+        }
+        // Otherwise add the memory to the seen set.
+        seen.insert(memory.clone(), steps);
+    }
+
+    if find_loop_length {
+        // Find the loop size (part2).
+        return steps - seen.get(&memory).unwrap();
+    }
+
+    // find the step count (part1).
+    steps
+}
+
+/// Parses the number of blocks in each memory bank.
+pub fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+    let mut result = vec![];
+    for line in lines(input) {
+        for bank in line.text.split_whitespace() {
+            result.push(line.parse(bank, "number of blocks")?);
+        }
+    }
+    if result.is_empty() {
+        return Err(ParseError::new(1, 1, "", "missing memory banks"));
+    }
+    Ok(result)
+}
+
+/// Day 6: Memory Reallocation.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<u32>) -> Answer {
+        debug_steps(input.clone(), false).into()
+    }
+
+    fn part2(input: &Vec<u32>) -> Answer {
+        debug_steps(input.clone(), true).into()
+    }
+}
+
+impl Generate for Day06 {
+    const SIZE: usize = 16;
+
+    /// `size` memory banks of up to 15 blocks.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| rng.range(0..=15).to_string())
+            .collect::<Vec<_>>()
+            .join("\t")
+    }
+}
+
+impl Trace for Day06 {
+    fn trace(input: &Vec<u32>, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        debug_steps_traced(input.clone(), part == 2, tracer).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(debug_steps(vec![0, 2, 7, 0], false), 5);
+    }
+
+    #[test]
+    fn part1_result() {
+        assert_eq!(debug_steps(parse(&get_input()).unwrap(), false), 6681);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(debug_steps(vec![0, 2, 7, 0], true), 4);
+    }
+
+    #[test]
+    fn part2_result() {
+        assert_eq!(debug_steps(parse(&get_input()).unwrap(), true), 2392);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("0\t2\tx").unwrap_err(),
+            ParseError::new(1, 5, "x", "invalid number of blocks")
+        );
+        // No memory banks used to panic.
+        assert_eq!(
+            parse(" \n").unwrap_err(),
+            ParseError::new(1, 1, "", "missing memory banks")
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        assert_eq!(
+            Day06::trace(&vec![0, 2, 7, 0], 2, &mut events),
+            Answer::Number(4)
+        );
+        let redistribute = |step: u32, bank: usize, blocks: u32, banks: &[u32]| {
+            Event::new("redistribute")
+                .with("step", step)
+                .with("bank", bank)
+                .with("blocks", blocks)
+                .with("banks", banks)
+        };
+        assert_eq!(
+            events,
+            [
+                redistribute(1, 2, 7, &[2, 4, 1, 2]),
+                redistribute(2, 1, 4, &[3, 1, 2, 3]),
+                redistribute(3, 0, 3, &[0, 2, 3, 4]),
+                redistribute(4, 3, 4, &[1, 3, 4, 1]),
+                redistribute(5, 2, 4, &[2, 4, 1, 2]),
+                Event::new("repeat").with("step", 5).with("first", 1),
+            ]
+        );
+    }
+
+    // Remembers every configuration in a list, instead of in a map.
+    fn naive_debug_steps(mut banks: Vec<u32>) -> (u32, u32) {
+        let mut seen = vec![banks.clone()];
+        loop {
+            let max = *banks.iter().max().unwrap();
+            let mut index = banks.iter().position(|&e| e == max).unwrap();
+            banks[index] = 0;
+            for _ in 0..max {
+                index = (index + 1) % banks.len();
+                banks[index] += 1;
+            }
+            if let Some(first) = seen.iter().position(|e| *e == banks) {
+                return (seen.len() as u32, (seen.len() - first) as u32);
+            }
+            seen.push(banks.clone());
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        let banks = parse(&generate::<Day06>(2, 16)).unwrap();
+        assert_eq!(banks.len(), 16);
+        assert!(debug_steps(banks.clone(), false) >= debug_steps(banks, true));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_naive(banks in prop::collection::vec(0u32..20, 1..8)) {
+            let (steps, loop_length) = naive_debug_steps(banks.clone());
+            prop_assert_eq!(debug_steps(banks.clone(), false), steps);
+            prop_assert_eq!(debug_steps(banks, true), loop_length);
+            prop_assert!(loop_length <= steps);
+        }
+
+        #[test]
+        fn prop_parse_roundtrip(banks in prop::collection::vec(0u32..1000, 1..16)) {
+            let input = banks.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("\t");
+            prop_assert_eq!(parse(&input).unwrap(), banks);
+        }
+    }
+}
@@ -0,0 +1,507 @@
+//! Day 7: Recursive Circus.
+//!
+//! A tower of programs, each holding up a disc of other programs.
+
+use aoc_common::parse::lines;
+use aoc_common::trace::NoTrace;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::option::Option;
+
+/// A program in the tower, along with the programs it is holding.
+#[derive(Debug, Clone)]
+pub struct Program {
+    name: String,
+    weight: u32,
+    aggregated_weight: u32,
+    programs: Vec<String>,
+}
+
+impl Program {
+    fn aggregated_sum(&mut self, programs: Programs) -> u32 {
+        if self.aggregated_weight != 0 {
+            return self.aggregated_weight;
+        }
+
+        let mut sum = self.weight;
+        for program in &self.programs {
+            let program = programs.get(program).unwrap();
+            sum += program.to_owned().aggregated_sum(programs.to_owned());
+        }
+        self.aggregated_weight = sum.to_owned();
+        sum
+    }
+}
+
+/// The programs in the tower, by name.
+pub type Programs = HashMap<String, Program>;
+
+/// Parses the programs, one per line like `fwft (72) -> ktlj, cntj, xhth`,
+/// checking they form a single tower: every program held is defined and held
+/// by just one other, and all of them stand on the same bottom program.
+pub fn parse(input: &str) -> Result<Programs, ParseError> {
+    let mut result = HashMap::new();
+    let mut defined = vec![];
+
+    // Parse all the program names/weights.
+    for line in lines(input).filter(|e| !e.text.is_empty()) {
+        let (program, relations) = match line.text.split_once(" -> ") {
+            Some((program, relations)) => (program, Some(relations)),
+            None => (line.text, None),
+        };
+        let (name, weight) = program
+            .split_once(" (")
+            .ok_or_else(|| line.error(program, "expected a weight"))?;
+        let weight = weight
+            .strip_suffix(')')
+            .ok_or_else(|| line.error(weight, "expected `)` after the weight"))?;
+        let weight = line.parse::<u32>(weight, "weight")?;
+        if result.contains_key(name) {
+            return Err(line.error(name, "program defined twice"));
+        }
+
+        // Handle relations to other programs.
+        let held = relations.map_or(vec![], |e| e.split(", ").collect::<Vec<_>>());
+        let programs = held.iter().map(|&e| e.to_owned()).collect();
+        defined.push((line, name, held));
+
+        result.insert(
+            name.to_owned(),
+            Program {
+                name: name.to_owned(),
+                weight,
+                programs,
+                aggregated_weight: 0,
+            },
+        );
+    }
+
+    let mut holders = HashSet::new();
+    for (line, _, held) in &defined {
+        for &program in held {
+            if !result.contains_key(program) {
+                return Err(line.error(program, "undefined program"));
+            }
+            if !holders.insert(program) {
+                return Err(line.error(program, "program held twice"));
+            }
+        }
+    }
+
+    // Each program is held at most once, so anything not standing on the
+    // bottom program is part of a cycle.
+    let mut bottom = defined.iter().filter(|e| !holders.contains(&e.1));
+    let mut tower = HashSet::new();
+    if let Some((_, name, held)) = bottom.next() {
+        if let Some((line, name, _)) = bottom.next() {
+            return Err(line.error(name, "another program at the bottom"));
+        }
+        tower.insert(*name);
+        let mut queue = held.clone();
+        while let Some(program) = queue.pop() {
+            tower.insert(program);
+            queue.extend(result[program].programs.iter().map(String::as_str));
+        }
+    }
+    match defined.iter().find(|e| !tower.contains(&e.1)) {
+        Some((line, name, _)) => Err(line.error(name, "program held in a cycle")),
+        None if defined.is_empty() => Err(ParseError::new(1, 1, "", "missing programs")),
+        None => Ok(result),
+    }
+}
+
+/// The name of the program at the bottom of the tower, the only one not held
+/// by another.
+pub fn find_bottom_program(programs: HashMap<String, Program>) -> String {
+    let referenced = programs
+        .values()
+        .flat_map(|e| e.programs.clone())
+        .collect::<HashSet<_>>();
+
+    programs
+        .values()
+        .find(|e| !referenced.contains(&e.name))
+        .unwrap()
+        .to_owned()
+        .name
+}
+
+/// The weight the single program with the wrong weight should have for the
+/// tower above `root` to be balanced, or `None` if it already is or if no
+/// single weight change can balance it.
+pub fn fix_bad_weight(programs: Programs, root: Program) -> Option<u32> {
+    fix_bad_weight_traced(programs, root, &mut NoTrace)
+}
+
+/// Like `fix_bad_weight`, passing each disc that isn't balanced to the tracer
+/// as an `unbalanced` event, with the program holding it, the program whose
+/// tower has the odd weight, and what its tower weighs and should weigh.
+pub fn fix_bad_weight_traced(
+    programs: Programs,
+    root: Program,
+    tracer: &mut dyn Tracer,
+) -> Option<u32> {
+    let mut weights: HashMap<u32, u32> = HashMap::new();
+    let mut weights_program: HashMap<u32, String> = HashMap::new();
+    for program in root.programs {
+        let program = programs.get(&program).unwrap();
+        let agg_sum = program.to_owned().aggregated_sum(programs.to_owned());
+
+        // Aggregate all the aggregated weights, to find the outlier.
+        let new_weight = weights.get(&agg_sum).unwrap_or(&0) + 1;
+        weights.insert(agg_sum, new_weight);
+
+        weights_program
+            .entry(agg_sum)
+            .or_insert_with(|| program.name.to_owned());
+    }
+
+    // The bad weight is the only one with a single tower, and the correct
+    // weight is the one all the other towers have. With anything else, like
+    // two pairs of weights, no single program is to blame.
+    if weights.len() != 2 {
+        return None;
+    }
+    let single = weights
+        .iter()
+        .filter(|&(_, &count)| count == 1)
+        .map(|(&weight, _)| weight)
+        .collect::<Vec<_>>();
+    let bad_weight = match single[..] {
+        [weight] => weight,
+        _ => return None,
+    };
+    let correct_weight = *weights.keys().find(|&&k| k != bad_weight).unwrap();
+    let bad_program = programs
+        .get(weights_program.get(&bad_weight).unwrap())
+        .unwrap();
+    tracer.event(
+        Event::new("unbalanced")
+            .with("holder", root.name.as_str())
+            .with("program", bad_program.name.as_str())
+            .with("weight", bad_weight)
+            .with("expected", correct_weight),
+    );
+
+    // If the towers on the bad program aren't balanced either, the fix is
+    // further up, if there is one.
+    let mut above = bad_program.programs.iter().map(|program| {
+        programs[program]
+            .to_owned()
+            .aggregated_sum(programs.to_owned())
+    });
+    if let Some(first) = above.next() {
+        if above.any(|weight| weight != first) {
+            return fix_bad_weight_traced(programs.to_owned(), bad_program.to_owned(), tracer);
+        }
+    }
+
+    // Otherwise, balance the weight on this level of recursion, unless it
+    // would have to weigh less than nothing.
+    bad_program
+        .weight
+        .checked_add(correct_weight)?
+        .checked_sub(bad_weight)
+}
+
+/// Day 7: Recursive Circus.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Programs;
+
+    fn parse(input: &str) -> Result<Programs, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Programs) -> Answer {
+        find_bottom_program(input.clone()).into()
+    }
+
+    /// No answer when the tower is balanced already.
+    fn part2(input: &Programs) -> Answer {
+        let root = find_bottom_program(input.clone());
+        fix_bad_weight(input.clone(), input[&root].to_owned()).map_or(Answer::None, Answer::from)
+    }
+}
+
+impl Generate for Day07 {
+    const SIZE: usize = 1090;
+
+    /// A tower of about `size` programs holding 3 up to 6 programs each,
+    /// balanced except for a single program with the wrong weight.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut names = BTreeSet::new();
+        while names.len() < size.max(1) {
+            names.insert(rng.word(4..=7));
+        }
+        let mut names = names.into_iter().collect::<Vec<_>>();
+        rng.shuffle(&mut names);
+
+        // Grow the tower from the bottom program, by having a random program
+        // on top hold some more. Programs come after the one holding them.
+        let mut held = vec![vec![]];
+        let mut top = vec![0];
+        while held.len() + 3 <= size {
+            let count = rng.range(3..=6).min((size - held.len()) as i64) as usize;
+            let holder = top.swap_remove(rng.below(top.len()));
+            for _ in 0..count {
+                let program = held.len();
+                held[holder].push(program);
+                top.push(program);
+                held.push(vec![]);
+            }
+        }
+
+        // Balance each disc from the top down, by making the programs on it
+        // heavier until their towers all weigh the same.
+        let mut weights = (0..held.len())
+            .map(|_| rng.range(10..=99) as u32)
+            .collect::<Vec<_>>();
+        let mut towers = weights.clone();
+        for program in (0..held.len()).rev() {
+            if let Some(heaviest) = held[program].iter().map(|&e| towers[e]).max() {
+                for &e in &held[program] {
+                    weights[e] += heaviest - towers[e];
+                }
+                towers[program] += heaviest * held[program].len() as u32;
+            }
+        }
+
+        // Unbalance the tower.
+        if held.len() > 1 {
+            let program = 1 + rng.below(held.len() - 1);
+            let delta = rng.range(1..=9) * if rng.one_in(2) { 1 } else { -1 };
+            weights[program] = (weights[program] as i64 + delta) as u32;
+        }
+
+        let mut lines = (0..held.len())
+            .map(|program| {
+                let mut line = format!("{} ({})", names[program], weights[program]);
+                if !held[program].is_empty() {
+                    let held = held[program].iter().map(|&e| names[e].as_str());
+                    line += &format!(" -> {}", held.collect::<Vec<_>>().join(", "));
+                }
+                line
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut lines);
+        lines.join("\n")
+    }
+}
+
+impl Trace for Day07 {
+    /// Finding the bottom program takes no steps worth reporting, the first
+    /// part only has an event for the program found.
+    fn trace(input: &Programs, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        let root = find_bottom_program(input.clone());
+        tracer.event(Event::new("bottom").with("program", root.as_str()));
+        match part {
+            1 => root.into(),
+            _ => fix_bad_weight_traced(input.clone(), input[&root].to_owned(), tracer)
+                .map_or(Answer::None, Answer::from),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = "pbga (66)
+xhth (57)
+ebii (61)
+havc (66)
+ktlj (57)
+fwft (72) -> ktlj, cntj, xhth
+qoyq (66)
+padx (45) -> pbga, havc, qoyq
+tknk (41) -> ugml, padx, fwft
+jptl (61)
+ugml (68) -> gyxo, ebii, jptl
+gyxo (61)
+cntj (57)";
+
+    #[test]
+    fn part1_example() {
+        let programs = parse(TEST_INPUT).unwrap();
+        assert_eq!(find_bottom_program(programs), "tknk");
+    }
+
+    #[test]
+    fn part1_result() {
+        let programs = parse(&get_input()).unwrap();
+        assert_eq!(find_bottom_program(programs), "hmvwl");
+    }
+
+    #[test]
+    fn part2_example() {
+        let programs = parse(TEST_INPUT).unwrap();
+        let root = find_bottom_program(programs.to_owned());
+        assert_eq!(
+            fix_bad_weight(programs.to_owned(), programs.get(&root).unwrap().to_owned()).unwrap(),
+            60
+        );
+    }
+
+    #[test]
+    fn part2_result() {
+        let programs = parse(&get_input()).unwrap();
+        let root = find_bottom_program(programs.to_owned());
+        assert_eq!(
+            fix_bad_weight(programs.to_owned(), programs.get(&root).unwrap().to_owned()).unwrap(),
+            1853
+        );
+    }
+
+    #[test]
+    fn test_balanced_tower() {
+        for input in [
+            "a (1)",
+            "a (1) -> b, c\nb (2)\nc (2)",
+            // No single odd weight, which used to panic.
+            "a (1) -> b, c, d, e\nb (1)\nc (1)\nd (2)\ne (2)",
+            // The odd tower would have to weigh less than nothing, which
+            // used to underflow.
+            "a (1) -> x, p, q\nx (1) -> y, z\ny (10)\nz (10)\np (1)\nq (1)",
+        ] {
+            let programs = parse(input).unwrap();
+            assert_eq!(Day07::part2(&programs), Answer::None);
+            assert_eq!(Day07::trace(&programs, 2, &mut vec![]), Answer::None);
+        }
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let programs = parse(TEST_INPUT).unwrap();
+        assert_eq!(Day07::trace(&programs, 2, &mut events), Answer::Number(60));
+        assert_eq!(
+            events,
+            [
+                Event::new("bottom").with("program", "tknk"),
+                Event::new("unbalanced")
+                    .with("holder", "tknk")
+                    .with("program", "ugml")
+                    .with("weight", 251)
+                    .with("expected", 243)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("pbga (66)\nxhth (x)").unwrap_err(),
+            ParseError::new(2, 7, "x", "invalid weight")
+        );
+        assert_eq!(
+            parse("pbga (66").unwrap_err(),
+            ParseError::new(1, 7, "66", "expected `)` after the weight")
+        );
+    }
+
+    #[test]
+    fn test_parse_tower_error() {
+        // These used to panic when solving instead.
+        let error = |input| parse(input).unwrap_err();
+        assert_eq!(
+            error("a (1) -> b"),
+            ParseError::new(1, 10, "b", "undefined program")
+        );
+        assert_eq!(
+            error("a (1) -> b\nb (1) -> a"),
+            ParseError::new(1, 1, "a", "program held in a cycle")
+        );
+        assert_eq!(
+            error("a (1) -> b\nb (1) -> c\nc (1) -> b"),
+            ParseError::new(3, 10, "b", "program held twice")
+        );
+        assert_eq!(
+            error("a (1) -> b\nb (1)\nc (1) -> d\nd (1) -> c"),
+            ParseError::new(3, 1, "c", "program held in a cycle")
+        );
+        assert_eq!(
+            error("a (1) -> b\nb (1)\nc (1)"),
+            ParseError::new(3, 1, "c", "another program at the bottom")
+        );
+        assert_eq!(
+            error("a (1)\nb (2)\na (3)"),
+            ParseError::new(3, 1, "a", "program defined twice")
+        );
+        assert_eq!(error(""), ParseError::new(1, 1, "", "missing programs"));
+        assert_eq!(error("\n\n"), ParseError::new(1, 1, "", "missing programs"));
+    }
+
+    // A balanced tower, where each program on a level has the given weight
+    // and number of children, with the program at `path` changed by `delta`.
+    // Returns the input, along with the name and weight of the changed
+    // program.
+    fn tower(levels: &[(usize, u32)], path: &[usize], delta: i32) -> (String, String, u32) {
+        let mut lines = vec![];
+        let mut changed = None;
+        let mut queue = vec![(String::from("n"), 0, true)];
+        while let Some((name, level, on_path)) = queue.pop() {
+            let (children, weight) = levels[level];
+            let mut line_weight = weight;
+            if on_path && level == path.len() {
+                line_weight = (weight as i32 + delta) as u32;
+                changed = Some((name.clone(), weight));
+            }
+            let mut line = format!("{} ({})", name, line_weight);
+            if level + 1 < levels.len() {
+                let names = (0..children)
+                    .map(|i| format!("{}{}", name, i))
+                    .collect::<Vec<_>>();
+                line += &format!(" -> {}", names.join(", "));
+                for (i, child) in names.into_iter().enumerate() {
+                    let on_path = on_path && path.get(level) == Some(&i);
+                    queue.push((child, level + 1, on_path));
+                }
+            }
+            lines.push(line);
+        }
+        let (name, weight) = changed.unwrap();
+        (lines.join("\n"), name, weight)
+    }
+
+    fn tower_strategy() -> impl Strategy<Value = (Vec<(usize, u32)>, Vec<usize>, i32)> {
+        prop::collection::vec((3usize..5, 50u32..100), 2..5).prop_flat_map(|levels| {
+            let depth = 1..levels.len();
+            let path = depth.prop_flat_map(|depth| prop::collection::vec(0usize..3, depth));
+            let delta = (-40i32..40).prop_filter("the weight must change", |d| *d != 0);
+            (Just(levels), path, delta)
+        })
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let programs = parse(&generate::<Day07>(seed, 50)).unwrap();
+            assert!((48..=50).contains(&programs.len()));
+            let root = find_bottom_program(programs.clone());
+            assert!(fix_bad_weight(programs.clone(), programs[&root].clone()).is_some());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_bottom_program(levels in prop::collection::vec((1usize..4, 1u32..100), 1..5)) {
+            let (input, _, _) = tower(&levels, &[], 0);
+            prop_assert_eq!(find_bottom_program(parse(&input).unwrap()), "n");
+        }
+
+        #[test]
+        fn prop_fix_restores_weight((levels, path, delta) in tower_strategy()) {
+            let (input, name, weight) = tower(&levels, &path, delta);
+            prop_assert!(name.len() > 1);
+            let programs = parse(&input).unwrap();
+            let root = programs["n"].clone();
+            prop_assert_eq!(fix_bad_weight(programs, root), Some(weight));
+        }
+    }
+}
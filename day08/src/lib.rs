@@ -0,0 +1,324 @@
+//! Day 8: I Heard You Like Registers.
+
+use aoc_common::parse::Line;
+use aoc_common::trace::NoTrace;
+use aoc_common::{Answer, Generate, ParseError, Rng, Solution, Trace, Tracer};
+use std::collections::BTreeSet;
+use vm::{Flow, Program, Register, RegisterFile};
+
+/// The value of each register, named as the program goes.
+pub type Registers = RegisterFile<i32>;
+
+/// How the condition compares a register to the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Greater,
+    Less,
+    GreaterOrEqual,
+    Equal,
+    LessOrEqual,
+    NotEqual,
+}
+
+impl Comparison {
+    fn holds(self, left: i32, right: i32) -> bool {
+        match self {
+            Comparison::Greater => left > right,
+            Comparison::Less => left < right,
+            Comparison::GreaterOrEqual => left >= right,
+            Comparison::Equal => left == right,
+            Comparison::LessOrEqual => left <= right,
+            Comparison::NotEqual => left != right,
+        }
+    }
+}
+
+/// A conditional instruction like `b inc 5 if a > 1`, with `dec` stored as
+/// adding the negated amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub register: Register,
+    pub amount: i32,
+    pub condition: Register,
+    pub comparison: Comparison,
+    pub value: i32,
+}
+
+impl vm::Instruction for Instruction {
+    type Value = i32;
+    /// The highest value any register has held.
+    type State = i32;
+
+    fn parse(line: &Line, registers: &mut Registers) -> Result<Instruction, ParseError> {
+        let fields = line.text.split_whitespace().collect::<Vec<_>>();
+        if fields.len() != 7 || fields[3] != "if" {
+            return Err(line.invalid("expected an instruction like `b inc 5 if a > 1`"));
+        }
+        let negate = match fields[1] {
+            "inc" => false,
+            "dec" => true,
+            _ => return Err(line.error(fields[1], "unknown operation")),
+        };
+        let mut amount = line.parse::<i32>(fields[2], "amount")?;
+        if negate {
+            amount = amount
+                .checked_neg()
+                .ok_or_else(|| line.error(fields[2], "invalid amount"))?;
+        }
+        let comparison = match fields[5] {
+            ">" => Comparison::Greater,
+            "<" => Comparison::Less,
+            ">=" => Comparison::GreaterOrEqual,
+            "==" => Comparison::Equal,
+            "<=" => Comparison::LessOrEqual,
+            "!=" => Comparison::NotEqual,
+            _ => return Err(line.error(fields[5], "unknown comparison")),
+        };
+        Ok(Instruction {
+            register: registers.register(fields[0]),
+            amount,
+            condition: registers.register(fields[4]),
+            comparison,
+            value: line.parse(fields[6], "value")?,
+        })
+    }
+
+    fn execute(&self, registers: &mut Registers, highest: &mut i32) -> Flow {
+        if self.comparison.holds(registers[self.condition], self.value) {
+            registers[self.register] += self.amount;
+            *highest = (*highest).max(registers[self.register]);
+        }
+        Flow::Next
+    }
+}
+
+/// Parses the conditional instructions, one per line.
+pub fn parse(input: &str) -> Result<Program<Instruction>, ParseError> {
+    Program::parse(input, Registers::new())
+}
+
+/// Runs the program, returning the registers at the end along with the
+/// highest value any register ever held.
+pub fn execute(program: &Program<Instruction>) -> (Registers, i32) {
+    execute_traced(program, &mut NoTrace)
+}
+
+/// Like `execute`, passing each instruction executed and each register it
+/// changed to the tracer.
+pub fn execute_traced(program: &Program<Instruction>, tracer: &mut dyn Tracer) -> (Registers, i32) {
+    let mut machine = program.machine(0);
+    machine.run_traced(tracer);
+    (machine.registers, machine.state)
+}
+
+// The largest value in any register, or 0 without any registers.
+fn largest(registers: &Registers) -> i32 {
+    registers.values().iter().max().copied().unwrap_or(0)
+}
+
+/// Day 8: I Heard You Like Registers.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Program<Instruction>;
+
+    fn parse(input: &str) -> Result<Program<Instruction>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Program<Instruction>) -> Answer {
+        largest(&execute(input).0).into()
+    }
+
+    fn part2(input: &Program<Instruction>) -> Answer {
+        execute(input).1.into()
+    }
+}
+
+impl Generate for Day08 {
+    const SIZE: usize = 999;
+
+    /// `size` instructions on 26 registers.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut names = BTreeSet::new();
+        while names.len() < 26 {
+            names.insert(rng.word(1..=3));
+        }
+        let mut names = names.into_iter().collect::<Vec<_>>();
+        rng.shuffle(&mut names);
+        let comparisons = [">", "<", ">=", "==", "<=", "!="];
+        (0..size)
+            .map(|_| {
+                format!(
+                    "{} {} {} if {} {} {}",
+                    rng.choose(&names),
+                    rng.choose(&["inc", "dec"]),
+                    rng.range(-1000..=1000),
+                    rng.choose(&names),
+                    rng.choose(&comparisons),
+                    rng.range(-10..=10)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Trace for Day08 {
+    fn trace(input: &Program<Instruction>, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        let (registers, highest) = execute_traced(input, tracer);
+        match part {
+            1 => largest(&registers).into(),
+            _ => highest.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use aoc_common::Event;
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = "b inc 5 if a > 1
+a inc 1 if b < 5
+c dec -10 if a >= 1
+c inc -20 if c == 10";
+
+    #[test]
+    fn part1_example() {
+        let (registers, _) = execute(&parse(TEST_INPUT).unwrap());
+        assert_eq!(registers.values().iter().max(), Some(&1)); // a is 1.
+    }
+
+    #[test]
+    fn part1_result() {
+        let (registers, _) = execute(&parse(&get_input()).unwrap());
+        assert_eq!(registers.values().iter().max(), Some(&5075));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(execute(&parse(TEST_INPUT).unwrap()).1, 10);
+    }
+
+    #[test]
+    fn part2_result() {
+        assert_eq!(execute(&parse(&get_input()).unwrap()).1, 7310);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day08::parse("b inc 5 if a > 1\na add 1 if b < 5").unwrap_err(),
+            ParseError::new(2, 3, "add", "unknown operation")
+        );
+        assert_eq!(
+            Day08::parse("b inc 5 if a => 1").unwrap_err(),
+            ParseError::new(1, 14, "=>", "unknown comparison")
+        );
+        // Negating the amount of a `dec` can't overflow.
+        assert_eq!(
+            Day08::parse("b dec -2147483648 if a > 1").unwrap_err(),
+            ParseError::new(1, 7, "-2147483648", "invalid amount")
+        );
+        // A short line used to be indexed out of bounds.
+        assert_eq!(
+            Day08::parse("b inc 5").unwrap_err(),
+            ParseError::new(
+                1,
+                1,
+                "b inc 5",
+                "expected an instruction like `b inc 5 if a > 1`"
+            )
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let program = parse(TEST_INPUT).unwrap();
+        assert_eq!(Day08::trace(&program, 2, &mut events), Answer::Number(10));
+        assert_eq!(
+            events.iter().filter(|event| event.name == "step").count(),
+            4
+        );
+        let set = |register: &str, from: i32, to: i32| {
+            Event::new("set")
+                .with("register", register)
+                .with("from", from)
+                .with("to", to)
+        };
+        // The first instruction's condition doesn't hold, so it changes nothing.
+        let changes = events
+            .into_iter()
+            .filter(|event| event.name == "set")
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            [set("a", 0, 1), set("c", 0, 10), set("c", 10, -10)]
+        );
+    }
+
+    fn program_strategy() -> impl Strategy<Value = Vec<(char, bool, i32, char, &'static str, i32)>>
+    {
+        let comparison = prop::sample::select(vec![">", "<", ">=", "==", "<=", "!="]);
+        prop::collection::vec(
+            (
+                "[abc]",
+                any::<bool>(),
+                -20i32..20,
+                "[abc]",
+                comparison,
+                -10i32..10,
+            )
+                .prop_map(|(reg, inc, amount, cond, cmp, value)| {
+                    let (reg, cond) = (reg.chars().next().unwrap(), cond.chars().next().unwrap());
+                    (reg, inc, amount, cond, cmp, value)
+                }),
+            0..50,
+        )
+    }
+
+    fn render(program: &[(char, bool, i32, char, &str, i32)]) -> String {
+        program
+            .iter()
+            .map(|&(reg, inc, amount, cond, cmp, value)| {
+                let op = if inc { "inc" } else { "dec" };
+                format!("{} {} {} if {} {} {}", reg, op, amount, cond, cmp, value)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_generate() {
+        let program = parse(&generate::<Day08>(4, 500)).unwrap();
+        assert_eq!(program.instructions.len(), 500);
+        assert_eq!(program.registers.len(), 26);
+        let (registers, highest) = execute(&program);
+        assert!(registers.values().iter().all(|&value| value <= highest));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_highest_value(program in program_strategy()) {
+            let (registers, highest) = execute(&parse(&render(&program)).unwrap());
+            prop_assert!(highest >= 0);
+            prop_assert!(registers.values().iter().all(|&value| value <= highest));
+        }
+
+        #[test]
+        fn prop_dec_is_negated_inc(program in program_strategy()) {
+            let negated = program
+                .iter()
+                .map(|&(reg, inc, amount, cond, cmp, value)| (reg, !inc, -amount, cond, cmp, value))
+                .collect::<Vec<_>>();
+            let run = |program| execute(&parse(&render(program)).unwrap());
+            prop_assert_eq!(run(&program), run(&negated));
+        }
+    }
+}
@@ -0,0 +1,337 @@
+//! Day 9: Stream Processing.
+
+use aoc_common::parse::lines;
+use aoc_common::trace::NoTrace;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer};
+
+/// The total score of the groups in the stream, or with `part2` the number of
+/// characters of garbage that aren't cancelled.
+pub fn calc_score(input: &str, part2: bool) -> u32 {
+    calc_score_traced(input, part2, &mut NoTrace)
+}
+
+/// Like `calc_score`, passing each group closed to the tracer as a `group`
+/// event with its score, or with `part2` each piece of garbage closed as a
+/// `garbage` event with the characters in it, along with the total so far.
+pub fn calc_score_traced(input: &str, part2: bool, tracer: &mut dyn Tracer) -> u32 {
+    let chars = input.chars().collect::<Vec<_>>();
+    let mut i = 0;
+    let mut result = 0;
+    let mut in_garbage = false;
+    let mut depth = 0;
+    let mut garbage_start = 0;
+    while i < chars.len() {
+        let c = chars[i];
+
+        // If we hit an escape character, skip ahead 2 characters (this one and the next).
+        if c == '!' {
+            i += 2;
+            continue;
+        }
+
+        if !in_garbage {
+            if c == '<' {
+                in_garbage = true;
+                garbage_start = result;
+            } else if c == '{' {
+                if !part2 {
+                    depth += 1;
+                }
+            } else if c == '}' && !part2 {
+                result += depth;
+                if tracer.enabled() {
+                    tracer.event(
+                        Event::new("group")
+                            .with("position", i)
+                            .with("score", depth)
+                            .with("total", result),
+                    );
+                }
+                depth -= 1;
+            }
+        } else {
+            if c == '>' {
+                in_garbage = false;
+                if part2 && tracer.enabled() {
+                    tracer.event(
+                        Event::new("garbage")
+                            .with("position", i)
+                            .with("characters", result - garbage_start)
+                            .with("total", result),
+                    );
+                }
+            } else if part2 {
+                result += 1;
+            }
+        }
+
+        i += 1;
+    }
+    result
+}
+
+// Checks that every group and every piece of garbage in the stream is closed,
+// pointing at the first one that isn't.
+fn check_stream(input: &str) -> Result<(), ParseError> {
+    let mut groups = vec![];
+    let mut garbage = None;
+    let mut cancelled = false;
+    for line in lines(input) {
+        for (i, c) in line.text.char_indices() {
+            let token = &line.text[i..i + c.len_utf8()];
+            if cancelled {
+                cancelled = false;
+            } else if c == '!' {
+                cancelled = true;
+            } else if garbage.is_some() {
+                if c == '>' {
+                    garbage = None;
+                }
+            } else if c == '<' {
+                garbage = Some((line, token));
+            } else if c == '{' {
+                groups.push((line, token));
+            } else if c == '}' && groups.pop().is_none() {
+                return Err(line.error(token, "unexpected `}`"));
+            }
+        }
+        // A `!` at the end of the line cancels the newline.
+        cancelled = false;
+    }
+    if let Some((line, token)) = garbage {
+        return Err(line.error(token, "unclosed garbage"));
+    }
+    if let Some((line, token)) = groups.pop() {
+        return Err(line.error(token, "unclosed group"));
+    }
+    Ok(())
+}
+
+/// Day 9: Stream Processing.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        check_stream(input)?;
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &String) -> Answer {
+        calc_score(input, false).into()
+    }
+
+    fn part2(input: &String) -> Answer {
+        calc_score(input, true).into()
+    }
+}
+
+// Appends a group of groups and garbage to the stream. The outermost group
+// only ends once the stream is `size` characters long.
+fn generate_group(rng: &mut Rng, stream: &mut String, size: usize, depth: usize) {
+    stream.push('{');
+    let mut first = true;
+    while stream.len() < size && (depth == 0 || !rng.one_in(4)) {
+        if !first {
+            stream.push(',');
+        }
+        first = false;
+        if depth < 20 && rng.one_in(2) {
+            generate_group(rng, stream, size, depth + 1);
+        } else {
+            stream.push('<');
+            for _ in 0..rng.below(12) {
+                let c = *rng.choose(&['a', 'e', 'i', 'o', 'u', '{', '}', '<', ',', '\'', '"', '!']);
+                stream.push(c);
+                if c == '!' {
+                    // Cancel anything, even the end of the garbage.
+                    stream.push(*rng.choose(&['!', '>', 'a', '{', '}']));
+                }
+            }
+            stream.push('>');
+        }
+    }
+    stream.push('}');
+}
+
+impl Generate for Day09 {
+    const SIZE: usize = 20142;
+
+    /// A stream of about `size` characters.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut stream = String::new();
+        generate_group(rng, &mut stream, size, 0);
+        stream
+    }
+}
+
+impl Trace for Day09 {
+    fn trace(input: &String, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        calc_score_traced(input, part == 2, tracer).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(calc_score("{}", false), 1);
+        assert_eq!(calc_score("{{{}}}", false), 6);
+        assert_eq!(calc_score("{{}, {}}", false), 5);
+        assert_eq!(calc_score("{{{},{},{{}}}}", false), 16);
+        assert_eq!(calc_score("{<a>,<a>,<a>,<a>}", false), 1);
+        assert_eq!(calc_score("{{<ab>},{<ab>},{<ab>},{<ab>}}", false), 9);
+        assert_eq!(calc_score("{{<!!>},{<!!>},{<!!>},{<!!>}}", false), 9);
+        assert_eq!(calc_score("{{<a!>},{<a!>},{<a!>},{<ab>}}", false), 3);
+    }
+
+    #[test]
+    fn part1_result() {
+        assert_eq!(calc_score(&get_input(), false), 14204);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(calc_score("<>", true), 0);
+        assert_eq!(calc_score("<random characters>", true), 17);
+        assert_eq!(calc_score("<<<<>", true), 3);
+        assert_eq!(calc_score("<{!>}>", true), 2);
+        assert_eq!(calc_score("<!!>", true), 0);
+        assert_eq!(calc_score("<!!!>>", true), 0);
+        assert_eq!(calc_score("<{o\"i!a,<{i<a>", true), 10);
+    }
+
+    #[test]
+    fn part2_result() {
+        assert_eq!(calc_score(&get_input(), true), 6622);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let input = "{{<ab>},{<!>a>}}".to_owned();
+        assert_eq!(Day09::trace(&input, 1, &mut events), Answer::Number(5));
+        let group = |position: usize, score: u32, total: u32| {
+            Event::new("group")
+                .with("position", position)
+                .with("score", score)
+                .with("total", total)
+        };
+        assert_eq!(events, [group(6, 2, 2), group(14, 2, 4), group(15, 1, 5)]);
+        events.clear();
+        assert_eq!(Day09::trace(&input, 2, &mut events), Answer::Number(3));
+        let garbage = |position: usize, characters: u32, total: u32| {
+            Event::new("garbage")
+                .with("position", position)
+                .with("characters", characters)
+                .with("total", total)
+        };
+        assert_eq!(events, [garbage(5, 2, 2), garbage(13, 1, 3)]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        // Closing a group that was never opened used to underflow the depth.
+        assert_eq!(
+            Day09::parse("{}}").unwrap_err(),
+            ParseError::new(1, 3, "}", "unexpected `}`")
+        );
+        assert_eq!(
+            Day09::parse("{{<!>},{}}").unwrap_err(),
+            ParseError::new(1, 3, "<", "unclosed garbage")
+        );
+        assert_eq!(
+            Day09::parse("{{}\n{}").unwrap_err(),
+            ParseError::new(1, 1, "{", "unclosed group")
+        );
+        assert!(Day09::parse("{<a>,<!>}>,{}}\n").is_ok());
+    }
+
+    #[derive(Debug, Clone)]
+    enum Thing {
+        Group(Vec<Thing>),
+        // The characters of the garbage, and whether each is cancelled.
+        Garbage(Vec<(char, bool)>),
+    }
+
+    impl Thing {
+        fn render(&self) -> String {
+            match *self {
+                Thing::Group(ref things) => format!(
+                    "{{{}}}",
+                    things
+                        .iter()
+                        .map(Thing::render)
+                        .collect::<Vec<_>>()
+                        .join(",")
+                ),
+                Thing::Garbage(ref chars) => {
+                    let mut result = String::from("<");
+                    for &(c, cancelled) in chars {
+                        if cancelled {
+                            result.push('!');
+                        }
+                        result.push(c);
+                    }
+                    result + ">"
+                }
+            }
+        }
+
+        fn score(&self, depth: u32) -> u32 {
+            match *self {
+                Thing::Group(ref things) => {
+                    depth + things.iter().map(|e| e.score(depth + 1)).sum::<u32>()
+                }
+                Thing::Garbage(_) => 0,
+            }
+        }
+
+        fn garbage(&self) -> u32 {
+            match *self {
+                Thing::Group(ref things) => things.iter().map(Thing::garbage).sum(),
+                Thing::Garbage(ref chars) => chars.iter().filter(|e| !e.1).count() as u32,
+            }
+        }
+    }
+
+    fn stream_strategy() -> impl Strategy<Value = Thing> {
+        let garbage = prop::collection::vec(
+            prop_oneof![
+                "[a-z{}<,'\"]".prop_map(|c| (c.chars().next().unwrap(), false)),
+                "[a-z{}<>!,]".prop_map(|c| (c.chars().next().unwrap(), true)),
+            ],
+            0..8,
+        )
+        .prop_map(Thing::Garbage);
+        let leaf = prop_oneof![garbage, Just(Thing::Group(vec![]))];
+        let things = leaf.prop_recursive(4, 64, 5, |inner| {
+            prop::collection::vec(inner, 0..5).prop_map(Thing::Group)
+        });
+        prop::collection::vec(things, 0..5).prop_map(Thing::Group)
+    }
+
+    #[test]
+    fn test_generate() {
+        let stream = Day09::parse(&generate::<Day09>(9, 2000)).unwrap();
+        assert!((2000..2100).contains(&stream.len()));
+        assert!(calc_score(&stream, false) > 0 && calc_score(&stream, true) > 0);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_score_and_garbage(stream in stream_strategy()) {
+            let input = stream.render();
+            prop_assert_eq!(Day09::parse(&input), Ok(input.clone()));
+            prop_assert_eq!(calc_score(&input, false), stream.score(1));
+            prop_assert_eq!(calc_score(&input, true), stream.garbage());
+        }
+    }
+}
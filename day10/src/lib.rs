@@ -0,0 +1,242 @@
+//! Day 10: Knot Hash.
+
+use aoc_common::parse::lines;
+use aoc_common::trace::NoTrace;
+use aoc_common::{
+    check_params, param, Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer,
+};
+use knot_hash::{knot_hash, Digest, SparseHash, ROUNDS, STANDARD_SUFFIX};
+
+/// The product of the first two numbers of a list of `size` numbers after a
+/// single round of knotting with the lengths.
+pub fn calculate_result(size: usize, lengths: &[usize]) -> u32 {
+    calculate_result_traced(size, lengths, &mut NoTrace)
+}
+
+/// Like `calculate_result`, passing each knot tied to the tracer as a `knot`
+/// event with its length and the list afterwards.
+pub fn calculate_result_traced(size: usize, lengths: &[usize], tracer: &mut dyn Tracer) -> u32 {
+    let mut sparse = SparseHash::new(size);
+    // The position and skip size carry over, so knotting one length at a
+    // time is the same as a round of all of them.
+    for &length in lengths {
+        sparse.round(&[length]);
+        if tracer.enabled() {
+            tracer.event(
+                Event::new("knot")
+                    .with("length", length)
+                    .with("list", sparse.list()),
+            );
+        }
+    }
+    let list = sparse.list();
+    u32::from(list[0]) * u32::from(list[1])
+}
+
+/// Like `knot_hash`, passing the list after each round to the tracer as a
+/// `round` event.
+pub fn knot_hash_traced(input: &[u8], tracer: &mut dyn Tracer) -> Digest {
+    let lengths = input
+        .iter()
+        .chain(&STANDARD_SUFFIX)
+        .map(|&length| length as usize)
+        .collect::<Vec<_>>();
+    let mut sparse = SparseHash::new(256);
+    for round in 1..=ROUNDS {
+        sparse.round(&lengths);
+        if tracer.enabled() {
+            tracer.event(
+                Event::new("round")
+                    .with("round", round)
+                    .with("list", sparse.list()),
+            );
+        }
+    }
+    sparse.dense()
+}
+
+// The lengths of a parsed input.
+fn lengths(input: &str) -> Vec<usize> {
+    input
+        .split(',')
+        .map(|e| e.parse::<usize>().unwrap())
+        .collect()
+}
+
+/// Day 10: Knot Hash.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        let input = input.trim();
+        let mut lines = lines(input);
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::new(1, 1, "", "missing lengths"))?;
+        if let Some(line) = lines.next() {
+            return Err(line.invalid("expected the lengths on a single line"));
+        }
+        for length in line.text.split(',') {
+            if line.parse::<usize>(length, "length")? > 256 {
+                return Err(line.error(length, "length longer than the list"));
+            }
+        }
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &String) -> Answer {
+        calculate_result(256, &lengths(input)).into()
+    }
+
+    fn part2(input: &String) -> Answer {
+        knot_hash(input.as_bytes()).to_string().into()
+    }
+
+    /// The example of part 1 knots a list of `size` numbers instead of 256.
+    fn solve_with(input: &String, part: u8, params: &[(&str, i64)]) -> Result<Answer, String> {
+        if part != 1 {
+            check_params(params, part, &[])?;
+            return Ok(Day10::solve(input, part));
+        }
+        check_params(params, part, &["size"])?;
+        let size = param(params, "size", 256, 2..=256)? as usize;
+        let lengths = lengths(input);
+        if let Some(length) = lengths.iter().find(|&&length| length > size) {
+            return Err(format!(
+                "length {} longer than the list of {}",
+                length, size
+            ));
+        }
+        Ok(calculate_result(size, &lengths).into())
+    }
+}
+
+impl Generate for Day10 {
+    const SIZE: usize = 16;
+
+    /// `size` lengths of up to 255.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| rng.range(0..=255).to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+impl Trace for Day10 {
+    fn trace(input: &String, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        match part {
+            1 => calculate_result_traced(256, &lengths(input), tracer).into(),
+            _ => knot_hash_traced(input.as_bytes(), tracer)
+                .to_string()
+                .into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_result1() {
+        let input = Day10::parse(&get_input()).unwrap();
+        assert_eq!(Day10::part1(&input), Answer::Number(212));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day10::parse("").unwrap_err(),
+            ParseError::new(1, 1, "", "missing lengths")
+        );
+        assert_eq!(
+            Day10::parse("3,4,,1").unwrap_err(),
+            ParseError::new(1, 5, "", "invalid length")
+        );
+        // Huge lengths used to be allocated before being reversed.
+        assert_eq!(
+            Day10::parse("3,99999999999").unwrap_err(),
+            ParseError::new(1, 3, "99999999999", "length longer than the list")
+        );
+    }
+
+    #[test]
+    fn test_examples2() {
+        let input = Day10::parse("1,2,3\n").unwrap();
+        assert_eq!(
+            Day10::part2(&input),
+            Answer::from("3efbe78a8d82f29979031a4aa0b16a9d")
+        );
+    }
+
+    #[test]
+    fn test_solve_with_error() {
+        let input = Day10::parse("3,4,1,5").unwrap();
+        assert_eq!(
+            Day10::solve_with(&input, 1, &[("size", 4)]),
+            Err("length 5 longer than the list of 4".to_owned())
+        );
+        assert_eq!(
+            Day10::solve_with(&input, 2, &[("size", 5)]),
+            Err("unknown parameter `size` for part 2".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        assert_eq!(calculate_result_traced(5, &[3, 4, 1, 5], &mut events), 12);
+        let knot = |length: usize, list: &[u8]| {
+            Event::new("knot").with("length", length).with("list", list)
+        };
+        assert_eq!(
+            events,
+            [
+                knot(3, &[2, 1, 0, 3, 4]),
+                knot(4, &[4, 3, 0, 1, 2]),
+                knot(1, &[4, 3, 0, 1, 2]),
+                knot(5, &[3, 4, 2, 1, 0])
+            ]
+        );
+        events.clear();
+        let input = "1,2,3".to_owned();
+        assert_eq!(Day10::trace(&input, 2, &mut events), Day10::part2(&input));
+        assert_eq!(events.len(), ROUNDS);
+    }
+
+    #[test]
+    fn test_result2() {
+        let input = Day10::parse(&get_input()).unwrap();
+        assert_eq!(
+            Day10::part2(&input),
+            Answer::from("96de9657665675b51cd03f0b3528ba26")
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = Day10::parse(&generate::<Day10>(10, 100)).unwrap();
+        assert_eq!(input.split(',').count(), 100);
+        assert_eq!(Day10::part2(&input).to_string().len(), 32);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_valid_lengths(lengths in prop::collection::vec(0usize..=256, 1..20)) {
+            let text = lengths.iter().map(|e| e.to_string()).collect::<Vec<_>>().join(",");
+            let input = Day10::parse(&text).unwrap();
+            prop_assert_eq!(
+                Day10::part1(&input),
+                Answer::from(calculate_result(256, &lengths))
+            );
+            prop_assert_eq!(Day10::part2(&input).to_string().len(), 32);
+        }
+    }
+}
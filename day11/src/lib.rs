@@ -0,0 +1,268 @@
+//! Day 11: Hex Ed.
+
+use aoc_common::parse::lines;
+use aoc_common::trace::NoTrace;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer};
+
+/// The fewest steps needed to reach the end of the comma separated path on the
+/// hex grid, or with `part2` the furthest it ever got.
+pub fn shortest_path(path: &str, part2: bool) -> u32 {
+    shortest_path_traced(path, part2, &mut NoTrace)
+}
+
+/// Like `shortest_path`, passing each step to the tracer as a `step` event
+/// with the position it reached and how far that is from the start.
+pub fn shortest_path_traced(path: &str, part2: bool, tracer: &mut dyn Tracer) -> u32 {
+    let (mut x, mut y, mut max_steps) = (0, 0, 0);
+
+    // Start by calculating the position from spawn.
+    for p in path.split(",") {
+        match p {
+            "n" => y -= 2,
+            "ne" => {
+                y -= 1;
+                x += 1;
+            }
+            "nw" => {
+                y -= 1;
+                x -= 1;
+            }
+            "s" => y += 2,
+            "se" => {
+                y += 1;
+                x += 1;
+            }
+            "sw" => {
+                y += 1;
+                x -= 1;
+            }
+            _ => panic!("Unknown path: {}", p),
+        }
+        if tracer.enabled() {
+            tracer.event(
+                Event::new("step")
+                    .with("direction", p)
+                    .with("x", x)
+                    .with("y", y)
+                    .with("distance", calculate_least_amount_of_moves(x, y)),
+            );
+        }
+        // Probably not that efficient, due to calculating steps after each
+        // path change, but fast it enough it seems :)
+        if part2 {
+            let new_max_steps = calculate_least_amount_of_moves(x, y);
+            if new_max_steps > max_steps {
+                max_steps = new_max_steps;
+            }
+        }
+    }
+
+    if part2 {
+        return max_steps;
+    }
+    calculate_least_amount_of_moves(x, y)
+}
+
+/// The fewest steps from the origin to `(x, y)` on the hex grid.
+pub fn calculate_least_amount_of_moves(x: i32, y: i32) -> u32 {
+    // Every step moves one column at most, and two rows at most when staying
+    // in the column.
+    let (x, y) = (x.unsigned_abs(), y.unsigned_abs());
+    x.max((x + y) / 2)
+}
+
+/// Day 11: Hex Ed.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        let input = input.trim();
+        let mut lines = lines(input);
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::new(1, 1, "", "missing path"))?;
+        if let Some(line) = lines.next() {
+            return Err(line.invalid("expected the path on a single line"));
+        }
+        for direction in line.text.split(',') {
+            if !matches!(direction, "n" | "ne" | "nw" | "s" | "se" | "sw") {
+                return Err(line.error(direction, "unknown direction"));
+            }
+        }
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &String) -> Answer {
+        shortest_path(input, false).into()
+    }
+
+    fn part2(input: &String) -> Answer {
+        shortest_path(input, true).into()
+    }
+}
+
+impl Generate for Day11 {
+    const SIZE: usize = 8223;
+
+    /// A path of `size` steps, drifting in one direction.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let directions = ["n", "ne", "nw", "s", "se", "sw"];
+        let drift = *rng.choose(&directions);
+        (0..size.max(1))
+            .map(|_| {
+                if rng.one_in(4) {
+                    drift
+                } else {
+                    *rng.choose(&directions)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+impl Trace for Day11 {
+    fn trace(input: &String, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        shortest_path_traced(input, part == 2, tracer).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use proptest::prelude::*;
+    use std::collections::hash_map::Entry;
+    use std::collections::HashMap;
+
+    #[test]
+    fn part1_examles() {
+        assert_eq!(shortest_path("ne,ne,ne", false), 3);
+        assert_eq!(shortest_path("ne,ne,sw,sw", false), 0);
+        assert_eq!(shortest_path("ne,ne,s,s", false), 2);
+        assert_eq!(shortest_path("se,sw,se,sw,sw", false), 3);
+    }
+
+    #[test]
+    fn test_mostly_sideways() {
+        assert_eq!(shortest_path("ne,ne,se", false), 3);
+        assert_eq!(shortest_path("nw,sw,nw,sw", false), 4);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let input = "ne,ne,s,s".to_owned();
+        assert_eq!(Day11::trace(&input, 2, &mut events), Answer::Number(2));
+        let step = |direction: &str, x: i32, y: i32, distance: u32| {
+            Event::new("step")
+                .with("direction", direction)
+                .with("x", x)
+                .with("y", y)
+                .with("distance", distance)
+        };
+        assert_eq!(
+            events,
+            [
+                step("ne", 1, -1, 1),
+                step("ne", 2, -2, 2),
+                step("s", 2, 0, 2),
+                step("s", 2, 2, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day11::parse("ne,ne,e").unwrap_err(),
+            ParseError::new(1, 7, "e", "unknown direction")
+        );
+        assert_eq!(
+            Day11::parse("\n").unwrap_err(),
+            ParseError::new(1, 1, "", "missing path")
+        );
+    }
+
+    #[test]
+    fn part1_result() {
+        assert_eq!(shortest_path(&get_input(), false), 747);
+    }
+
+    #[test]
+    fn part2_result() {
+        assert_eq!(shortest_path(&get_input(), true), 1544)
+    }
+
+    const STEPS: [(&str, i32, i32); 6] = [
+        ("n", 0, -2),
+        ("ne", 1, -1),
+        ("se", 1, 1),
+        ("s", 0, 2),
+        ("sw", -1, 1),
+        ("nw", -1, -1),
+    ];
+
+    // The fewest steps to every position within `radius` steps, by a breadth
+    // first search from the origin.
+    fn naive_distances(radius: u32) -> HashMap<(i32, i32), u32> {
+        let mut result = HashMap::new();
+        result.insert((0, 0), 0);
+        let mut frontier = vec![(0, 0)];
+        for distance in 1..=radius {
+            let mut next = vec![];
+            for (x, y) in frontier {
+                for &(_, dx, dy) in &STEPS {
+                    let position = (x + dx, y + dy);
+                    if let Entry::Vacant(e) = result.entry(position) {
+                        e.insert(distance);
+                        next.push(position);
+                    }
+                }
+            }
+            frontier = next;
+        }
+        result
+    }
+
+    #[test]
+    fn test_generate() {
+        let path = Day11::parse(&generate::<Day11>(11, 1000)).unwrap();
+        assert_eq!(path.split(',').count(), 1000);
+        let (distance, furthest) = (shortest_path(&path, false), shortest_path(&path, true));
+        assert!(0 < distance && distance <= furthest && furthest <= 1000);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_naive(path in prop::collection::vec(0usize..6, 1..30)) {
+            let distances = naive_distances(30);
+            let (mut x, mut y, mut furthest) = (0, 0, 0);
+            for &step in &path {
+                x += STEPS[step].1;
+                y += STEPS[step].2;
+                furthest = furthest.max(distances[&(x, y)]);
+            }
+            let path = path.iter().map(|&step| STEPS[step].0).collect::<Vec<_>>().join(",");
+            prop_assert_eq!(shortest_path(&path, false), distances[&(x, y)]);
+            prop_assert_eq!(shortest_path(&path, true), furthest);
+        }
+
+        #[test]
+        fn prop_reversed_path_returns(path in prop::collection::vec(0usize..6, 1..30)) {
+            let back = path.iter().rev().map(|&step| (step + 3) % 6);
+            let path = path
+                .iter()
+                .copied()
+                .chain(back)
+                .map(|step| STEPS[step].0)
+                .collect::<Vec<_>>()
+                .join(",");
+            prop_assert_eq!(shortest_path(&path, false), 0);
+        }
+    }
+}
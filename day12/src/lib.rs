@@ -0,0 +1,330 @@
+//! Day 12: Digital Plumber.
+
+use aoc_common::parse::lines;
+use aoc_common::trace::NoTrace;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::iter::FromIterator;
+
+/// The programs each program can talk to directly, by ID.
+pub type Programs = HashMap<u32, HashSet<u32>>;
+
+/// Parses the pipes, one program per line like `2 <-> 0, 3, 4`.
+pub fn parse_programs(input: &str) -> Result<Programs, ParseError> {
+    let mut result = Programs::new();
+    for line in lines(input).filter(|e| !e.text.is_empty()) {
+        let (node, children) = line.split_once(" <-> ")?;
+        let node = line.parse::<u32>(node, "program")?;
+        let children = children
+            .split(", ")
+            .map(|e| line.parse::<u32>(e, "program"))
+            .collect::<Result<HashSet<_>, _>>()?;
+        result.insert(node, children.to_owned());
+        for child in children {
+            if let std::collections::hash_map::Entry::Vacant(e) = result.entry(child) {
+                let mut value_set = HashSet::new();
+                value_set.insert(node);
+                e.insert(value_set);
+            } else {
+                result.get_mut(&child).unwrap().insert(node);
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// The IDs of the programs in the same group as `program_id`.
+pub fn determine_programgroup(programs: &Programs, program_id: u32) -> HashSet<u32> {
+    determine_programgroup_traced(programs, program_id, &mut NoTrace)
+}
+
+/// Like `determine_programgroup`, passing each program reached to the tracer
+/// as a `visit` event. The pipes of each program are followed from the lowest
+/// ID up, for the same trace on every run.
+pub fn determine_programgroup_traced(
+    programs: &Programs,
+    program_id: u32,
+    tracer: &mut dyn Tracer,
+) -> HashSet<u32> {
+    let mut result = HashSet::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(program_id);
+    while !queue.is_empty() {
+        let elem = queue.pop_front().unwrap();
+        result.insert(elem);
+        if tracer.enabled() {
+            tracer.event(Event::new("visit").with("program", elem));
+        }
+        // Programs without any pipes are only in a group with themselves.
+        let mut children = programs
+            .get(&elem)
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        children.sort_unstable();
+        for &child in children {
+            if result.insert(child) {
+                queue.push_back(child);
+            }
+        }
+    }
+    result
+}
+
+/// Counts the groups of programs that can talk to each other.
+pub fn count_program_groups(programs: &Programs) -> u32 {
+    count_program_groups_traced(programs, &mut NoTrace)
+}
+
+/// Like `count_program_groups`, passing each group found to the tracer as a
+/// `group` event with the lowest ID in it and its size. The groups are found
+/// from the lowest ID up.
+pub fn count_program_groups_traced(programs: &Programs, tracer: &mut dyn Tracer) -> u32 {
+    // Put all the nodes in a queue, the lowest ID last to pop it first.
+    let mut queue = programs.keys().copied().collect::<Vec<_>>();
+    queue.sort_unstable_by(|a, b| b.cmp(a));
+    // Put all the nodes in a set.
+    let mut all_programs = HashSet::from_iter(programs.keys().copied());
+    let mut count = 0;
+    // Fetch a node from the queue, skipping it if it's already been processed
+    // from another node linked to it somehow.
+    while let Some(elem) = queue.pop() {
+        if !all_programs.contains(&elem) {
+            continue;
+        }
+        // Remove all the nodes linked to the given node, from the set of all
+        // the programs.
+        let group = determine_programgroup(programs, elem);
+        if tracer.enabled() {
+            tracer.event(
+                Event::new("group")
+                    .with("program", elem)
+                    .with("size", group.len()),
+            );
+        }
+        all_programs = all_programs.difference(&group).copied().collect();
+        // Increment the program group counter.
+        count += 1;
+    }
+    count
+}
+
+/// Day 12: Digital Plumber.
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Programs;
+
+    fn parse(input: &str) -> Result<Programs, ParseError> {
+        parse_programs(input)
+    }
+
+    fn part1(input: &Programs) -> Answer {
+        determine_programgroup(input, 0).len().into()
+    }
+
+    fn part2(input: &Programs) -> Answer {
+        count_program_groups(input).into()
+    }
+}
+
+impl Generate for Day12 {
+    const SIZE: usize = 2000;
+
+    /// `size` programs, each piped to a few others.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let mut pipes = vec![BTreeSet::new(); size];
+        for program in 0..size {
+            for _ in 0..rng.below(3) {
+                let other = rng.below(size);
+                pipes[program].insert(other);
+                pipes[other].insert(program);
+            }
+        }
+        pipes
+            .iter_mut()
+            .enumerate()
+            .map(|(program, others)| {
+                if others.is_empty() {
+                    others.insert(program);
+                }
+                let others = others.iter().map(usize::to_string).collect::<Vec<_>>();
+                format!("{} <-> {}", program, others.join(", "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Trace for Day12 {
+    fn trace(input: &Programs, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        match part {
+            1 => determine_programgroup_traced(input, 0, tracer).len().into(),
+            _ => count_program_groups_traced(input, tracer).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use aoc_common::trace::Value;
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = "0 <-> 2
+1 <-> 1
+2 <-> 0, 3, 4
+3 <-> 2, 4
+4 <-> 2, 3, 6
+5 <-> 6
+6 <-> 4, 5";
+
+    #[test]
+    fn part1_examles() {
+        let input = parse_programs(TEST_INPUT).unwrap();
+        assert_eq!(determine_programgroup(&input, 0).len(), 6);
+    }
+
+    #[test]
+    fn part1_result() {
+        let input = parse_programs(&get_input()).unwrap();
+        assert_eq!(determine_programgroup(&input, 0).len(), 134);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let input = parse_programs(TEST_INPUT).unwrap();
+        assert_eq!(count_program_groups(&input), 2);
+    }
+
+    #[test]
+    fn part2_result() {
+        let input = parse_programs(&get_input()).unwrap();
+        assert_eq!(count_program_groups(&input), 193);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let input = parse_programs(TEST_INPUT).unwrap();
+        assert_eq!(Day12::trace(&input, 1, &mut events), Answer::Number(6));
+        let visits = events.iter().map(|e| e.get("program").unwrap().clone());
+        let expected = [0, 2, 3, 4, 6, 5].map(Value::Number);
+        assert_eq!(visits.collect::<Vec<_>>(), expected);
+        events.clear();
+        assert_eq!(Day12::trace(&input, 2, &mut events), Answer::Number(2));
+        let group = |program: u32, size: usize| {
+            Event::new("group")
+                .with("program", program)
+                .with("size", size)
+        };
+        assert_eq!(events, [group(0, 6), group(1, 1)]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_programs("0 <-> 2\n1 <-> 1, y").unwrap_err(),
+            ParseError::new(2, 10, "y", "invalid program")
+        );
+        assert_eq!(
+            parse_programs("0 - 2").unwrap_err(),
+            ParseError::new(1, 1, "0 - 2", "expected ` <-> `")
+        );
+    }
+
+    #[test]
+    fn test_missing_program() {
+        // Found by fuzzing, a program without any pipes used to panic.
+        let programs = parse_programs("").unwrap();
+        assert_eq!(determine_programgroup(&programs, 0), HashSet::from([0]));
+        assert_eq!(count_program_groups(&programs), 0);
+    }
+
+    // The pipes of `programs` programs connected by `pipes`, a line per
+    // program. Programs without pipes are connected to themselves.
+    fn village(programs: u32, pipes: &[(u32, u32)]) -> String {
+        let mut lines = vec![];
+        for program in 0..programs {
+            let mut connected = pipes
+                .iter()
+                .filter_map(|&(a, b)| match program {
+                    _ if a == program => Some(b),
+                    _ if b == program => Some(a),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if connected.is_empty() {
+                connected.push(program);
+            }
+            let connected = connected.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+            lines.push(format!("{} <-> {}", program, connected.join(", ")));
+        }
+        lines.join("\n")
+    }
+
+    // The group of each program, by union-find.
+    fn naive_groups(programs: u32, pipes: &[(u32, u32)]) -> Vec<u32> {
+        fn find(parents: &mut [u32], program: u32) -> u32 {
+            let parent = parents[program as usize];
+            if parent == program {
+                return program;
+            }
+            let root = find(parents, parent);
+            parents[program as usize] = root;
+            root
+        }
+        let mut parents = (0..programs).collect::<Vec<_>>();
+        for &(a, b) in pipes {
+            let (a, b) = (find(&mut parents, a), find(&mut parents, b));
+            parents[a as usize] = b;
+        }
+        (0..programs).map(|e| find(&mut parents, e)).collect()
+    }
+
+    fn village_strategy() -> impl Strategy<Value = (u32, Vec<(u32, u32)>)> {
+        (1u32..40).prop_flat_map(|programs| {
+            let pipes = prop::collection::vec((0..programs, 0..programs), 0..40);
+            (Just(programs), pipes)
+        })
+    }
+
+    #[test]
+    fn test_generate() {
+        let programs = parse_programs(&generate::<Day12>(12, 1000)).unwrap();
+        assert_eq!(programs.len(), 1000);
+        let groups = count_program_groups(&programs);
+        assert!(1 < groups && groups < 1000);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_groups_partition_programs((programs, pipes) in village_strategy()) {
+            let parsed = parse_programs(&village(programs, &pipes)).unwrap();
+            prop_assert_eq!(parsed.len() as u32, programs);
+            let mut remaining = parsed.keys().copied().collect::<HashSet<_>>();
+            let mut sizes = 0;
+            while let Some(&program) = remaining.iter().next() {
+                let group = determine_programgroup(&parsed, program);
+                prop_assert!(group.is_subset(&remaining));
+                remaining = remaining.difference(&group).copied().collect();
+                sizes += group.len();
+            }
+            prop_assert_eq!(sizes as u32, programs);
+        }
+
+        #[test]
+        fn prop_matches_union_find((programs, pipes) in village_strategy()) {
+            let parsed = parse_programs(&village(programs, &pipes)).unwrap();
+            let groups = naive_groups(programs, &pipes);
+            let expected = groups.iter().collect::<HashSet<_>>().len() as u32;
+            prop_assert_eq!(count_program_groups(&parsed), expected);
+            let group = (0..programs).filter(|&e| groups[e as usize] == groups[0]).collect();
+            prop_assert_eq!(determine_programgroup(&parsed, 0), group);
+        }
+    }
+}
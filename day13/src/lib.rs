@@ -0,0 +1,437 @@
+//! Day 13: Packet Scanners.
+
+use aoc_common::parse::lines;
+use aoc_common::trace::NoTrace;
+use aoc_common::visualize::{faint, highlight};
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer, Visualize};
+
+/// The depth and range of each scanner, by increasing depth.
+pub type Firewall = Vec<(u32, u32)>;
+
+/// Parses the layers of the firewall, one per line like `0: 3`.
+pub fn parse(input: &str) -> Result<Firewall, ParseError> {
+    let mut result = Firewall::new();
+    for line in lines(input).filter(|e| !e.text.is_empty()) {
+        let (depth_text, range_text) = line.split_once(": ")?;
+        let depth = line.parse::<u32>(depth_text, "depth")?;
+        let range = line.parse::<u32>(range_text, "range")?;
+        if result.last().is_some_and(|&(last, _)| depth <= last) {
+            return Err(line.error(depth_text, "depth out of order"));
+        }
+        if range == 0 {
+            return Err(line.error(range_text, "invalid range"));
+        }
+        result.push((depth, range));
+    }
+    Ok(result)
+}
+
+/// The severity of passing through the firewall after waiting `offset`
+/// picoseconds, and whether the packet was caught. Stops at the first catch if
+/// `break_on_caught` is set. The severity stops at `u32::MAX`.
+pub fn calculate_severity(firewall: &Firewall, offset: u32, break_on_caught: bool) -> (u32, bool) {
+    calculate_severity_traced(firewall, offset, break_on_caught, &mut NoTrace)
+}
+
+/// Like `calculate_severity`, passing each catch to the tracer as a `caught`
+/// event with the delay, the depth of the layer and the severity so far.
+pub fn calculate_severity_traced(
+    firewall: &Firewall,
+    offset: u32,
+    break_on_caught: bool,
+    tracer: &mut dyn Tracer,
+) -> (u32, bool) {
+    let mut severity = 0u32;
+    let mut caught = false;
+    for &(depth, range) in firewall {
+        if (u64::from(depth) + u64::from(offset)).is_multiple_of(cycle(range)) {
+            if tracer.enabled() {
+                tracer.event(
+                    Event::new("caught")
+                        .with("delay", offset)
+                        .with("depth", depth)
+                        .with(
+                            "severity",
+                            severity.saturating_add(depth.saturating_mul(range)),
+                        ),
+                );
+            }
+            if break_on_caught {
+                return (depth.saturating_mul(range), true);
+            }
+            severity = severity.saturating_add(depth.saturating_mul(range));
+            if !caught {
+                caught = true;
+            }
+        }
+    }
+    (severity, caught)
+}
+
+// The picoseconds the scanner of a layer takes to get back to the top row,
+// where a scanner with a range of 1 always is.
+fn cycle(range: u32) -> u64 {
+    (2 * u64::from(range) - 2).max(1)
+}
+
+/// The fewest picoseconds to wait to pass through without being caught, or
+/// `None` if every delay gets caught.
+pub fn determine_delay(firewall: &Firewall) -> Option<u32> {
+    determine_delay_traced(firewall, &mut NoTrace)
+}
+
+/// Like `determine_delay`, passing the first catch of each delay tried to the
+/// tracer.
+pub fn determine_delay_traced(firewall: &Firewall, tracer: &mut dyn Tracer) -> Option<u32> {
+    let gcd = |mut a: u64, mut b: u64| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    // The scanners are all back where they started after the least common
+    // multiple of their cycles, so if none of the delays before it is safe,
+    // none is. Delays past `u32::MAX` aren't tried either way.
+    let period = firewall.iter().fold(1, |period, &(_, range)| {
+        let cycle = cycle(range);
+        (period / gcd(period, cycle) * cycle).min(1 << 32)
+    });
+    (0..period)
+        .map(|delay| delay as u32)
+        .find(|&delay| !calculate_severity_traced(firewall, delay, true, tracer).1)
+}
+
+/// Day 13: Packet Scanners.
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Firewall;
+
+    fn parse(input: &str) -> Result<Firewall, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Firewall) -> Answer {
+        calculate_severity(input, 0, false).0.into()
+    }
+
+    fn part2(input: &Firewall) -> Answer {
+        determine_delay(input).map_or(Answer::None, Answer::from)
+    }
+}
+
+// The row the scanner of a layer is on at the given picosecond.
+fn scanner_row(range: u32, time: u32) -> u32 {
+    if range == 1 {
+        return 0;
+    }
+    let cycle = 2 * range - 2;
+    let row = time % cycle;
+    if row < range {
+        row
+    } else {
+        cycle - row
+    }
+}
+
+// Draws the layers around the packet at the given picosecond, the packet
+// being on the top row of the layer at the depth of the picosecond.
+fn render_firewall(firewall: &Firewall, time: u32) -> String {
+    let last = firewall.last().map_or(0, |&(depth, _)| depth);
+    let first = time.saturating_sub(4);
+    let depths = first..(first + 16).min(last + 1);
+    let layers = firewall
+        .iter()
+        .filter(|&(depth, _)| depths.contains(depth))
+        .collect::<Vec<_>>();
+    let rows = layers.iter().map(|&&(_, range)| range).max().unwrap_or(1);
+    let mut result = depths
+        .clone()
+        .map(|depth| format!("{:^3} ", depth))
+        .collect::<String>();
+    result.push('\n');
+    for row in 0..rows {
+        for depth in depths.clone() {
+            let packet = row == 0 && depth == time;
+            let cell = match layers.iter().find(|&&&(d, _)| d == depth) {
+                Some(&&(_, range)) if row < range => {
+                    let scanner = if scanner_row(range, time) == row {
+                        'S'
+                    } else {
+                        ' '
+                    };
+                    if packet {
+                        highlight(format!("({})", scanner))
+                    } else {
+                        format!("[{}]", scanner)
+                    }
+                }
+                None if packet => highlight("(.)"),
+                None if row == 0 => faint("..."),
+                _ => "   ".to_owned(),
+            };
+            result += &cell;
+            result.push(' ');
+        }
+        result.push('\n');
+    }
+    result
+}
+
+impl Visualize for Day13 {
+    /// Moves the packet through the firewall without waiting, a layer per
+    /// frame.
+    fn frames(input: &Firewall) -> Box<dyn Iterator<Item = String> + '_> {
+        let last = input.last().map_or(0, |&(depth, _)| depth);
+        let mut severity = 0;
+        Box::new((0..=last).map(move |time| {
+            let caught = input
+                .iter()
+                .find(|&&(depth, range)| depth == time && scanner_row(range, time) == 0);
+            if let Some(&(depth, range)) = caught {
+                severity += depth * range;
+            }
+            format!(
+                "Picosecond {}, {}, severity {}\n{}",
+                time,
+                if caught.is_some() {
+                    "caught"
+                } else {
+                    "not caught"
+                },
+                severity,
+                render_firewall(input, time)
+            )
+        }))
+    }
+}
+
+impl Generate for Day13 {
+    const SIZE: usize = 43;
+
+    /// A firewall of `size` layers, with a delay of up to 10 million
+    /// picoseconds to pass it without being caught.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let delay = rng.range(0..=10_000_000);
+        let mut depth = 0;
+        let mut layers = vec![];
+        while layers.len() < size {
+            // Only ranges missing the packet after the delay will do.
+            let range = rng.range(2..=20);
+            if (depth + delay) % (2 * range - 2) != 0 {
+                layers.push(format!("{}: {}", depth, range));
+                depth += rng.range(1..=2);
+            }
+        }
+        layers.join("\n")
+    }
+}
+
+impl Trace for Day13 {
+    fn trace(input: &Firewall, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        match part {
+            1 => calculate_severity_traced(input, 0, false, tracer).0.into(),
+            _ => determine_delay_traced(input, tracer).map_or(Answer::None, Answer::from),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use aoc_common::visualize::strip_styles;
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = "0: 3
+1: 2
+4: 4
+6: 4";
+
+    #[test]
+    fn part1_examles() {
+        let input = parse(TEST_INPUT).unwrap();
+        assert_eq!(calculate_severity(&input, 0, false).0, 24);
+    }
+
+    #[test]
+    fn part1_result() {
+        let input = parse(&get_input()).unwrap();
+        assert_eq!(calculate_severity(&input, 0, false).0, 648);
+    }
+
+    #[test]
+    fn part2_examles() {
+        let input = parse(TEST_INPUT).unwrap();
+        assert_eq!(determine_delay(&input), Some(10));
+    }
+
+    #[test]
+    fn part2_results() {
+        let input = parse(&get_input()).unwrap();
+        assert_eq!(calculate_severity(&input, 3933124, true).0, 0);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let input = parse(TEST_INPUT).unwrap();
+        assert_eq!(Day13::trace(&input, 1, &mut events), Answer::Number(24));
+        let caught = |delay: u32, depth: u32, severity: u32| {
+            Event::new("caught")
+                .with("delay", delay)
+                .with("depth", depth)
+                .with("severity", severity)
+        };
+        assert_eq!(events, [caught(0, 0, 0), caught(0, 6, 24)]);
+        events.clear();
+        assert_eq!(Day13::trace(&input, 2, &mut events), Answer::Number(10));
+        assert_eq!(events.len(), 10);
+        assert_eq!(events[1], caught(1, 1, 2));
+    }
+
+    #[test]
+    fn test_frames() {
+        let input = parse(TEST_INPUT).unwrap();
+        let frames = Day13::frames(&input)
+            .map(|frame| strip_styles(&frame))
+            .collect::<Vec<_>>();
+        assert_eq!(frames.len(), 7);
+        assert!(frames[0].starts_with("Picosecond 0, caught, severity 0\n"));
+        assert!(frames[6].starts_with("Picosecond 6, caught, severity 24\n"));
+        let rows = frames[1].lines().skip(1).collect::<Vec<_>>();
+        assert_eq!(rows[0].trim_end(), " 0   1   2   3   4   5   6");
+        assert_eq!(rows[1].trim_end(), "[ ] ( ) ... ... [ ] ... [ ]");
+        assert_eq!(rows[2].trim_end(), "[S] [S]         [S]     [S]");
+        assert_eq!(rows[4].trim_end(), "                [ ]     [ ]");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("0: 3\n-1: 2").unwrap_err(),
+            ParseError::new(2, 1, "-1", "invalid depth")
+        );
+        assert_eq!(
+            parse("0: 3\n0: 2").unwrap_err(),
+            ParseError::new(2, 1, "0", "depth out of order")
+        );
+        assert_eq!(
+            parse("0: 0").unwrap_err(),
+            ParseError::new(1, 4, "0", "invalid range")
+        );
+    }
+
+    #[test]
+    fn test_parse_deep_layer() {
+        // Found by fuzzing, layers used to be allocated up to the deepest one.
+        assert_eq!(parse("4000000000: 2").unwrap(), vec![(4_000_000_000, 2)]);
+    }
+
+    #[test]
+    fn test_large_ranges() {
+        // Found by fuzzing, the cycle of this range used to overflow.
+        let firewall = parse("0: 4294967295\n4000000000: 2").unwrap();
+        assert_eq!(calculate_severity(&firewall, 0, false), (u32::MAX, true));
+        assert_eq!(Day13::part2(&firewall), Answer::Number(1));
+    }
+
+    #[test]
+    fn test_no_delay() {
+        // Every delay is caught, at depth 0 on the even ones and at depth 1 on
+        // the odd ones. This used to search until the delay overflowed.
+        let firewall = parse("0: 2\n1: 2").unwrap();
+        assert_eq!(Day13::part2(&firewall), Answer::None);
+        // A scanner with a range of 1 never leaves the top row.
+        let firewall = parse("3: 1").unwrap();
+        assert_eq!(calculate_severity(&firewall, 5, false), (3, true));
+        assert_eq!(naive_caught(&firewall, 5), (3, true));
+        assert_eq!(Day13::part2(&firewall), Answer::None);
+    }
+
+    // Moves every scanner a picosecond at a time, checking whether the packet
+    // is caught as it enters each layer.
+    fn naive_caught(firewall: &Firewall, delay: u32) -> (u32, bool) {
+        let mut dense = vec![0; firewall.last().map_or(0, |&(depth, _)| depth as usize + 1)];
+        for &(depth, range) in firewall {
+            dense[depth as usize] = range;
+        }
+        let firewall = &dense;
+        let mut scanners = vec![(0, 1); firewall.len()];
+        let (mut severity, mut caught) = (0, false);
+        for time in 0..delay + firewall.len() as u32 {
+            if time >= delay {
+                let depth = (time - delay) as usize;
+                if firewall[depth] > 0 && scanners[depth].0 == 0 {
+                    severity += depth as u32 * firewall[depth];
+                    caught = true;
+                }
+            }
+            for (scanner, &range) in scanners.iter_mut().zip(firewall) {
+                if range > 1 {
+                    if scanner.0 + scanner.1 < 0 || scanner.0 + scanner.1 >= range as i32 {
+                        scanner.1 = -scanner.1;
+                    }
+                    scanner.0 += scanner.1;
+                }
+            }
+        }
+        (severity, caught)
+    }
+
+    fn firewall(layers: &[(usize, u32)]) -> Firewall {
+        let input = layers
+            .iter()
+            .map(|(depth, range)| format!("{}: {}", depth, range))
+            .collect::<Vec<_>>()
+            .join("\n");
+        parse(&input).unwrap()
+    }
+
+    // Layers at increasing depths, from the depth gaps between them.
+    fn layers_strategy(
+        layers: usize,
+        ranges: std::ops::Range<u32>,
+    ) -> impl Strategy<Value = Vec<(usize, u32)>> {
+        prop::collection::vec((0usize..4, ranges), 1..layers).prop_map(|gaps| {
+            let mut depth = 0;
+            gaps.into_iter()
+                .map(|(gap, range)| {
+                    depth += gap + 1;
+                    (depth - 1, range)
+                })
+                .collect()
+        })
+    }
+
+    #[test]
+    fn test_generate() {
+        let firewall = parse(&generate::<Day13>(13, 20)).unwrap();
+        assert_eq!(firewall.len(), 20);
+        let delay = determine_delay(&firewall).unwrap();
+        assert!(!calculate_severity(&firewall, delay, false).1);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_severity_matches_naive(layers in layers_strategy(10, 2..8), delay in 0u32..50) {
+            let firewall = firewall(&layers);
+            prop_assert_eq!(
+                calculate_severity(&firewall, delay, false),
+                naive_caught(&firewall, delay)
+            );
+        }
+
+        #[test]
+        fn prop_delay_is_the_first_safe_one(layers in layers_strategy(4, 3..8)) {
+            // Three scanners with a period of 4 or more can never block every
+            // delay.
+            let firewall = firewall(&layers);
+            let delay = determine_delay(&firewall).unwrap();
+            prop_assert!(!naive_caught(&firewall, delay).1);
+            prop_assert!((0..delay).all(|delay| naive_caught(&firewall, delay).1));
+        }
+    }
+}
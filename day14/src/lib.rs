@@ -0,0 +1,246 @@
+//! Day 14: Disk Defragmentation.
+
+use aoc_common::trace::NoTrace;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer};
+use grid::DenseGrid;
+use knot_hash::knot_hash;
+
+/// The squares of the disk, `true` for the used ones.
+pub type Grid = DenseGrid<bool>;
+
+/// Counts the used squares.
+pub fn count_squares(grid: &Grid) -> u32 {
+    count_squares_traced(grid, &mut NoTrace)
+}
+
+/// Like `count_squares`, passing each row to the tracer as a `row` event with
+/// its used squares and the total so far.
+pub fn count_squares_traced(grid: &Grid, tracer: &mut dyn Tracer) -> u32 {
+    let mut total = 0;
+    for (y, row) in grid.rows().enumerate() {
+        let used = row.iter().filter(|&&used| used).count() as u32;
+        total += used;
+        if tracer.enabled() {
+            tracer.event(
+                Event::new("row")
+                    .with("row", y)
+                    .with("used", used)
+                    .with("total", total),
+            );
+        }
+    }
+    total
+}
+
+/// Counts the regions of adjacent used squares. Clears the grid as it goes.
+pub fn count_regions(grid: &mut Grid) -> u32 {
+    count_regions_traced(grid, &mut NoTrace)
+}
+
+/// Like `count_regions`, passing each region to the tracer as a `region`
+/// event with the first square found and its number of squares.
+pub fn count_regions_traced(grid: &mut Grid, tracer: &mut dyn Tracer) -> u32 {
+    let mut result = 0;
+    for point in grid.points().collect::<Vec<_>>() {
+        if !grid[point] {
+            continue;
+        }
+        // Clear the whole region, starting from the first square found.
+        result += 1;
+        grid[point] = false;
+        let mut size = 1;
+        let mut pending = vec![point];
+        while let Some(point) = pending.pop() {
+            for neighbour in point.neighbours4() {
+                if let Some(used @ true) = grid.get_mut(neighbour) {
+                    *used = false;
+                    size += 1;
+                    pending.push(neighbour);
+                }
+            }
+        }
+        if tracer.enabled() {
+            tracer.event(
+                Event::new("region")
+                    .with("x", point.x)
+                    .with("y", point.y)
+                    .with("size", size),
+            );
+        }
+    }
+    result
+}
+
+/// Generates the grid from the knot hashes of the key string.
+pub fn generate_grid(input: &str) -> Grid {
+    // Each row is the bits of the hash of the key and the row number.
+    let rows = (0..128)
+        .map(|i| {
+            knot_hash(format!("{}-{}", input, i).as_bytes())
+                .bits()
+                .collect()
+        })
+        .collect();
+    Grid::from_rows(rows)
+}
+
+/// Day 14: Disk Defragmentation.
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Grid, ParseError> {
+        Ok(generate_grid(input.trim()))
+    }
+
+    fn part1(input: &Grid) -> Answer {
+        count_squares(input).into()
+    }
+
+    fn part2(input: &Grid) -> Answer {
+        count_regions(&mut input.clone()).into()
+    }
+}
+
+impl Generate for Day14 {
+    const SIZE: usize = 8;
+
+    /// A key of `size` letters.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        rng.word(size..=size)
+    }
+}
+
+impl Trace for Day14 {
+    fn trace(input: &Grid, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        match part {
+            1 => count_squares_traced(input, tracer).into(),
+            _ => count_regions_traced(&mut input.clone(), tracer).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_examples1_initial_grid() {
+        let grid = generate_grid("flqrgnkx");
+        let rendered = grid.render(|&used| if used { '#' } else { '.' });
+        let corner = rendered
+            .lines()
+            .take(8)
+            .map(|row| &row[..8])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            corner,
+            [
+                "##.#.#..", ".#.#.#.#", "....#.#.", "#.#.##.#", ".##.#...", "##..#..#", ".#...#..",
+                "##.#.##."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let grid = Grid::from_rows(vec![vec![true, true, false], vec![false, false, true]]);
+        assert_eq!(Day14::trace(&grid, 1, &mut events), Answer::Number(3));
+        let row = |row: usize, used: u32, total: u32| {
+            Event::new("row")
+                .with("row", row)
+                .with("used", used)
+                .with("total", total)
+        };
+        assert_eq!(events, [row(0, 2, 2), row(1, 1, 3)]);
+        events.clear();
+        assert_eq!(Day14::trace(&grid, 2, &mut events), Answer::Number(2));
+        let region = |x: i32, y: i32, size: u32| {
+            Event::new("region")
+                .with("x", x)
+                .with("y", y)
+                .with("size", size)
+        };
+        assert_eq!(events, [region(0, 0, 2), region(2, 1, 1)]);
+    }
+
+    #[test]
+    fn test_examples1_count_squares() {
+        let grid = generate_grid("flqrgnkx");
+        assert_eq!(count_squares(&grid), 8108);
+    }
+
+    #[test]
+    fn test_examples2() {
+        let mut grid = generate_grid("flqrgnkx");
+        assert_eq!(count_regions(&mut grid), 1242);
+    }
+
+    // Labels each used square with the region it belongs to, by union-find.
+    fn naive_regions(grid: &Grid) -> usize {
+        fn find(parents: &mut [usize], i: usize) -> usize {
+            if parents[i] != i {
+                parents[i] = find(parents, parents[i]);
+            }
+            parents[i]
+        }
+        let grid = grid.rows().collect::<Vec<_>>();
+        let width = grid.first().map_or(0, |row| row.len());
+        let mut parents = (0..grid.len() * width).collect::<Vec<_>>();
+        for y in 0..grid.len() {
+            for x in 0..width {
+                if !grid[y][x] {
+                    continue;
+                }
+                for (ny, nx) in [(y + 1, x), (y, x + 1)] {
+                    if ny < grid.len() && nx < width && grid[ny][nx] {
+                        let (a, b) = (
+                            find(&mut parents, y * width + x),
+                            find(&mut parents, ny * width + nx),
+                        );
+                        parents[a] = b;
+                    }
+                }
+            }
+        }
+        let mut roots = (0..grid.len() * width)
+            .filter(|&i| grid[i / width][i % width])
+            .map(|i| find(&mut parents, i))
+            .collect::<Vec<_>>();
+        roots.sort();
+        roots.dedup();
+        roots.len()
+    }
+
+    fn grid_strategy() -> impl Strategy<Value = Grid> {
+        (1usize..20, 1usize..20).prop_flat_map(|(width, height)| {
+            let row = prop::collection::vec(any::<bool>(), width);
+            prop::collection::vec(row, height).prop_map(Grid::from_rows)
+        })
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day14>(14, 8);
+        assert_eq!(input.len(), 8);
+        let grid = Day14::parse(&input).unwrap();
+        assert!(count_squares(&grid) > count_regions(&mut grid.clone()) as u32);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_regions_match_naive(grid in grid_strategy()) {
+            let expected = naive_regions(&grid);
+            let squares = count_squares(&grid);
+            let mut cleared = grid.clone();
+            prop_assert_eq!(count_regions(&mut cleared) as usize, expected);
+            prop_assert!(expected <= squares as usize);
+            prop_assert_eq!(count_squares(&cleared), 0);
+        }
+    }
+}
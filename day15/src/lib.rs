@@ -0,0 +1,242 @@
+//! Day 15: Dueling Generators.
+
+use aoc_common::parse::lines;
+use aoc_common::trace::NoTrace;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer};
+use std::option::Option;
+
+const FACTOR_A: u64 = 16807;
+const FACTOR_B: u64 = 48271;
+
+// Generates the next value based no the current value, the factor to multiply
+// and optionally a modulo check to validate.
+fn next_val(val: u64, modulo: &Option<u64>, factor: u64) -> u64 {
+    let mut res = val;
+
+    // If the modulo part is none, skip the whole check part and just return
+    // the next number.
+    if modulo.is_none() {
+        return res * factor % 0x7fff_ffff;
+    }
+
+    // Otherwise, unwrap() and check until we hit a valid number.
+    let modulo = modulo.unwrap();
+    loop {
+        res = res * factor % 0x7fff_ffff;
+        if res.is_multiple_of(modulo) {
+            return res;
+        }
+    }
+}
+
+/// Counts the pairs of values out of `iterations` whose lowest 16 bits match.
+/// A generator with a modulo only yields values that are multiples of it.
+pub fn calculate(
+    initial_a: u32,
+    initial_b: u32,
+    modulo_a: &Option<u64>,
+    modulo_b: &Option<u64>,
+    iterations: u32,
+) -> u32 {
+    calculate_traced(
+        initial_a,
+        initial_b,
+        modulo_a,
+        modulo_b,
+        iterations,
+        &mut NoTrace,
+    )
+}
+
+/// Like `calculate`, passing each pair that matches to the tracer as a
+/// `match` event with its number, both values and the count so far.
+pub fn calculate_traced(
+    initial_a: u32,
+    initial_b: u32,
+    modulo_a: &Option<u64>,
+    modulo_b: &Option<u64>,
+    iterations: u32,
+    tracer: &mut dyn Tracer,
+) -> u32 {
+    let (mut a, mut b) = (initial_a as u64, initial_b as u64);
+    let mut count = 0;
+    for pair in 1..=iterations {
+        a = next_val(a, modulo_a, FACTOR_A);
+        b = next_val(b, modulo_b, FACTOR_B);
+        if (a ^ b) & 0xffff == 0 {
+            count += 1;
+            if tracer.enabled() {
+                tracer.event(
+                    Event::new("match")
+                        .with("pair", pair)
+                        .with("a", a)
+                        .with("b", b)
+                        .with("count", count),
+                );
+            }
+        }
+    }
+    count
+}
+
+/// Parses the starting values of generator A and B.
+pub fn parse(input: &str) -> Result<(u32, u32), ParseError> {
+    let mut values = vec![];
+    for line in lines(input).filter(|line| !line.text.trim().is_empty()) {
+        let value = line.text.split_whitespace().last().unwrap();
+        values.push(line.parse(value, "starting value")?);
+    }
+    match values[..] {
+        [a, b] => Ok((a, b)),
+        _ => Err(ParseError::new(
+            1,
+            1,
+            input.trim(),
+            "expected the starting values of generator A and B",
+        )),
+    }
+}
+
+/// Day 15: Dueling Generators.
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = (u32, u32);
+
+    fn parse(input: &str) -> Result<(u32, u32), ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &(u32, u32)) -> Answer {
+        calculate(input.0, input.1, &None, &None, 40_000_000).into()
+    }
+
+    fn part2(input: &(u32, u32)) -> Answer {
+        calculate(input.0, input.1, &Some(4), &Some(8), 5_000_000).into()
+    }
+}
+
+impl Generate for Day15 {
+    const SIZE: usize = 1000;
+
+    /// Starting values from 1 up to `size`. The generators always make the
+    /// same number of values.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1) as i64;
+        format!(
+            "Generator A starts with {}\nGenerator B starts with {}",
+            rng.range(1..=size),
+            rng.range(1..=size)
+        )
+    }
+}
+
+impl Trace for Day15 {
+    fn trace(input: &(u32, u32), part: u8, tracer: &mut dyn Tracer) -> Answer {
+        match part {
+            1 => calculate_traced(input.0, input.1, &None, &None, 40_000_000, tracer).into(),
+            _ => calculate_traced(input.0, input.1, &Some(4), &Some(8), 5_000_000, tracer).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use proptest::prelude::*;
+
+    const TEST_INPUT: (u32, u32) = (65, 8921);
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        assert_eq!(
+            calculate_traced(TEST_INPUT.0, TEST_INPUT.1, &None, &None, 5, &mut events),
+            1
+        );
+        assert_eq!(
+            events,
+            [Event::new("match")
+                .with("pair", 3)
+                .with("a", 245556042)
+                .with("b", 1431495498)
+                .with("count", 1)]
+        );
+    }
+
+    #[test]
+    fn test_examples1() {
+        let result = calculate(TEST_INPUT.0, TEST_INPUT.1, &None, &None, 40_000_000);
+        assert_eq!(result, 588);
+    }
+
+    #[test]
+    fn test_result1() {
+        let (a, b) = parse(&get_input()).unwrap();
+        let result = calculate(a, b, &None, &None, 40_000_000);
+        assert_eq!(result, 619);
+    }
+
+    #[test]
+    fn test_examples2() {
+        let result = calculate(TEST_INPUT.0, TEST_INPUT.1, &Some(4), &Some(8), 5_000_000);
+        assert_eq!(result, 309);
+    }
+
+    #[test]
+    fn test_result2() {
+        let (a, b) = parse(&get_input()).unwrap();
+        let result = calculate(a, b, &Some(4), &Some(8), 5_000_000);
+        assert_eq!(result, 290);
+    }
+
+    // Every value of a generator, keeping only the multiples of `modulo`.
+    fn naive_generator(start: u32, factor: u64, modulo: u64) -> impl Iterator<Item = u64> {
+        std::iter::successors(Some(start as u64), move |value| {
+            Some(value * factor % 2147483647)
+        })
+        .skip(1)
+        .filter(move |value| value % modulo == 0)
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let (a, b) = parse(&generate::<Day15>(seed, 1000)).unwrap();
+            assert!((1..=1000).contains(&a) && (1..=1000).contains(&b));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_naive(
+            a in 1u32..2147483647,
+            b in 1u32..2147483647,
+            modulo_a in prop::sample::select(vec![1u64, 2, 4, 8]),
+            modulo_b in prop::sample::select(vec![1u64, 2, 4, 8]),
+            iterations in 0u32..2000,
+        ) {
+            let expected = naive_generator(a, FACTOR_A, modulo_a)
+                .zip(naive_generator(b, FACTOR_B, modulo_b))
+                .take(iterations as usize)
+                .filter(|(a, b)| a & 0xffff == b & 0xffff)
+                .count() as u32;
+            let modulo = |m| if m == 1 { None } else { Some(m) };
+            prop_assert_eq!(
+                calculate(a, b, &modulo(modulo_a), &modulo(modulo_b), iterations),
+                expected
+            );
+        }
+
+        #[test]
+        fn prop_same_generator_always_matches(start in 1u32..2147483647, iterations in 0u32..2000) {
+            // A generator always matches the value it generated itself.
+            let (a, b) = (naive_generator(start, FACTOR_A, 1), naive_generator(start, FACTOR_A, 1));
+            prop_assert_eq!(a.zip(b).take(iterations as usize).count() as u32, iterations);
+            prop_assert_eq!(parse(&format!("A {}\nB {}", start, start)).unwrap(), (start, start));
+        }
+    }
+}
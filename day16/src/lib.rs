@@ -0,0 +1,402 @@
+//! Day 16: Permutation Promenade.
+
+use aoc_common::parse::{lines, Line};
+use aoc_common::trace::NoTrace;
+use aoc_common::{
+    check_params, param, Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer,
+};
+use std::iter::FromIterator;
+use std::str::FromStr;
+
+/// The programs from `a` up to and including `to_char`, in order.
+pub fn generate_programs(to_char: char) -> Vec<char> {
+    (b'a'..(to_char as u8) + 1)
+        .map(|c| c as char)
+        .collect::<Vec<_>>()
+}
+
+/// Moves programs from the end to the front.
+#[derive(Copy, Clone, Debug)]
+pub struct InstSpin {
+    spin: usize,
+}
+/// Swaps the programs at two positions.
+#[derive(Copy, Clone, Debug)]
+pub struct InstExchange {
+    pos1: usize,
+    pos2: usize,
+}
+/// Swaps two programs by name.
+#[derive(Copy, Clone, Debug)]
+pub struct InstPartner {
+    pos1: char,
+    pos2: char,
+}
+/// A single dance move.
+#[derive(Debug)]
+pub enum Instruction {
+    Spin(InstSpin),
+    Exchange(InstExchange),
+    Partner(InstPartner),
+}
+
+impl Instruction {
+    // Whether the move only involves the first `len` programs.
+    fn fits(&self, len: usize) -> bool {
+        match self {
+            Instruction::Spin(_) => true,
+            Instruction::Exchange(inst) => inst.pos1.max(inst.pos2) < len,
+            Instruction::Partner(inst) => inst.pos1.max(inst.pos2) < (b'a' + len as u8) as char,
+        }
+    }
+}
+
+/// Performs the dance moves once.
+pub fn execute(instructions: &[Instruction], programs: &mut [char]) {
+    execute_traced(instructions, programs, &mut NoTrace)
+}
+
+/// Like `execute`, passing the order of the programs after each move to the
+/// tracer as a `move` event.
+pub fn execute_traced(
+    instructions: &[Instruction],
+    programs: &mut [char],
+    tracer: &mut dyn Tracer,
+) {
+    let len = programs.len();
+    for (i, inst) in instructions.iter().enumerate() {
+        match inst {
+            Instruction::Spin(inst) => {
+                let tmp = Vec::from_iter(programs.iter().copied());
+                let spin = inst.spin % len;
+                for i in 0..len {
+                    programs[(i + spin) % len] = tmp[i];
+                }
+            }
+            Instruction::Exchange(inst) => {
+                programs.swap(inst.pos1, inst.pos2);
+            }
+            Instruction::Partner(inst) => {
+                // Slow :(
+                let pos1 = programs.iter().position(|e| *e == inst.pos1).unwrap();
+                let pos2 = programs.iter().position(|e| *e == inst.pos2).unwrap();
+                programs.swap(pos1, pos2);
+            }
+        }
+        if tracer.enabled() {
+            tracer.event(
+                Event::new("move")
+                    .with("move", i)
+                    .with("programs", programs.iter().collect::<String>()),
+            );
+        }
+    }
+}
+
+/// Runs the dance `iterations` times, skipping ahead once the programs are back
+/// in their initial order.
+pub fn dance(instructions: &[Instruction], programs: &mut Vec<char>, iterations: usize) {
+    dance_traced(instructions, programs, iterations, &mut NoTrace)
+}
+
+/// Like `dance`, passing the order of the programs after each dance to the
+/// tracer as a `dance` event, and a `loop` event with the number of dances
+/// it takes to get back to the initial order.
+pub fn dance_traced(
+    instructions: &[Instruction],
+    programs: &mut Vec<char>,
+    iterations: usize,
+    tracer: &mut dyn Tracer,
+) {
+    let initial_programs = programs.clone();
+    let mut iterations = iterations;
+    let mut iteration = 0;
+    while iteration < iterations {
+        execute(instructions, programs);
+        iteration += 1;
+        if tracer.enabled() {
+            tracer.event(
+                Event::new("dance")
+                    .with("dance", iteration)
+                    .with("programs", programs.iter().collect::<String>()),
+            );
+        }
+        // When we find the loop length, skip all the whole loops left and
+        // reset the iteration counter.
+        if *programs == initial_programs {
+            tracer.event(Event::new("loop").with("length", iteration));
+            iterations %= iteration;
+            iteration = 0;
+        }
+    }
+}
+
+// Parses the two arguments of an exchange or a partner move, along with the
+// text of each so they can be pointed at.
+fn arguments<'a, T: FromStr>(
+    line: &Line<'a>,
+    inst: &'a str,
+    what: &str,
+) -> Result<(T, T, &'a str, &'a str), ParseError> {
+    let (pos1, pos2) = inst
+        .get(1..)
+        .and_then(|e| e.split_once('/'))
+        .ok_or_else(|| line.error(inst, "expected two arguments"))?;
+    Ok((line.parse(pos1, what)?, line.parse(pos2, what)?, pos1, pos2))
+}
+
+/// Parses the comma separated dance moves for the given number of programs.
+pub fn parse_instructions(
+    instructions: &str,
+    program_len: usize,
+) -> Result<Vec<Instruction>, ParseError> {
+    let mut result: Vec<Instruction> = Vec::new();
+    for line in lines(instructions) {
+        for inst in line.text.split(",") {
+            match inst.get(0..1) {
+                Some("s") => {
+                    let spin = line.parse::<usize>(&inst[1..], "spin")?;
+                    result.push(Instruction::Spin(InstSpin { spin }));
+                }
+                Some("x") => {
+                    let (pos1, pos2, token1, token2) = arguments(&line, inst, "position")?;
+                    for (pos, token) in [(pos1, token1), (pos2, token2)] {
+                        if pos >= program_len {
+                            return Err(line.error(token, "position out of range"));
+                        }
+                    }
+                    result.push(Instruction::Exchange(InstExchange { pos1, pos2 }));
+                }
+                Some("p") => {
+                    let (pos1, pos2, token1, token2) = arguments(&line, inst, "program")?;
+                    let last = (b'a' + program_len as u8 - 1) as char;
+                    for (pos, token) in [(pos1, token1), (pos2, token2)] {
+                        if !('a'..=last).contains(&pos) {
+                            return Err(line.error(token, "unknown program"));
+                        }
+                    }
+                    result.push(Instruction::Partner(InstPartner { pos1, pos2 }));
+                }
+                _ => return Err(line.error(inst, "unknown dance move")),
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Day 16: Permutation Promenade.
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        parse_instructions(input.trim(), 16)
+    }
+
+    fn part1(input: &Vec<Instruction>) -> Answer {
+        let mut programs = generate_programs('p');
+        execute(input, &mut programs);
+        programs.iter().collect::<String>().into()
+    }
+
+    fn part2(input: &Vec<Instruction>) -> Answer {
+        let mut programs = generate_programs('p');
+        dance(input, &mut programs, 1_000_000_000);
+        programs.iter().collect::<String>().into()
+    }
+
+    /// The examples dance with `programs` programs instead of 16, and part 2
+    /// dances `iterations` times instead of a billion.
+    fn solve_with(
+        input: &Vec<Instruction>,
+        part: u8,
+        params: &[(&str, i64)],
+    ) -> Result<Answer, String> {
+        check_params(params, part, &["programs", "iterations"])?;
+        let len = param(params, "programs", 16, 1..=16)? as usize;
+        let iterations = param(params, "iterations", 1_000_000_000, 0..=i64::MAX)? as usize;
+        if let Some(i) = input.iter().position(|inst| !inst.fits(len)) {
+            return Err(format!("move {} needs more than {} programs", i + 1, len));
+        }
+        let mut programs = generate_programs((b'a' + len as u8 - 1) as char);
+        match part {
+            1 => execute(input, &mut programs),
+            _ => dance(input, &mut programs, iterations),
+        }
+        Ok(programs.iter().collect::<String>().into())
+    }
+}
+
+impl Generate for Day16 {
+    const SIZE: usize = 10000;
+
+    /// `size` dance moves of the 16 programs.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let program = |rng: &mut Rng| (b'a' + rng.below(16) as u8) as char;
+        (0..size.max(1))
+            .map(|_| match rng.below(3) {
+                0 => format!("s{}", rng.range(1..=15)),
+                1 => format!("x{}/{}", rng.below(16), rng.below(16)),
+                _ => format!("p{}/{}", program(rng), program(rng)),
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+impl Trace for Day16 {
+    fn trace(input: &Vec<Instruction>, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        let mut programs = generate_programs('p');
+        match part {
+            1 => execute_traced(input, &mut programs, tracer),
+            _ => dance_traced(input, &mut programs, 1_000_000_000, tracer),
+        }
+        programs.iter().collect::<String>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_generator() {
+        let input = generate_programs('e');
+        assert_eq!(input, vec!['a', 'b', 'c', 'd', 'e']);
+    }
+
+    #[test]
+    fn test_result1() {
+        let mut input = generate_programs('p');
+        let instructions = parse_instructions(get_input().trim(), input.len()).unwrap();
+        execute(&instructions, &mut input);
+        assert_eq!(input.iter().collect::<String>(), "namdgkbhifpceloj");
+    }
+
+    #[test]
+    fn test_solve_with() {
+        // Spinning is the same whatever number of programs the input was
+        // parsed for.
+        let instructions = Day16::parse("s6,x1/2").unwrap();
+        let answer = Day16::solve_with(&instructions, 1, &[("programs", 5)]);
+        assert_eq!(answer, Ok(Answer::from("ebacd")));
+        let instructions = Day16::parse(TEST_INPUT).unwrap();
+        assert_eq!(
+            Day16::solve_with(&instructions, 2, &[("programs", 4)]),
+            Err("move 2 needs more than 4 programs".to_owned())
+        );
+        assert_eq!(
+            Day16::solve_with(&instructions, 1, &[("size", 5)]),
+            Err("unknown parameter `size` for part 1".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_dance_loop() {
+        // Spinning by 2 gets the programs back in order after 5 dances.
+        let instructions = parse_instructions("s2", 5).unwrap();
+        let mut input = generate_programs('e');
+        dance(&instructions, &mut input, 6);
+        assert_eq!(input, vec!['d', 'e', 'a', 'b', 'c']);
+    }
+
+    #[test]
+    fn test_result2() {
+        let mut input = generate_programs('p');
+        let instructions = parse_instructions(get_input().trim(), input.len()).unwrap();
+        dance(&instructions, &mut input, 1_000_000_000);
+        assert_eq!(input.iter().collect::<String>(), "ibmchklnofjpdeag");
+    }
+
+    const TEST_INPUT: &str = "s1,x3/4,pe/b";
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let mut programs = generate_programs('e');
+        let instructions = parse_instructions(TEST_INPUT, programs.len()).unwrap();
+        execute_traced(&instructions, &mut programs, &mut events);
+        let moved = |i: usize, programs: &str| {
+            Event::new("move")
+                .with("move", i)
+                .with("programs", programs)
+        };
+        assert_eq!(
+            events,
+            [moved(0, "eabcd"), moved(1, "eabdc"), moved(2, "baedc")]
+        );
+        events.clear();
+        let mut programs = generate_programs('e');
+        dance_traced(&instructions, &mut programs, 5, &mut events);
+        assert_eq!(programs, ['b', 'a', 'e', 'd', 'c']);
+        assert_eq!(
+            events[1],
+            Event::new("dance")
+                .with("dance", 2)
+                .with("programs", "ceadb")
+        );
+        assert_eq!(events[4], Event::new("loop").with("length", 4));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_instructions("s1,q3/4", 5).unwrap_err(),
+            ParseError::new(1, 4, "q3/4", "unknown dance move")
+        );
+        assert_eq!(
+            parse_instructions("s1,x3/9", 5).unwrap_err(),
+            ParseError::new(1, 7, "9", "position out of range")
+        );
+        assert_eq!(
+            parse_instructions("pe/z", 5).unwrap_err(),
+            ParseError::new(1, 4, "z", "unknown program")
+        );
+    }
+
+    fn moves_strategy(programs: usize) -> impl Strategy<Value = String> {
+        let last = (b'a' + programs as u8 - 1) as char;
+        let program = prop::char::range('a', last);
+        let dance_move = prop_oneof![
+            (0..programs * 2).prop_map(|spin| format!("s{}", spin)),
+            (0..programs, 0..programs).prop_map(|(a, b)| format!("x{}/{}", a, b)),
+            (program.clone(), program).prop_map(|(a, b)| format!("p{}/{}", a, b)),
+        ];
+        prop::collection::vec(dance_move, 1..20).prop_map(|moves| moves.join(","))
+    }
+
+    #[test]
+    fn test_generate() {
+        let moves = Day16::parse(&generate::<Day16>(16, 1000)).unwrap();
+        assert_eq!(moves.len(), 1000);
+        assert_eq!(Day16::part1(&moves).to_string().len(), 16);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_dance_is_a_permutation(moves in moves_strategy(16)) {
+            let instructions = parse_instructions(&moves, 16).unwrap();
+            let mut programs = generate_programs('p');
+            execute(&instructions, &mut programs);
+            programs.sort();
+            prop_assert_eq!(programs, generate_programs('p'));
+        }
+
+        #[test]
+        fn prop_dance_matches_naive(moves in moves_strategy(5), iterations in 0usize..500) {
+            let instructions = parse_instructions(&moves, 5).unwrap();
+            let mut expected = generate_programs('e');
+            for _ in 0..iterations {
+                execute(&instructions, &mut expected);
+            }
+            let mut programs = generate_programs('e');
+            dance(&instructions, &mut programs, iterations);
+            prop_assert_eq!(programs, expected);
+        }
+    }
+}
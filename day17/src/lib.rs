@@ -0,0 +1,308 @@
+//! Day 17: Spinlock.
+
+use aoc_common::parse::lines;
+use aoc_common::trace::NoTrace;
+use aoc_common::visualize::highlight;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer, Visualize};
+
+/// A circular buffer that inserts values after stepping forward.
+#[derive(Debug)]
+pub struct Spinlock {
+    buffer: Vec<i32>,
+    pos: usize,
+}
+
+impl Spinlock {
+    /// A buffer containing only 0, with room for `capacity` values.
+    pub fn new(capacity: usize) -> Spinlock {
+        // Allocate a buffer with the capacity required.
+        let mut vec = Vec::with_capacity(capacity);
+        vec.push(0);
+        // Create the struct and return in.
+        Spinlock {
+            buffer: vec,
+            pos: 0,
+        }
+    }
+
+    /// Steps forward `stepping` times and inserts `value` after the current
+    /// position, which becomes the new position.
+    pub fn step_forward(&mut self, stepping: usize, value: i32) {
+        // Move the position.
+        self.pos = (self.pos + stepping % self.buffer.len()) % self.buffer.len() + 1;
+        // Insert the new value.
+        self.buffer.insert(self.pos, value);
+    }
+
+    /// The value after the current position, wrapping around to the start.
+    pub fn result(&self) -> i32 {
+        self.buffer[(self.pos + 1) % self.buffer.len()]
+    }
+
+    // Draws the values around the current position, which is highlighted.
+    fn render(&self) -> String {
+        let len = self.buffer.len() as i64;
+        let around = 8.min((len - 1) / 2);
+        (-around..=around)
+            .map(|offset| {
+                let i = (self.pos as i64 + offset).rem_euclid(len) as usize;
+                if offset == 0 {
+                    highlight(format!("({})", self.buffer[i]))
+                } else {
+                    format!(" {} ", self.buffer[i])
+                }
+            })
+            .collect()
+    }
+}
+
+/// The value after the last one inserted, once the values 1 to `insertions`
+/// have been inserted.
+pub fn value_after_last(stepping: usize, insertions: i32) -> i32 {
+    value_after_last_traced(stepping, insertions, &mut NoTrace)
+}
+
+/// Like `value_after_last`, passing each value inserted to the tracer as an
+/// `insert` event with the position it was inserted at.
+pub fn value_after_last_traced(stepping: usize, insertions: i32, tracer: &mut dyn Tracer) -> i32 {
+    let mut spinlock = Spinlock::new(insertions as usize + 1);
+    for value in 1..=insertions {
+        spinlock.step_forward(stepping, value);
+        if tracer.enabled() {
+            tracer.event(
+                Event::new("insert")
+                    .with("value", value)
+                    .with("position", spinlock.pos),
+            );
+        }
+    }
+    spinlock.result()
+}
+
+/// The value after 0 once the values 1 to `insertions` have been inserted.
+pub fn value_after_zero(stepping: usize, insertions: usize) -> usize {
+    value_after_zero_traced(stepping, insertions, &mut NoTrace)
+}
+
+/// Like `value_after_zero`, passing each value inserted right after 0 to the
+/// tracer as an `after_zero` event.
+pub fn value_after_zero_traced(
+    stepping: usize,
+    insertions: usize,
+    tracer: &mut dyn Tracer,
+) -> usize {
+    let mut pos = 0;
+    let mut result = 0;
+    // Using a spinlock in memory is too expensive, just simulate it.
+    for value in 1..=insertions {
+        // Push the position based on the stepping, modulo with the value
+        // (which is otherwise the size of the buffer - 1).
+        pos = (pos + stepping % value) % value + 1;
+        // If we're at position 1, then we're just after the 0, since 0 is the
+        // only position that never changes, due to always inserting after a
+        // position.
+        if pos == 1 {
+            result = value;
+            if tracer.enabled() {
+                tracer.event(Event::new("after_zero").with("value", value));
+            }
+        }
+    }
+    result
+}
+
+/// Day 17: Spinlock.
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = usize;
+
+    fn parse(input: &str) -> Result<usize, ParseError> {
+        let line = lines(input.trim())
+            .next()
+            .ok_or_else(|| ParseError::new(1, 1, "", "missing number of steps"))?;
+        line.parse(line.text, "number of steps")
+    }
+
+    fn part1(input: &usize) -> Answer {
+        value_after_last(*input, 2017).into()
+    }
+
+    fn part2(input: &usize) -> Answer {
+        value_after_zero(*input, 50_000_000).into()
+    }
+}
+
+impl Visualize for Day17 {
+    /// Inserts the values of the first part, a value per frame.
+    fn frames(input: &usize) -> Box<dyn Iterator<Item = String> + '_> {
+        let mut spinlock = Spinlock::new(2018);
+        Box::new((0..2018).map(move |value| {
+            if value > 0 {
+                spinlock.step_forward(*input, value);
+            }
+            format!(
+                "Inserted {}, {} values in the buffer\n{}\n",
+                value,
+                spinlock.buffer.len(),
+                spinlock.render()
+            )
+        }))
+    }
+}
+
+impl Generate for Day17 {
+    const SIZE: usize = 377;
+
+    /// A number of steps from 1 up to `size`.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        rng.range(1..=size.max(1) as i64).to_string()
+    }
+}
+
+impl Trace for Day17 {
+    fn trace(input: &usize, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        match part {
+            1 => value_after_last_traced(*input, 2017, tracer).into(),
+            _ => value_after_zero_traced(*input, 50_000_000, tracer).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use aoc_common::visualize::strip_styles;
+    use proptest::prelude::*;
+
+    const TEST_INPUT: usize = 3;
+
+    #[test]
+    fn test_step_forward() {
+        let mut spinlock = Spinlock::new(10);
+        assert_eq!(spinlock.buffer, vec![0]);
+        assert_eq!(spinlock.pos, 0);
+        spinlock.step_forward(TEST_INPUT, 1);
+        assert_eq!(spinlock.buffer, vec![0, 1]);
+        assert_eq!(spinlock.pos, 1);
+        spinlock.step_forward(TEST_INPUT, 2);
+        assert_eq!(spinlock.buffer, vec![0, 2, 1]);
+        assert_eq!(spinlock.pos, 1);
+        spinlock.step_forward(TEST_INPUT, 3);
+        assert_eq!(spinlock.buffer, vec![0, 2, 3, 1]);
+        assert_eq!(spinlock.pos, 2);
+    }
+
+    #[test]
+    fn test_examples1() {
+        let mut spinlock = Spinlock::new(2018);
+        for value in 1..2018 {
+            spinlock.step_forward(3, value);
+        }
+        assert_eq!(spinlock.buffer[spinlock.pos], 2017);
+        assert_eq!(spinlock.result(), 638);
+    }
+
+    #[test]
+    fn test_result1() {
+        let mut spinlock = Spinlock::new(2018);
+        for value in 1..2018 {
+            spinlock.step_forward(get_input().parse().unwrap(), value);
+        }
+        assert_eq!(spinlock.buffer[spinlock.pos], 2017);
+        assert_eq!(spinlock.result(), 596);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        assert_eq!(value_after_last_traced(TEST_INPUT, 3, &mut events), 1);
+        let insert = |value: i32, position: usize| {
+            Event::new("insert")
+                .with("value", value)
+                .with("position", position)
+        };
+        assert_eq!(events, [insert(1, 1), insert(2, 1), insert(3, 2)]);
+        events.clear();
+        assert_eq!(value_after_zero_traced(TEST_INPUT, 9, &mut events), 9);
+        let after_zero = |value: usize| Event::new("after_zero").with("value", value);
+        assert_eq!(
+            events,
+            [after_zero(1), after_zero(2), after_zero(5), after_zero(9)]
+        );
+    }
+
+    #[test]
+    fn test_stepping_edge_cases() {
+        // Without stepping the last value inserted is the last in the buffer,
+        // so the value after it used to be looked up past the end.
+        assert_eq!(Day17::part1(&0), Answer::Number(0));
+        assert_eq!(value_after_zero(0, 5), 1);
+        // Stepping this far used to overflow the position. It steps like 3
+        // around buffers of up to 3 values.
+        assert_eq!(value_after_last(usize::MAX, 3), 1);
+        assert_eq!(value_after_zero(usize::MAX, 3), 2);
+    }
+
+    #[test]
+    fn test_frames() {
+        let frames = Day17::frames(&TEST_INPUT)
+            .take(10)
+            .map(|frame| strip_styles(&frame))
+            .collect::<Vec<_>>();
+        assert_eq!(frames[0], "Inserted 0, 1 values in the buffer\n(0)\n");
+        assert_eq!(frames[3], "Inserted 3, 4 values in the buffer\n 2 (3) 1 \n");
+        assert_eq!(
+            frames[9].lines().nth(1).unwrap(),
+            " 8  6  1  0 (9) 5  7  2  4 "
+        );
+        assert_eq!(Day17::frames(&TEST_INPUT).count(), 2018);
+    }
+
+    #[test]
+    fn test_result2() {
+        assert_eq!(
+            value_after_zero(get_input().parse().unwrap(), 50_000_000),
+            39051595
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let steps = Day17::parse(&generate::<Day17>(seed, 377)).unwrap();
+            assert!((1..=377).contains(&steps));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_buffer_holds_every_value(stepping in 0usize..500, insertions in 1i32..500) {
+            let mut spinlock = Spinlock::new(insertions as usize + 1);
+            for value in 1..=insertions {
+                spinlock.step_forward(stepping, value);
+            }
+            prop_assert_eq!(spinlock.buffer[spinlock.pos], insertions);
+            let mut buffer = spinlock.buffer.clone();
+            buffer.sort();
+            prop_assert_eq!(buffer, (0..=insertions).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn prop_value_after_zero_matches_spinlock(
+            stepping in 0usize..500,
+            insertions in 1usize..2000,
+        ) {
+            let mut spinlock = Spinlock::new(insertions + 1);
+            for value in 1..=insertions {
+                spinlock.step_forward(stepping, value as i32);
+            }
+            let zero = spinlock.buffer.iter().position(|&e| e == 0).unwrap();
+            let expected = spinlock.buffer[(zero + 1) % spinlock.buffer.len()];
+            prop_assert_eq!(value_after_zero(stepping, insertions), expected as usize);
+        }
+    }
+}
@@ -0,0 +1,403 @@
+//! Day 18: Duet.
+
+use aoc_common::parse::Line;
+use aoc_common::trace::NoTrace;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer};
+use std::collections::VecDeque;
+use vm::{Flow, Machine, Operand, Program, Register, RegisterFile};
+
+/// The registers `a` to `z`.
+pub type Registers = RegisterFile<i64>;
+
+/// An instruction of the tablet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Snd(Operand<i64>),
+    Set(Register, Operand<i64>),
+    Add(Register, Operand<i64>),
+    Mul(Register, Operand<i64>),
+    Mod(Register, Operand<i64>),
+    Rcv(Register),
+    Jgz(Operand<i64>, Operand<i64>),
+}
+
+/// What `snd` and `rcv` do: play and recover sounds, or send values to and
+/// receive them from another program running the same code.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tablet {
+    /// Whether the values go to the other program, rather than being played.
+    pub duet: bool,
+    /// The frequency of the last sound played.
+    pub last_sound: i64,
+    /// The values sent by the other program, not yet received.
+    pub inbox: VecDeque<i64>,
+    /// The values sent, not yet passed on to the other program.
+    pub outbox: VecDeque<i64>,
+    /// The number of values sent.
+    pub sent: u64,
+}
+
+impl vm::Instruction for Instruction {
+    type Value = i64;
+    type State = Tablet;
+
+    fn parse(line: &Line, registers: &mut Registers) -> Result<Instruction, ParseError> {
+        let name = line.text.split(' ').next().unwrap();
+        let count = match name {
+            "snd" | "rcv" => 1,
+            "set" | "add" | "mul" | "mod" | "jgz" => 2,
+            _ => return Err(line.error(name, "unknown instruction")),
+        };
+        let arguments = vm::split_arguments(line, name, count)?;
+        let operand = |i: usize| vm::operand(line, arguments[i], registers);
+        let register = |i: usize| vm::register(line, arguments[i], registers);
+        Ok(match name {
+            "snd" => Instruction::Snd(operand(0)?),
+            "rcv" => Instruction::Rcv(register(0)?),
+            "jgz" => Instruction::Jgz(operand(0)?, operand(1)?),
+            "set" => Instruction::Set(register(0)?, operand(1)?),
+            "add" => Instruction::Add(register(0)?, operand(1)?),
+            "mul" => Instruction::Mul(register(0)?, operand(1)?),
+            _ => Instruction::Mod(register(0)?, operand(1)?),
+        })
+    }
+
+    fn execute(&self, registers: &mut Registers, tablet: &mut Tablet) -> Flow {
+        match *self {
+            Instruction::Snd(x) if tablet.duet => {
+                tablet.outbox.push_back(x.get(registers));
+                tablet.sent += 1;
+            }
+            Instruction::Snd(x) => tablet.last_sound = x.get(registers),
+            Instruction::Set(x, y) => registers[x] = y.get(registers),
+            Instruction::Add(x, y) => registers[x] += y.get(registers),
+            Instruction::Mul(x, y) => registers[x] *= y.get(registers),
+            Instruction::Mod(x, y) => registers[x] %= y.get(registers),
+            Instruction::Rcv(x) if tablet.duet => match tablet.inbox.pop_front() {
+                Some(value) => registers[x] = value,
+                // Wait for the other program to send something.
+                None => return Flow::Wait,
+            },
+            Instruction::Rcv(x) => {
+                // The first sound recovered is the answer, so stop there.
+                if registers[x] != 0 {
+                    return Flow::Halt;
+                }
+            }
+            Instruction::Jgz(x, y) => {
+                if x.get(registers) > 0 {
+                    return Flow::Jump(y.get(registers));
+                }
+            }
+        }
+        Flow::Next
+    }
+}
+
+/// Parses the program, with the registers `a` to `z`.
+pub fn parse(input: &str) -> Result<Program<Instruction>, ParseError> {
+    Program::parse(input, Registers::letters('z'))
+}
+
+/// Plays sounds until the first one is recovered, returning its frequency.
+pub fn recover_sound(program: &Program<Instruction>) -> i64 {
+    recover_sound_traced(program, &mut NoTrace)
+}
+
+/// Like `recover_sound`, passing each instruction executed and each register
+/// it changed to the tracer.
+pub fn recover_sound_traced(program: &Program<Instruction>, tracer: &mut dyn Tracer) -> i64 {
+    let mut machine = program.machine(Tablet::default());
+    machine.run_traced(tracer);
+    machine.state.last_sound
+}
+
+/// Runs two copies of the program against each other until both are waiting
+/// or done, returning how many values program 1 sent.
+pub fn count_sends(program: &Program<Instruction>) -> u64 {
+    count_sends_traced(program, &mut NoTrace)
+}
+
+/// Like `count_sends`, passing each instruction executed and each register it
+/// changed to the tracer, with the id of the program that executed it.
+pub fn count_sends_traced(program: &Program<Instruction>, tracer: &mut dyn Tracer) -> u64 {
+    let p = program.registers.find("p").unwrap();
+    let mut machines = [0, 1].map(|id| {
+        let mut machine = program.machine(Tablet {
+            duet: true,
+            ..Tablet::default()
+        });
+        machine.registers[p] = id;
+        machine
+    });
+    loop {
+        // Run each program in turn, passing on what it sent to the other one.
+        for id in 0..2 {
+            if tracer.enabled() {
+                machines[id]
+                    .run_traced(&mut |event: Event| tracer.event(event.with("program", id)));
+            } else {
+                machines[id].run();
+            }
+            let sent = std::mem::take(&mut machines[id].state.outbox);
+            machines[1 - id].state.inbox.extend(sent);
+        }
+
+        // Values left for a program that ended are never received, so if
+        // neither program can go on, they're done.
+        let stuck =
+            |machine: &Machine<Instruction>| machine.is_halted() || machine.state.inbox.is_empty();
+        if machines.iter().all(stuck) {
+            break;
+        }
+    }
+    machines[1].state.sent
+}
+
+/// Day 18: Duet.
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = Program<Instruction>;
+
+    fn parse(input: &str) -> Result<Program<Instruction>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Program<Instruction>) -> Answer {
+        recover_sound(input).into()
+    }
+
+    fn part2(input: &Program<Instruction>) -> Answer {
+        count_sends(input).into()
+    }
+}
+
+impl Generate for Day18 {
+    const SIZE: usize = 127;
+
+    /// The usual duet: both programs sending `size` pseudo random values
+    /// back and forth, sorting them, from a random seed.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(2);
+        format!(
+            "set i 31
+set a 1
+mul p 17
+jgz p p
+mul a 2
+add i -1
+jgz i -2
+add a -1
+set i {}
+set p {}
+mul p 8505
+mod p a
+mul p 129749
+add p 12345
+mod p a
+set b p
+mod b 10000
+snd b
+add i -1
+jgz i -9
+jgz a 3
+rcv b
+jgz b -1
+set f 0
+set i {}
+rcv a
+rcv b
+set p a
+mul p -1
+add p b
+jgz p 4
+snd a
+set a b
+jgz 1 3
+snd b
+set f 1
+add i -1
+jgz i -11
+snd a
+jgz f -16
+jgz a -19",
+            size,
+            rng.range(1..=999),
+            size - 1
+        )
+    }
+}
+
+impl Trace for Day18 {
+    fn trace(input: &Program<Instruction>, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        match part {
+            1 => recover_sound_traced(input, tracer).into(),
+            _ => count_sends_traced(input, tracer).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = "set a 1
+add a 2
+mul a a
+mod a 5
+snd a
+set a 0
+rcv a
+jgz a -1
+set a 1
+jgz a -2";
+
+    #[test]
+    fn test_examples1() {
+        assert_eq!(recover_sound(&parse(TEST_INPUT).unwrap()), 4);
+    }
+
+    #[test]
+    fn test_result1() {
+        assert_eq!(recover_sound(&parse(&get_input()).unwrap()), 3188);
+    }
+
+    const TEST_INPUT2: &str = "snd 1
+snd 2
+snd p
+rcv a
+rcv b
+rcv c
+rcv d";
+
+    #[test]
+    fn test_examples2() {
+        let result = count_sends(&parse(TEST_INPUT2).unwrap());
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let program = parse(TEST_INPUT2).unwrap();
+        assert_eq!(Day18::trace(&program, 2, &mut events), Answer::Number(3));
+        // Program 1 receives what program 0 sent. The last value is its id, 0,
+        // which doesn't change `c`.
+        let received = events
+            .iter()
+            .filter(|event| event.name == "set" && event.get("program") == Some(&1.into()))
+            .map(|event| {
+                (
+                    event.get("register").unwrap().clone(),
+                    event.get("to").unwrap().clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(received, [("a".into(), 1.into()), ("b".into(), 2.into())]);
+    }
+
+    #[test]
+    fn test_ends_with_values_queued() {
+        assert_eq!(count_sends(&parse("snd p\nsnd p").unwrap()), 2);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day18::parse("set a 1\njmp a -1").unwrap_err(),
+            ParseError::new(2, 1, "jmp", "unknown instruction")
+        );
+        assert_eq!(
+            Day18::parse("set 1 a").unwrap_err(),
+            ParseError::new(1, 5, "1", "expected a register")
+        );
+        // An empty value used to panic when the instruction was run.
+        assert_eq!(
+            Day18::parse("jgz  1").unwrap_err(),
+            ParseError::new(1, 5, "", "expected a register or a number")
+        );
+        assert_eq!(
+            Day18::parse("snd").unwrap_err(),
+            ParseError::new(1, 1, "snd", "expected one argument")
+        );
+    }
+
+    #[test]
+    fn test_result2() {
+        let result = count_sends(&parse(&get_input()).unwrap());
+        assert_eq!(result, 7112);
+    }
+
+    // Straight-line code with a `snd` or a `rcv` per instruction.
+    fn duet_strategy() -> impl Strategy<Value = Vec<bool>> {
+        prop::collection::vec(any::<bool>(), 0..30)
+    }
+
+    #[test]
+    fn test_generate() {
+        let program = Day18::parse(&generate::<Day18>(18, 20)).unwrap();
+        assert_eq!(program.instructions.len(), 41);
+        // Program 1 sends every value once, and once more per round of
+        // sorting.
+        assert_eq!(count_sends(&program) % 20, 0);
+        assert!(recover_sound(&program) < 10000);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_sends_match_naive(sends in duet_strategy()) {
+            let program = sends
+                .iter()
+                .map(|&send| if send { "snd p" } else { "rcv a" })
+                .collect::<Vec<_>>()
+                .join("\n");
+            // Both programs run the same code, so they block at the first
+            // `rcv` that comes before enough `snd`s.
+            let (mut sent, mut received) = (0, 0);
+            for &send in &sends {
+                if send {
+                    sent += 1;
+                } else if received == sent {
+                    break;
+                } else {
+                    received += 1;
+                }
+            }
+            prop_assert_eq!(count_sends(&parse(&program).unwrap()), sent);
+        }
+
+        #[test]
+        fn prop_sound_matches_naive(
+            start in -100i64..100,
+            ops in prop::collection::vec((0usize..3, 1i64..5), 0..10),
+        ) {
+            let mut program = vec![format!("set a {}", start), "set b 7".to_owned()];
+            let mut expected = start;
+            for &(op, value) in &ops {
+                match op {
+                    0 => {
+                        program.push("add a b".to_owned());
+                        expected += 7;
+                    }
+                    1 => {
+                        program.push(format!("mul a {}", value));
+                        expected *= value;
+                    }
+                    _ => {
+                        program.push(format!("mod a {}", value));
+                        expected %= value;
+                    }
+                }
+            }
+            program.push("snd a".to_owned());
+            program.push("rcv a".to_owned());
+            let program = parse(&program.join("\n")).unwrap();
+            prop_assert_eq!(Day18::part1(&program), Answer::Number(expected));
+        }
+    }
+}
@@ -0,0 +1,322 @@
+//! Day 19: A Series of Tubes.
+
+use aoc_common::parse::lines;
+use aoc_common::trace::NoTrace;
+use aoc_common::visualize::{faint, highlight};
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer, Visualize};
+use grid::{render_window, DenseGrid, Direction, Point};
+use std::iter;
+
+/// The routing diagram, padded with spaces to a rectangle.
+pub type Maze = DenseGrid<char>;
+
+/// Parses the routing diagram, which must start with a `|` on the first line.
+pub fn parse(input: &str) -> Result<Maze, ParseError> {
+    let first = lines(input)
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "", "missing diagram"))?;
+    if !first.text.contains('|') {
+        return Err(first.invalid("expected a `|` to start from"));
+    }
+    DenseGrid::parse(input, ' ', Ok)
+}
+
+/// The cells of the path from the `|` on the top row, in the order the
+/// packet passes them.
+pub fn path(maze: &Maze) -> impl Iterator<Item = Point> + '_ {
+    let open = move |point: Point| maze.get(point).is_some_and(|&c| c != ' ');
+    let x = maze.row(0).iter().position(|&c| c == '|').unwrap();
+    // We always start by going down.
+    let mut dir = Direction::Down;
+    iter::successors(Some(Point::new(x as i32, 0)), move |&point| {
+        // If we're at a crossroads, turn to whichever side the path goes on.
+        if maze[point] == '+' {
+            dir = [dir.turn_left(), dir.turn_right()]
+                .into_iter()
+                .find(|&side| open(point.step(side)))
+                .unwrap_or(dir);
+        }
+        // Move in the direction we're facing, until we're off the track.
+        Some(point.step(dir)).filter(|&next| open(next))
+    })
+}
+
+/// Follows the path from the top, returning the letters seen along the way and
+/// the number of steps taken.
+pub fn traverse(maze: &Maze) -> (String, usize) {
+    traverse_traced(maze, &mut NoTrace)
+}
+
+/// Like `traverse`, passing each letter seen and each crossroads passed to the
+/// tracer as a `letter` or a `turn` event, with the step and the position.
+pub fn traverse_traced(maze: &Maze, tracer: &mut dyn Tracer) -> (String, usize) {
+    let mut result = String::new();
+    let mut steps = 0;
+    for point in path(maze) {
+        steps += 1;
+        let name = match maze[point] {
+            // If we hit a character, add it to the result.
+            c if c.is_alphabetic() => {
+                result.push(c);
+                "letter"
+            }
+            '+' => "turn",
+            _ => continue,
+        };
+        if tracer.enabled() {
+            tracer.event(
+                Event::new(name)
+                    .with("step", steps)
+                    .with("x", point.x)
+                    .with("y", point.y)
+                    .with("cell", maze[point].to_string()),
+            );
+        }
+    }
+    (result, steps)
+}
+
+/// Day 19: A Series of Tubes.
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = Maze;
+
+    fn parse(input: &str) -> Result<Maze, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Maze) -> Answer {
+        traverse(input).0.into()
+    }
+
+    fn part2(input: &Maze) -> Answer {
+        traverse(input).1.into()
+    }
+}
+
+impl Visualize for Day19 {
+    /// Follows the packet along the path, a step per frame.
+    fn frames(input: &Maze) -> Box<dyn Iterator<Item = String> + '_> {
+        let mut seen = String::new();
+        Box::new(path(input).enumerate().map(move |(step, point)| {
+            let c = input[point];
+            if c.is_alphabetic() {
+                seen.push(c);
+            }
+            let window = render_window(point, 61, 21, |p| match input.get(p) {
+                _ if p == point => highlight(c),
+                Some(&c) if c.is_alphabetic() => c.to_string(),
+                Some(&c) => faint(c),
+                None => " ".to_owned(),
+            });
+            format!("Step {}, letters seen: {}\n{}", step + 1, seen, window)
+        }))
+    }
+}
+
+impl Generate for Day19 {
+    const SIZE: usize = 17628;
+
+    /// A diagram with a path of about `size` steps, winding down across 40
+    /// columns, with a few letters along the way.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        const COLUMNS: i64 = 40;
+        // The path goes through nodes two cells apart, so it never touches
+        // itself: down one or more rows, then across the row to another
+        // column, and so on.
+        let mut nodes = vec![(rng.range(0..=COLUMNS - 1), 0)];
+        let mut down = true;
+        while 2 * nodes.len() <= size {
+            let (x, y) = *nodes.last().unwrap();
+            if down {
+                nodes.extend((1..=rng.range(1..=3)).map(|dy| (x, y + dy)));
+            } else {
+                let column = (x + rng.range(1..=COLUMNS - 1)) % COLUMNS;
+                let dx = if column > x { 1 } else { -1 };
+                nodes.extend((1..=(column - x).abs()).map(|i| (x + i * dx, y)));
+            }
+            down = !down;
+        }
+
+        let height = 2 * nodes.last().unwrap().1 as usize + 1;
+        let mut rows = vec![vec![' '; 2 * COLUMNS as usize - 1]; height];
+        let last = nodes.len() - 1;
+        for (i, &(x, y)) in nodes.iter().enumerate() {
+            let vertical = i == 0 || nodes[i - 1].0 == x;
+            let turns = i > 0 && i < last && (nodes[i + 1].0 == x) != vertical;
+            rows[2 * y as usize][2 * x as usize] = if i == 0 {
+                '|'
+            } else if turns {
+                '+'
+            } else if i == last || rng.one_in(size / 20 + 1) {
+                (b'A' + rng.below(26) as u8) as char
+            } else if vertical {
+                '|'
+            } else {
+                '-'
+            };
+            // The cell between this node and the next.
+            if let Some(&(next_x, next_y)) = nodes.get(i + 1) {
+                rows[(y + next_y) as usize][(x + next_x) as usize] =
+                    if next_x == x { '|' } else { '-' };
+            }
+        }
+        rows.iter()
+            .map(|row| row.iter().collect::<String>().trim_end().to_owned())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Trace for Day19 {
+    fn trace(input: &Maze, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        let (letters, steps) = traverse_traced(input, tracer);
+        match part {
+            1 => letters.into(),
+            _ => steps.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use aoc_common::visualize::strip_styles;
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = "     |
+     |  +--+
+     A  |  C
+ F---|----E|--+
+     |  |  |  D
+     +B-+  +--+ ";
+
+    #[test]
+    fn test_examples1() {
+        let maze = parse(TEST_INPUT).unwrap();
+        assert_eq!(traverse(&maze).0, "ABCDEF");
+    }
+
+    #[test]
+    fn test_result() {
+        let maze = parse(&get_input()).unwrap();
+        assert_eq!(traverse(&maze).0, "GEPYAWTMLK");
+    }
+
+    #[test]
+    fn test_examples2() {
+        let maze = parse(TEST_INPUT).unwrap();
+        assert_eq!(traverse(&maze).1, 38);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let maze = parse(TEST_INPUT).unwrap();
+        assert_eq!(Day19::trace(&maze, 2, &mut events), Answer::Number(38));
+        let event = |name: &'static str, step: usize, x: i32, y: i32, cell: &str| {
+            Event::new(name)
+                .with("step", step)
+                .with("x", x)
+                .with("y", y)
+                .with("cell", cell)
+        };
+        assert_eq!(
+            events[..2],
+            [event("letter", 3, 5, 2, "A"), event("turn", 6, 5, 5, "+")]
+        );
+        assert_eq!(events.len(), 6 + 7);
+    }
+
+    #[test]
+    fn test_frames() {
+        let maze = parse(TEST_INPUT).unwrap();
+        let frames = Day19::frames(&maze)
+            .map(|frame| strip_styles(&frame))
+            .collect::<Vec<_>>();
+        assert_eq!(frames.len(), 38);
+        assert!(frames[2].starts_with("Step 3, letters seen: A\n"));
+        assert!(frames[37].starts_with("Step 38, letters seen: ABCDEF\n"));
+        // The packet is in the middle of the window, on the `F`.
+        let rows = frames[37].lines().skip(1).collect::<Vec<_>>();
+        assert_eq!(&rows[10][27..35], "   F---|");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        // A diagram without a start used to panic when traversed.
+        assert_eq!(
+            parse("  +-A\n  |").unwrap_err(),
+            ParseError::new(1, 1, "  +-A", "expected a `|` to start from")
+        );
+        assert_eq!(
+            parse("").unwrap_err(),
+            ParseError::new(1, 1, "", "missing diagram")
+        );
+    }
+
+    // A path going down and right in turns from `start` on the top row, with
+    // a letter on each of the cells picked by `letters` that isn't a corner.
+    // Returns the maze along with the letters and the number of cells.
+    fn staircase(start: usize, segments: &[usize], letters: &[bool]) -> (Maze, String, usize) {
+        let mut cells = vec![(start, 0, '|')];
+        let (mut x, mut y) = (start, 0);
+        for (i, &length) in segments.iter().enumerate() {
+            for step in 1..=length {
+                let down = i % 2 == 0;
+                if down {
+                    y += 1;
+                } else {
+                    x += 1;
+                }
+                let c = match () {
+                    _ if step == length && i + 1 < segments.len() => '+',
+                    _ if letters[cells.len() % letters.len()] => {
+                        (b'A' + (cells.len() % 26) as u8) as char
+                    }
+                    _ if down => '|',
+                    _ => '-',
+                };
+                cells.push((x, y, c));
+            }
+        }
+        let mut maze = Maze::new(x + 2, y + 2, ' ');
+        for &(x, y, c) in &cells {
+            maze[Point::new(x as i32, y as i32)] = c;
+        }
+        let seen = cells
+            .iter()
+            .map(|e| e.2)
+            .filter(|c| c.is_alphabetic())
+            .collect();
+        (maze, seen, cells.len())
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let maze = parse(&generate::<Day19>(seed, 1000)).unwrap();
+            let (letters, steps) = traverse(&maze);
+            // The path ends on a letter, having taken an odd number of steps
+            // through the nodes two cells apart.
+            assert!(!letters.is_empty());
+            assert!((1001..1100).contains(&steps) && steps % 2 == 1, "{}", steps);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_follows_staircase(
+            start in 0usize..5,
+            segments in prop::collection::vec(2usize..6, 1..10),
+            letters in prop::collection::vec(prop::bool::weighted(0.2), 1..20),
+        ) {
+            let (maze, seen, cells) = staircase(start, &segments, &letters);
+            prop_assert_eq!(traverse(&maze), (seen, cells));
+        }
+    }
+}
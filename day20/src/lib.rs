@@ -0,0 +1,583 @@
+//! Day 20: Particle Swarm.
+
+use aoc_common::parse::{lines, Line};
+use aoc_common::trace::NoTrace;
+use aoc_common::visualize::highlight;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer, Visualize};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+/// A position, velocity or acceleration in 3D space.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Position {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Position {
+    fn distance(&self) -> i64 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+}
+
+/// A particle along with its position, velocity and acceleration.
+#[derive(Debug, Clone, Eq)]
+pub struct Particle {
+    position: Box<Position>,
+    velocity: Box<Position>,
+    acceleration: Box<Position>,
+    absolute_vector: i64,
+    current_distance: i64,
+    moving_closer: bool,
+    id: usize,
+}
+
+impl Particle {
+    fn tick(&mut self) {
+        // Add the acceleration to the velocity.
+        self.velocity.x += self.acceleration.x;
+        self.velocity.y += self.acceleration.y;
+        self.velocity.z += self.acceleration.z;
+
+        // Change position based on the velocity.
+        self.position.x += self.velocity.x;
+        self.position.y += self.velocity.y;
+        self.position.z += self.velocity.z;
+
+        self.absolute_vector = self.velocity.distance();
+
+        let prev_distance = self.current_distance;
+        self.current_distance = self.position.distance();
+        self.moving_closer = self.current_distance <= prev_distance;
+    }
+}
+
+impl Ord for Particle {
+    fn cmp(&self, other: &Particle) -> Ordering {
+        self.current_distance.cmp(&other.current_distance)
+    }
+}
+
+impl PartialOrd for Particle {
+    fn partial_cmp(&self, other: &Particle) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Particle {
+    fn eq(&self, other: &Particle) -> bool {
+        self.id == other.id
+    }
+}
+
+// Parses a single vector, like `p=<-317,1413,1507>`.
+fn parse_position(line: &Line, input: &str) -> Result<Position, ParseError> {
+    let coords = input
+        .get(1..)
+        .and_then(|e| e.strip_prefix("=<"))
+        .and_then(|e| e.strip_suffix('>'))
+        .ok_or_else(|| line.error(input, "expected a vector like `p=<1,2,3>`"))?
+        .split(',')
+        .map(|e| line.parse(e.trim(), "coordinate"))
+        .collect::<Result<Vec<_>, _>>()?;
+    if coords.len() != 3 {
+        return Err(line.error(input, "expected 3 coordinates"));
+    }
+    Ok(Position {
+        x: coords[0],
+        y: coords[1],
+        z: coords[2],
+    })
+}
+
+/// Parses the particles, one per line like
+/// `p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>`.
+pub fn parse(input: &str) -> Result<Vec<Particle>, ParseError> {
+    let mut result = vec![];
+    for (id, line) in lines(input).filter(|e| !e.text.is_empty()).enumerate() {
+        let parts = line
+            .text
+            .split(", ")
+            .map(|e| parse_position(&line, e))
+            .collect::<Result<Vec<_>, _>>()?;
+        if parts.len() != 3 {
+            return Err(line.invalid("expected a position, velocity and acceleration"));
+        }
+        let p = Particle {
+            position: Box::new(parts[0]),
+            velocity: Box::new(parts[1]),
+            acceleration: Box::new(parts[2]),
+            absolute_vector: i64::MAX,
+            current_distance: i64::MAX,
+            id,
+            moving_closer: true,
+        };
+        result.push(p);
+    }
+    if result.is_empty() {
+        return Err(ParseError::new(1, 1, "", "missing particles"));
+    }
+    Ok(result)
+}
+
+/// The ID of the particle that stays closest to the origin in the long run.
+pub fn closest_particle(particles: &mut [Particle]) -> usize {
+    closest_particle_traced(particles, &mut NoTrace)
+}
+
+/// Like `closest_particle`, passing each tick to the tracer as a `tick` event
+/// with the particle closest to the origin and its distance.
+pub fn closest_particle_traced(particles: &mut [Particle], tracer: &mut dyn Tracer) -> usize {
+    let mut tick = 0;
+    loop {
+        // Check whether all the particles are on their way away from the
+        // center and whether the first element has the lowest velocity.
+        let first_id = particles[0].id;
+        if particles.iter().find(|p| p.moving_closer).is_none()
+            && first_id
+                == particles
+                    .iter()
+                    .min_by_key(|p| p.absolute_vector)
+                    .unwrap()
+                    .id
+            && first_id
+                == particles
+                    .iter()
+                    .min_by_key(|p| p.acceleration.distance())
+                    .unwrap()
+                    .id
+        {
+            break;
+        }
+
+        // Iterate everything
+        for particle in particles.iter_mut() {
+            particle.tick();
+        }
+
+        // Sort the particles based on distance.
+        particles.sort();
+        tick += 1;
+        if tracer.enabled() {
+            tracer.event(
+                Event::new("tick")
+                    .with("tick", tick)
+                    .with("closest", particles[0].id)
+                    .with("distance", particles[0].current_distance),
+            );
+        }
+    }
+
+    // Just return the first one, being closest and with the lowest velocity.
+    particles
+        .iter()
+        .min_by_key(|p| p.current_distance)
+        .unwrap()
+        .id
+}
+
+// Removes the particles sharing a position, returning the positions of the
+// collisions.
+fn remove_collisions(particles: &mut Vec<Particle>) -> HashSet<Position> {
+    // Check for collisions.
+    let collision_positions = {
+        let mut result = HashSet::new();
+        for (i, particle) in particles.iter().enumerate() {
+            for other in &particles[i + 1..] {
+                if particle.position == other.position {
+                    // Mark the position.
+                    result.insert(*particle.position);
+                }
+            }
+        }
+        result
+    };
+
+    // Remove all particles with positions in the hashset.
+    if !collision_positions.is_empty() {
+        for i in (0..particles.len()).rev() {
+            if collision_positions.contains(&particles[i].position) {
+                particles.remove(i);
+            }
+        }
+    }
+    collision_positions
+}
+
+/// The number of particles left once all collisions are resolved.
+pub fn remaining_particles(particles: &mut Vec<Particle>) -> usize {
+    remaining_particles_traced(particles, &mut NoTrace)
+}
+
+/// Like `remaining_particles`, passing each tick with collisions to the tracer
+/// as a `collide` event, with the number of positions collided at and the
+/// particles left.
+pub fn remaining_particles_traced(particles: &mut Vec<Particle>, tracer: &mut dyn Tracer) -> usize {
+    for tick in 0..500 {
+        let collisions = remove_collisions(particles);
+        if !collisions.is_empty() && tracer.enabled() {
+            tracer.event(
+                Event::new("collide")
+                    .with("tick", tick)
+                    .with("positions", collisions.len())
+                    .with("left", particles.len()),
+            );
+        }
+
+        // Iterate everything
+        for particle in particles.iter_mut() {
+            particle.tick();
+        }
+    }
+
+    // Return the particle count.
+    particles.len()
+}
+
+// Projects the particles and collisions onto the x and y axes, scaled to fit
+// a window of `WIDTH` by `HEIGHT` characters.
+fn render_swarm(particles: &[Particle], collisions: &HashSet<Position>) -> String {
+    const WIDTH: i64 = 60;
+    const HEIGHT: i64 = 20;
+    let positions = particles
+        .iter()
+        .map(|p| *p.position)
+        .chain(collisions.iter().copied())
+        .collect::<Vec<_>>();
+    let min_x = positions.iter().map(|p| p.x).min().unwrap_or(0);
+    let max_x = positions.iter().map(|p| p.x).max().unwrap_or(0);
+    let min_y = positions.iter().map(|p| p.y).min().unwrap_or(0);
+    let max_y = positions.iter().map(|p| p.y).max().unwrap_or(0);
+    let cell = |p: &Position| {
+        let column = (p.x - min_x) as i128 * (WIDTH - 1) as i128 / (max_x - min_x).max(1) as i128;
+        let row = (p.y - min_y) as i128 * (HEIGHT - 1) as i128 / (max_y - min_y).max(1) as i128;
+        (row as usize, column as usize)
+    };
+    let mut rows = vec![vec![" ".to_owned(); WIDTH as usize]; HEIGHT as usize];
+    for position in &positions[..particles.len()] {
+        let (row, column) = cell(position);
+        rows[row][column] = "*".to_owned();
+    }
+    for position in collisions {
+        let (row, column) = cell(position);
+        rows[row][column] = highlight('X');
+    }
+    let mut result = String::new();
+    for row in rows {
+        result.extend(row);
+        result.push('\n');
+    }
+    result
+}
+
+/// Day 20: Particle Swarm.
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = Vec<Particle>;
+
+    fn parse(input: &str) -> Result<Vec<Particle>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Vec<Particle>) -> Answer {
+        closest_particle(&mut input.clone()).into()
+    }
+
+    fn part2(input: &Vec<Particle>) -> Answer {
+        remaining_particles(&mut input.clone()).into()
+    }
+}
+
+impl Visualize for Day20 {
+    /// Resolves the collisions tick by tick, looking down on the swarm from
+    /// above, with the collisions of each tick marked.
+    fn frames(input: &Vec<Particle>) -> Box<dyn Iterator<Item = String> + '_> {
+        let mut particles = input.clone();
+        Box::new((0..500).map(move |tick| {
+            let collisions = remove_collisions(&mut particles);
+            let frame = format!(
+                "Tick {}, {} particles left\n{}",
+                tick,
+                particles.len(),
+                render_swarm(&particles, &collisions)
+            );
+            particles.iter_mut().for_each(Particle::tick);
+            frame
+        }))
+    }
+}
+
+impl Generate for Day20 {
+    const SIZE: usize = 1000;
+
+    /// `size` particles, with a single one accelerating the least and about
+    /// half of them colliding in groups of 2 up to 4.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let vector = |rng: &mut Rng, max: i64| Position {
+            x: rng.range(-max..=max),
+            y: rng.range(-max..=max),
+            z: rng.range(-max..=max),
+        };
+        let mut particles = vec![];
+        while particles.len() < size {
+            let group = if rng.one_in(4) { rng.range(2..=4) } else { 1 };
+            let (meeting, tick) = (vector(rng, 3000), rng.range(1..=40));
+            for _ in 0..group.min((size - particles.len()) as i64) {
+                // Every particle but the one staying closest accelerates by
+                // 2 or more.
+                let a = loop {
+                    let a = vector(rng, 15);
+                    if a.distance() >= 2 {
+                        break a;
+                    }
+                };
+                let v = vector(rng, 150);
+                // Colliding particles start from where they meet, moving
+                // back the ticks it takes.
+                let p = if group > 1 {
+                    let back = |p: i64, v: i64, a: i64| p - tick * v - a * tick * (tick + 1) / 2;
+                    Position {
+                        x: back(meeting.x, v.x, a.x),
+                        y: back(meeting.y, v.y, a.y),
+                        z: back(meeting.z, v.z, a.z),
+                    }
+                } else {
+                    vector(rng, 3000)
+                };
+                particles.push([p, v, a]);
+            }
+        }
+        if !particles.is_empty() {
+            let closest = rng.below(particles.len());
+            let mut a = [0; 3];
+            a[rng.below(3)] = if rng.one_in(2) { 1 } else { -1 };
+            particles[closest][2] = Position {
+                x: a[0],
+                y: a[1],
+                z: a[2],
+            };
+        }
+        particles
+            .iter()
+            .map(|[p, v, a]| {
+                format!(
+                    "p=<{},{},{}>, v=<{},{},{}>, a=<{},{},{}>",
+                    p.x, p.y, p.z, v.x, v.y, v.z, a.x, a.y, a.z
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Trace for Day20 {
+    fn trace(input: &Vec<Particle>, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        match part {
+            1 => closest_particle_traced(&mut input.clone(), tracer).into(),
+            _ => remaining_particles_traced(&mut input.clone(), tracer).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use aoc_common::visualize::strip_styles;
+    use proptest::prelude::*;
+    use std::collections::HashMap;
+
+    const TEST_INPUT: &str = "p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>
+p=<4,0,0>, v=<0,0,0>, a=<-2,0,0>";
+
+    #[test]
+    fn test_examples1() {
+        let mut particles = parse(TEST_INPUT).unwrap();
+        assert_eq!(closest_particle(&mut particles), 0);
+    }
+
+    #[test]
+    fn test_result1() {
+        let mut particles = parse(&get_input()).unwrap();
+        assert_eq!(closest_particle(&mut particles), 243);
+    }
+
+    #[test]
+    fn test_result2() {
+        let mut particles = parse(&get_input()).unwrap();
+        assert_eq!(remaining_particles(&mut particles), 648);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let particles = parse(TEST_INPUT).unwrap();
+        assert_eq!(Day20::trace(&particles, 1, &mut events), Answer::Number(0));
+        assert_eq!(
+            events[0],
+            Event::new("tick")
+                .with("tick", 1)
+                .with("closest", 1)
+                .with("distance", 2)
+        );
+        events.clear();
+        let particles = parse(
+            "p=<-6,0,0>, v=<3,0,0>, a=<0,0,0>
+p=<-4,0,0>, v=<2,0,0>, a=<0,0,0>
+p=<-2,0,0>, v=<1,0,0>, a=<0,0,0>
+p=<3,0,0>, v=<-1,0,0>, a=<0,0,0>",
+        )
+        .unwrap();
+        assert_eq!(Day20::trace(&particles, 2, &mut events), Answer::Number(1));
+        assert_eq!(
+            events,
+            [Event::new("collide")
+                .with("tick", 2)
+                .with("positions", 1)
+                .with("left", 1)]
+        );
+    }
+
+    #[test]
+    fn test_frames() {
+        let particles = parse(
+            "p=<-6,0,0>, v=<3,0,0>, a=<0,0,0>
+p=<-4,0,0>, v=<2,0,0>, a=<0,0,0>
+p=<-2,0,0>, v=<1,0,0>, a=<0,0,0>
+p=<3,0,0>, v=<-1,0,0>, a=<0,0,0>",
+        )
+        .unwrap();
+        let frames = Day20::frames(&particles)
+            .map(|frame| strip_styles(&frame))
+            .collect::<Vec<_>>();
+        assert_eq!(frames.len(), 500);
+        assert!(frames[0].starts_with("Tick 0, 4 particles left\n"));
+        assert!(frames[2].starts_with("Tick 2, 1 particles left\n"));
+        // The three particles collide on the left, leaving the last one on
+        // the right.
+        let rows = frames[2].lines().skip(1).collect::<Vec<_>>();
+        assert_eq!(rows.len(), 20);
+        assert_eq!(rows[0].trim_end(), format!("X{}*", " ".repeat(58)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("p=<3,0,0>, v=<2,x,0>, a=<-1,0,0>").unwrap_err(),
+            ParseError::new(1, 17, "x", "invalid coordinate")
+        );
+        assert_eq!(
+            parse("p=<3,0,0>, v=<2,0>, a=<-1,0,0>").unwrap_err(),
+            ParseError::new(1, 12, "v=<2,0>", "expected 3 coordinates")
+        );
+        // An empty swarm used to panic.
+        assert_eq!(
+            parse("\n").unwrap_err(),
+            ParseError::new(1, 1, "", "missing particles")
+        );
+    }
+
+    type Vector = (i64, i64, i64);
+
+    fn vector() -> impl Strategy<Value = Vector> {
+        small_vector(10)
+    }
+
+    fn small_vector(max: i64) -> impl Strategy<Value = Vector> {
+        (-max..=max, -max..=max, -max..=max)
+    }
+
+    fn particles(particles: &[(Vector, Vector, Vector)]) -> String {
+        particles
+            .iter()
+            .map(|&(p, v, a)| {
+                format!(
+                    "p=<{},{},{}>, v=<{},{},{}>, a=<{},{},{}>",
+                    p.0, p.1, p.2, v.0, v.1, v.2, a.0, a.1, a.2
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    // Removes the particles sharing a position after each tick, by counting
+    // the particles on each position.
+    fn naive_remaining(mut particles: Vec<Particle>, ticks: usize) -> usize {
+        for _ in 0..ticks {
+            let mut counts = HashMap::new();
+            for particle in &particles {
+                *counts.entry(*particle.position).or_insert(0) += 1;
+            }
+            particles.retain(|particle| counts[&*particle.position] == 1);
+            particles.iter_mut().for_each(Particle::tick);
+        }
+        particles.len()
+    }
+
+    #[test]
+    fn test_generate() {
+        let mut particles = parse(&generate::<Day20>(20, 200)).unwrap();
+        assert_eq!(particles.len(), 200);
+        let slowest = particles
+            .iter()
+            .position(|p| p.acceleration.distance() == 1)
+            .unwrap();
+        assert_eq!(closest_particle(&mut particles.clone()), slowest);
+        assert!(remaining_particles(&mut particles) < 180);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_closest_has_least_acceleration(
+            swarm in prop::collection::vec((vector(), vector(), small_vector(3)), 1..10)
+                .prop_filter("a single particle must accelerate the least", |swarm| {
+                    let mut magnitudes = swarm
+                        .iter()
+                        .map(|(_, _, a)| a.0.abs() + a.1.abs() + a.2.abs())
+                        .collect::<Vec<_>>();
+                    magnitudes.sort();
+                    magnitudes[0] > 0 && magnitudes.get(1) != Some(&magnitudes[0])
+                })
+        ) {
+            let expected = (0..swarm.len())
+                .min_by_key(|&i| {
+                    let a = swarm[i].2;
+                    a.0.abs() + a.1.abs() + a.2.abs()
+                })
+                .unwrap();
+            let mut parsed = parse(&particles(&swarm)).unwrap();
+            prop_assert_eq!(closest_particle(&mut parsed), expected);
+        }
+
+        #[test]
+        fn prop_pairs_collide(
+            still in prop::collection::hash_set(vector(), 0..10),
+            pairs in prop::collection::vec(1i64..50, 0..5),
+        ) {
+            // Still particles never collide, and each pair of particles moving
+            // towards each other on its own row collides in the middle.
+            let mut swarm = still.iter().map(|&p| (p, (0, 0, 0), (0, 0, 0))).collect::<Vec<_>>();
+            for (i, &distance) in pairs.iter().enumerate() {
+                let y = 100 + i as i64;
+                swarm.push(((-distance, y, 0), (1, 0, 0), (0, 0, 0)));
+                swarm.push(((distance, y, 0), (-1, 0, 0), (0, 0, 0)));
+            }
+            prop_assume!(!swarm.is_empty());
+            let mut parsed = parse(&particles(&swarm)).unwrap();
+            prop_assert_eq!(remaining_particles(&mut parsed), still.len());
+        }
+
+        #[test]
+        fn prop_remaining_matches_naive(
+            swarm in prop::collection::vec((vector(), small_vector(2), small_vector(1)), 1..20)
+        ) {
+            let mut parsed = parse(&particles(&swarm)).unwrap();
+            let expected = naive_remaining(parsed.clone(), 500);
+            prop_assert_eq!(remaining_particles(&mut parsed), expected);
+        }
+    }
+}
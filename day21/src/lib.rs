@@ -0,0 +1,403 @@
+//! Day 21: Fractal Art.
+
+use aoc_common::parse::{lines, Line};
+use aoc_common::trace::NoTrace;
+use aoc_common::{
+    check_params, param, Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer,
+};
+use std::collections::{HashMap, HashSet};
+
+/// A square grid of pixels, `#` or `.`.
+pub type Grid = Vec<Vec<u8>>;
+/// Maps every rotation and flip of a pattern to its enhanced grid and the
+/// number of pixels on in it.
+pub type Rules = HashMap<Grid, (Grid, usize)>;
+
+/// Rotate a given grid clockwise.
+pub fn rotate_clockwise(v: &[Vec<u8>]) -> Grid {
+    let len = v.len();
+    (0..len)
+        .map(|l| (0..len).map(|c| v[len - 1 - c][l]).collect())
+        .collect()
+}
+
+/// Generate all possible combinations of a pattern (flip and rotate
+/// combinations).
+pub fn generate_grid_combinations(v: &[Vec<u8>]) -> Vec<Grid> {
+    let mut result = Vec::with_capacity(8);
+    // Iterate on normal and flipped grid.
+    for mut flip_vec in [v.to_vec(), v.iter().rev().cloned().collect()] {
+        result.push(flip_vec.clone());
+        // Iterate on the 4 possible rotations.
+        for _ in 0..3 {
+            flip_vec = rotate_clockwise(&flip_vec);
+            result.push(flip_vec.clone());
+        }
+    }
+    result
+}
+
+/// Converts a string-based grid to a grid vector.
+pub fn grid_to_vec(i: &str) -> Grid {
+    i.split('/').map(|w| w.as_bytes().to_vec()).collect()
+}
+
+/// Converts a grid vector back to a string-based grid.
+pub fn vec_to_grid(v: &[Vec<u8>]) -> String {
+    let rows = v.iter().map(|row| String::from_utf8_lossy(row));
+    rows.collect::<Vec<_>>().join("/")
+}
+
+// Checks that a pattern is a square grid of `.` and `#`, 2 to 4 pixels wide.
+fn check_pattern(line: &Line, pattern: &str) -> Result<(), ParseError> {
+    if let Some((i, c)) = pattern
+        .char_indices()
+        .find(|&(_, c)| !matches!(c, '.' | '#' | '/'))
+    {
+        return Err(line.error(&pattern[i..i + c.len_utf8()], "expected `.`, `#` or `/`"));
+    }
+    let size = pattern.split('/').count();
+    if !(2..=4).contains(&size) || pattern.split('/').any(|row| row.len() != size) {
+        return Err(line.error(pattern, "expected a square pattern"));
+    }
+    Ok(())
+}
+
+/// The pattern of `size` by `size` pixels whose bits, row by row, are the
+/// pixels that are on.
+pub fn pattern(pixels: u32, size: usize) -> Grid {
+    (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| {
+                    if pixels >> (y * size + x) & 1 == 1 {
+                        b'#'
+                    } else {
+                        b'.'
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Parse the rules (and all combinations of these) into a hashmap, for fast
+/// translation. Every 2 by 2 and 3 by 3 pattern must have a rule, up to
+/// rotating and flipping it, so enhancing never gets stuck.
+pub fn parse_rules(input: &str) -> Result<Rules, ParseError> {
+    let result = parse_rule_lines(input)?;
+    for size in 2..=3 {
+        for pixels in 0..1u32 << (size * size) {
+            let pattern = pattern(pixels, size);
+            if !result.contains_key(&pattern) {
+                let text = vec_to_grid(&pattern);
+                return Err(ParseError::new(1, 1, text, "no rule for the pattern"));
+            }
+        }
+    }
+    Ok(result)
+}
+
+// Parses the rules without checking that every pattern has one, like the
+// rule book of the example which only has the rules it needs.
+fn parse_rule_lines(input: &str) -> Result<Rules, ParseError> {
+    let mut result = Rules::new();
+    for line in lines(input).filter(|e| !e.text.trim().is_empty()) {
+        let (k, v) = line.split_once(" => ")?;
+        let (k, v) = (k.trim(), v.trim());
+        check_pattern(&line, k)?;
+        check_pattern(&line, v)?;
+        if v.split('/').count() != k.split('/').count() + 1 {
+            return Err(line.error(v, "expected the output to be one pixel wider"));
+        }
+        let sharps = v.chars().filter(|&c| c == '#').count();
+        let vv = (grid_to_vec(v), sharps);
+        for kk in generate_grid_combinations(&grid_to_vec(k)) {
+            result.insert(kk, vv.clone());
+        }
+    }
+    if result.is_empty() {
+        return Err(ParseError::new(1, 1, "", "missing rules"));
+    }
+    Ok(result)
+}
+
+/// The pattern the art starts from.
+pub fn initial_grid() -> Grid {
+    grid_to_vec(".#./..#/###")
+}
+
+/// The number of pixels on after enhancing the initial grid `iterations`
+/// times. Panics if a block has no rule, which `parse_rules` rules out.
+pub fn calculate_pixels(iterations: u32, rules: &Rules) -> usize {
+    calculate_pixels_traced(iterations, rules, &mut NoTrace)
+}
+
+/// Like `calculate_pixels`, passing each iteration to the tracer as an
+/// `enhance` event with the size of the grid and the pixels on.
+pub fn calculate_pixels_traced(iterations: u32, rules: &Rules, tracer: &mut dyn Tracer) -> usize {
+    let mut grid = initial_grid();
+    let mut pixels = 0;
+    for iteration in 1..=iterations {
+        pixels = 0;
+
+        // Look for evenly divisible by 2 or 3.
+        let rule = 2 + (grid.len() % 2);
+        let len_div_rule = grid.len() / rule;
+
+        // Extend the grid vertically and horizontally.
+        grid.iter_mut()
+            .for_each(|l| l.extend(vec![b' '; len_div_rule]));
+        grid.extend(vec![vec![b' '; (rule + 1) * len_div_rule]; len_div_rule]);
+
+        // Iterate on each "block" of the grid, in each direction.
+        for c in (0..len_div_rule).rev() {
+            for l in (0..len_div_rule).rev() {
+                // Match the current "block" against a rule.
+                let pattern = (l * rule..l * rule + rule)
+                    .map(|line| grid[line][c * rule..c * rule + rule].to_vec())
+                    .collect::<Vec<_>>();
+                let (ref new, s) = rules[&pattern];
+
+                // Modify the grid to match the new rule.
+                for ll in (0..rule + 1).rev() {
+                    for cc in (0..rule + 1).rev() {
+                        grid[l * (rule + 1) + ll][c * (rule + 1) + cc] = new[ll][cc];
+                    }
+                }
+
+                // Add the number of pixels added from the current block.
+                pixels += s;
+            }
+        }
+        if tracer.enabled() {
+            tracer.event(
+                Event::new("enhance")
+                    .with("iteration", iteration)
+                    .with("size", grid.len())
+                    .with("pixels", pixels),
+            );
+        }
+    }
+    pixels
+}
+
+/// Day 21: Fractal Art.
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input = Rules;
+
+    fn parse(input: &str) -> Result<Rules, ParseError> {
+        parse_rules(input)
+    }
+
+    fn part1(input: &Rules) -> Answer {
+        calculate_pixels(5, input).into()
+    }
+
+    fn part2(input: &Rules) -> Answer {
+        calculate_pixels(18, input).into()
+    }
+
+    /// Enhances the grid `iterations` times instead of 5 or 18.
+    fn solve_with(input: &Rules, part: u8, params: &[(&str, i64)]) -> Result<Answer, String> {
+        check_params(params, part, &["iterations"])?;
+        let default = if part == 1 { 5 } else { 18 };
+        let iterations = param(params, "iterations", default, 1..=18)? as u32;
+        Ok(calculate_pixels(iterations, input).into())
+    }
+}
+
+impl Generate for Day21 {
+    const SIZE: usize = 108;
+
+    /// A rule with a random output for each of the 108 patterns of 2 by 2
+    /// and 3 by 3 pixels, up to rotating and flipping them. There's no
+    /// other size of rule book.
+    fn generate(rng: &mut Rng, _size: usize) -> String {
+        let mut covered = HashSet::new();
+        let mut rules = vec![];
+        for size in 2..=3 {
+            for pixels in 0..1u32 << (size * size) {
+                let pattern = pattern(pixels, size);
+                if covered.contains(&pattern) {
+                    continue;
+                }
+                covered.extend(generate_grid_combinations(&pattern));
+                let output = (0..=size)
+                    .map(|_| (0..=size).map(|_| *rng.choose(&['#', '.'])).collect())
+                    .collect::<Vec<String>>();
+                rules.push(format!("{} => {}", vec_to_grid(&pattern), output.join("/")));
+            }
+        }
+        rng.shuffle(&mut rules);
+        rules.join("\n")
+    }
+}
+
+impl Trace for Day21 {
+    fn trace(input: &Rules, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        let iterations = if part == 1 { 5 } else { 18 };
+        calculate_pixels_traced(iterations, input, tracer).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = "../.# => ##./#../...
+.#./..#/### => #..#/..../..../#..#";
+
+    #[test]
+    fn test_result1() {
+        let rules = parse_rules(&get_input()).unwrap();
+        let result = calculate_pixels(5, &rules);
+        assert_eq!(result, 160);
+    }
+
+    #[test]
+    fn test_result2() {
+        let rules = parse_rules(&get_input()).unwrap();
+        let result = calculate_pixels(18, &rules);
+        assert_eq!(result, 2271537);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_rules("../.# => ##./#x./...").unwrap_err(),
+            ParseError::new(1, 15, "x", "expected `.`, `#` or `/`")
+        );
+        // A multibyte character used to panic.
+        assert_eq!(
+            parse_rules("é => ##./#../...").unwrap_err(),
+            ParseError::new(1, 1, "é", "expected `.`, `#` or `/`")
+        );
+        // So did rule books without a rule for the grids enhanced.
+        assert_eq!(
+            parse_rules("").unwrap_err(),
+            ParseError::new(1, 1, "", "missing rules")
+        );
+        // Or with a rule missing for any pattern, the example's included.
+        assert_eq!(
+            parse_rules(TEST_INPUT).unwrap_err(),
+            ParseError::new(1, 1, "../..", "no rule for the pattern")
+        );
+        let mut rules = generate::<Day21>(21, 108)
+            .lines()
+            .filter(|rule| !rule.starts_with("###/#.#/###"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(
+            parse_rules(&rules).unwrap_err(),
+            ParseError::new(1, 1, "###/#.#/###", "no rule for the pattern")
+        );
+        rules.push_str("\n###/#.#/### => ..../..../..../....");
+        assert!(parse_rules(&rules).is_ok());
+    }
+
+    #[test]
+    fn test_examples1() {
+        // The example only has the rules it needs, so it is checked here
+        // instead of in `examples.toml`.
+        let rules = parse_rule_lines(TEST_INPUT).unwrap();
+        assert_eq!(calculate_pixels(2, &rules), 12);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let rules = parse_rule_lines(TEST_INPUT).unwrap();
+        assert_eq!(calculate_pixels_traced(2, &rules, &mut events), 12);
+        let enhance = |iteration: u32, size: usize, pixels: usize| {
+            Event::new("enhance")
+                .with("iteration", iteration)
+                .with("size", size)
+                .with("pixels", pixels)
+        };
+        assert_eq!(events, [enhance(1, 4, 4), enhance(2, 6, 12)]);
+    }
+
+    // Rules for every 2x2 and 3x3 pattern, enhancing into the bits of the
+    // given numbers.
+    fn rules(small: &[u16], large: &[u16]) -> Rules {
+        let mut result = Rules::new();
+        for (size, outputs) in [(2, small), (3, large)] {
+            for (bits, &output) in outputs.iter().enumerate() {
+                let output = pattern(output as u32, size + 1);
+                let on = output.iter().flatten().filter(|&&c| c == b'#').count();
+                result.insert(pattern(bits as u32, size), (output, on));
+            }
+        }
+        result
+    }
+
+    // Splits the grid into blocks, and joins the enhanced blocks into a new
+    // grid.
+    fn naive_pixels(iterations: u32, rules: &Rules) -> usize {
+        let mut grid = initial_grid();
+        for _ in 0..iterations {
+            let block = if grid.len().is_multiple_of(2) { 2 } else { 3 };
+            let blocks = grid.len() / block;
+            let mut next = vec![vec![b'.'; blocks * (block + 1)]; blocks * (block + 1)];
+            for by in 0..blocks {
+                for bx in 0..blocks {
+                    let pattern = grid[by * block..(by + 1) * block]
+                        .iter()
+                        .map(|row| row[bx * block..(bx + 1) * block].to_vec())
+                        .collect::<Vec<_>>();
+                    for (y, row) in rules[&pattern].0.iter().enumerate() {
+                        for (x, &c) in row.iter().enumerate() {
+                            next[by * (block + 1) + y][bx * (block + 1) + x] = c;
+                        }
+                    }
+                }
+            }
+            grid = next;
+        }
+        grid.iter().flatten().filter(|&&c| c == b'#').count()
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day21>(21, 108);
+        assert_eq!(input.lines().count(), 108);
+        // Every pattern has a rule, so enhancing never gets stuck.
+        let rules = parse_rules(&input).unwrap();
+        assert_eq!(rules.len(), 16 + 512);
+        calculate_pixels(5, &rules);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_pixels_match_naive(
+            small in prop::collection::vec(0u16..512, 16),
+            large in prop::collection::vec(any::<u16>(), 512),
+            iterations in 1u32..7,
+        ) {
+            let rules = rules(&small, &large);
+            prop_assert_eq!(calculate_pixels(iterations, &rules), naive_pixels(iterations, &rules));
+        }
+
+        #[test]
+        fn prop_combinations(pattern in "[.#]{3}/[.#]{3}/[.#]{3}") {
+            let grid = grid_to_vec(&pattern);
+            let mut rotated = grid.clone();
+            for _ in 0..4 {
+                rotated = rotate_clockwise(&rotated);
+            }
+            prop_assert_eq!(&rotated, &grid);
+            let combinations = generate_grid_combinations(&grid);
+            prop_assert_eq!(combinations.len(), 8);
+            prop_assert!(combinations.contains(&grid));
+            let on = |grid: &Grid| grid.iter().flatten().filter(|&&c| c == b'#').count();
+            prop_assert!(combinations.iter().all(|e| on(e) == on(&grid)));
+        }
+    }
+}
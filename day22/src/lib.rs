@@ -0,0 +1,422 @@
+//! Day 22: Sporifica Virus.
+
+use aoc_common::trace::NoTrace;
+use aoc_common::visualize::{faint, highlight};
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer, Visualize};
+use grid::{render_window, Direction, Point, SparseGrid};
+
+/// The nodes that aren't clean, relative to the middle of the map. Clean
+/// nodes are left out.
+pub type Grid = SparseGrid<Flag>;
+
+/// The state of an infected node.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Flag {
+    // Clean: Means the position doesn't exist in the grid.
+    Weakened,
+    Infected,
+    Flagged,
+}
+
+/// Parses the infected (`#`) nodes of the map, centered on the middle.
+pub fn parse(input: &str) -> Result<Grid, ParseError> {
+    let grid = SparseGrid::parse(input, |c| match c {
+        '#' => Ok(Some(Flag::Infected)),
+        '.' => Ok(None),
+        _ => Err("expected `.` or `#`"),
+    })?;
+    // Determine the middle of the grid.
+    let half_width = (input.lines().next().unwrap_or("").chars().count() / 2) as i32;
+    let half_height = (input.lines().count() / 2) as i32;
+    let middle = Point::new(half_width, half_height);
+    Ok(grid
+        .into_iter()
+        .map(|(point, flag)| (point - middle, flag))
+        .collect())
+}
+
+// Handles a tick, modifies the grid, position and direction. Returns true
+// when infecting, false when cleansing.
+fn tick(grid: &mut Grid, position: &mut Point, direction: &mut Direction, part2: bool) -> bool {
+    if !part2 {
+        if !grid.contains(*position) {
+            // Clean -> Infected
+            grid.insert(*position, Flag::Infected);
+            *direction = direction.turn_left();
+            *position = position.step(*direction);
+            true
+        } else {
+            // Infected -> Clean
+            grid.remove(*position);
+            *direction = direction.turn_right();
+            *position = position.step(*direction);
+            false
+        }
+    } else {
+        if !grid.contains(*position) {
+            // Clean -> Weakened
+            *direction = direction.turn_left();
+            grid.insert(*position, Flag::Weakened);
+            *position = position.step(*direction);
+            false
+        } else {
+            // Change direction and determine the new flag for the position.
+            let new_flag_value = match grid[*position] {
+                Flag::Weakened => {
+                    // Weakened -> Infected
+                    Some(Flag::Infected)
+                }
+                Flag::Infected => {
+                    // Infected -> Flagged
+                    *direction = direction.turn_right();
+                    Some(Flag::Flagged)
+                }
+                Flag::Flagged => {
+                    // Flagged -> Clean
+                    *direction = direction.reverse();
+                    None
+                }
+            };
+            // Update the grid.
+            match new_flag_value {
+                Some(new_flag) => grid.insert(*position, new_flag),
+                None => grid.remove(*position),
+            };
+            // Move the position.
+            *position = position.step(*direction);
+            // If we ended up in Infected state, return true.
+            match new_flag_value {
+                Some(e) => e == Flag::Infected,
+                _ => false,
+            }
+        }
+    }
+}
+
+/// Runs the virus carrier for a number of bursts from the middle of the grid,
+/// returning the number of bursts that caused an infection.
+pub fn count_infections(grid: &mut Grid, bursts: usize, part2: bool) -> usize {
+    count_infections_traced(grid, bursts, part2, &mut NoTrace)
+}
+
+/// Like `count_infections`, passing each burst to the tracer as a `burst`
+/// event with the node the carrier was on and what the node became.
+pub fn count_infections_traced(
+    grid: &mut Grid,
+    bursts: usize,
+    part2: bool,
+    tracer: &mut dyn Tracer,
+) -> usize {
+    let mut position = Point::ORIGIN;
+    let mut direction = Direction::Up;
+    let mut result = 0;
+    for burst in 1..=bursts {
+        let node = position;
+        if tick(grid, &mut position, &mut direction, part2) {
+            result += 1;
+        }
+        if tracer.enabled() {
+            let state = match grid.get(node) {
+                None => "clean",
+                Some(Flag::Weakened) => "weakened",
+                Some(Flag::Infected) => "infected",
+                Some(Flag::Flagged) => "flagged",
+            };
+            tracer.event(
+                Event::new("burst")
+                    .with("burst", burst)
+                    .with("x", node.x)
+                    .with("y", node.y)
+                    .with("node", state),
+            );
+        }
+    }
+    result
+}
+
+/// Day 22: Sporifica Virus.
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Grid, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Grid) -> Answer {
+        count_infections(&mut input.clone(), 10_000, false).into()
+    }
+
+    fn part2(input: &Grid) -> Answer {
+        count_infections(&mut input.clone(), 10_000_000, true).into()
+    }
+}
+
+impl Visualize for Day22 {
+    /// Runs the evolved virus carrier for the first 10,000 bursts, with the
+    /// carrier drawn as an arrow in the direction it's facing.
+    fn frames(input: &Grid) -> Box<dyn Iterator<Item = String> + '_> {
+        let mut grid = input.clone();
+        let mut position = Point::ORIGIN;
+        let mut direction = Direction::Up;
+        let mut infections = 0;
+        Box::new((0..=10_000).map(move |burst| {
+            if burst > 0 && tick(&mut grid, &mut position, &mut direction, true) {
+                infections += 1;
+            }
+            let window = render_window(position, 41, 21, |p| match grid.get(p) {
+                _ if p == position => highlight(match direction {
+                    Direction::Up => '^',
+                    Direction::Right => '>',
+                    Direction::Down => 'v',
+                    Direction::Left => '<',
+                }),
+                Some(Flag::Weakened) => "W".to_owned(),
+                Some(Flag::Infected) => "#".to_owned(),
+                Some(Flag::Flagged) => "F".to_owned(),
+                None => faint('.'),
+            });
+            format!("Burst {}, {} infections\n{}", burst, infections, window)
+        }))
+    }
+}
+
+impl Generate for Day22 {
+    const SIZE: usize = 25;
+
+    /// A map of `size` by `size` nodes, made odd to have a middle, with about
+    /// half of them infected.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size | 1;
+        (0..size)
+            .map(|_| (0..size).map(|_| *rng.choose(&['#', '.'])).collect())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl Trace for Day22 {
+    fn trace(input: &Grid, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        match part {
+            1 => count_infections_traced(&mut input.clone(), 10_000, false, tracer).into(),
+            _ => count_infections_traced(&mut input.clone(), 10_000_000, true, tracer).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use aoc_common::visualize::strip_styles;
+    use proptest::prelude::*;
+    const TEST_INPUT: &str = "..#
+#..
+...";
+
+    #[test]
+    fn test_parse() {
+        let grid = parse(TEST_INPUT).unwrap();
+        assert_eq!(grid.len(), 2);
+        assert!(grid.contains(Point::new(1, -1)));
+        assert!(grid.contains(Point::new(-1, 0)));
+    }
+
+    #[test]
+    fn test_examples1_first_few_moves() {
+        let mut grid = parse(TEST_INPUT).unwrap();
+        let mut position = Point::ORIGIN;
+        let mut direction = Direction::Up;
+        assert!(!grid.contains(position));
+
+        // Move left and infect
+        assert!(tick(&mut grid, &mut position, &mut direction, false));
+        assert_eq!(direction, Direction::Left);
+        assert_eq!(position, Point::new(-1, 0));
+        assert_eq!(grid.len(), 3);
+        assert!(grid.contains(position));
+
+        // Move up and clean infection.
+        assert!(!tick(&mut grid, &mut position, &mut direction, false));
+        assert_eq!(direction, Direction::Up);
+        assert_eq!(position, Point::new(-1, -1));
+        assert_eq!(grid.len(), 2);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let mut grid = parse(TEST_INPUT).unwrap();
+        assert_eq!(count_infections_traced(&mut grid, 3, false, &mut events), 2);
+        let burst = |burst: usize, x: i32, y: i32, node: &str| {
+            Event::new("burst")
+                .with("burst", burst)
+                .with("x", x)
+                .with("y", y)
+                .with("node", node)
+        };
+        assert_eq!(
+            events,
+            [
+                burst(1, 0, 0, "infected"),
+                burst(2, -1, 0, "clean"),
+                burst(3, -1, -1, "infected")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_examples1_70_movex() {
+        let mut grid = parse(TEST_INPUT).unwrap();
+        let mut result = 0;
+        let mut position = Point::ORIGIN;
+        let mut direction = Direction::Up;
+        for _ in 0..70 {
+            if tick(&mut grid, &mut position, &mut direction, false) {
+                result += 1;
+            }
+        }
+        assert_eq!(direction, Direction::Up);
+        assert_eq!(result, 41);
+    }
+
+    #[test]
+    fn test_examples1_10_000_moves() {
+        let result = count_infections(&mut parse(TEST_INPUT).unwrap(), 10_000, false);
+        assert_eq!(result, 5587);
+    }
+
+    #[test]
+    fn test_result1() {
+        let result = count_infections(&mut parse(&get_input()).unwrap(), 10_000, false);
+        assert_eq!(result, 5406);
+    }
+
+    #[test]
+    fn test_examples2_first_few_moves() {
+        let mut grid = parse(TEST_INPUT).unwrap();
+        let mut position = Point::ORIGIN;
+        let mut direction = Direction::Up;
+        assert!(!grid.contains(position));
+
+        // Move left and infect
+        assert!(!tick(&mut grid, &mut position, &mut direction, true));
+        assert_eq!(direction, Direction::Left);
+        assert_eq!(position, Point::new(-1, 0));
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.get(Point::new(0, 0)).copied(), Some(Flag::Weakened));
+
+        // Move up and clean infection.
+        assert!(!tick(&mut grid, &mut position, &mut direction, true));
+        assert_eq!(direction, Direction::Up);
+        assert_eq!(position, Point::new(-1, -1));
+        assert_eq!(grid.get(Point::new(-1, 0)).copied(), Some(Flag::Flagged));
+        assert_eq!(grid.len(), 3);
+    }
+
+    #[test]
+    fn test_examples2() {
+        let result = count_infections(&mut parse(TEST_INPUT).unwrap(), 10_000_000, true);
+        assert_eq!(result, 2_511_944);
+    }
+
+    #[test]
+    fn test_result2() {
+        let result = count_infections(&mut parse(&get_input()).unwrap(), 10_000_000, true);
+        assert_eq!(result, 2511640);
+    }
+
+    #[test]
+    fn test_frames() {
+        let grid = parse(TEST_INPUT).unwrap();
+        let frames = Day22::frames(&grid)
+            .map(|frame| strip_styles(&frame))
+            .take(2)
+            .collect::<Vec<_>>();
+        assert!(frames[0].starts_with("Burst 0, 0 infections\n"));
+        let rows = frames[0].lines().skip(1).collect::<Vec<_>>();
+        assert_eq!(rows.len(), 21);
+        assert_eq!(&rows[9][18..23], "...#.");
+        assert_eq!(&rows[10][18..23], ".#^..");
+        // The carrier weakened the node and moved left.
+        let rows = frames[1].lines().skip(1).collect::<Vec<_>>();
+        assert_eq!(&rows[10][18..23], "..<W.");
+    }
+
+    // Runs the virus carrier on a dense grid large enough to never walk off,
+    // with a number per state: clean, weakened, infected and flagged.
+    fn naive_infections(map: &[Vec<bool>], bursts: usize, evolved: bool) -> usize {
+        let size = map.len() + 2 * bursts + 2;
+        let offset = bursts + 1;
+        let mut grid = vec![vec![0u8; size]; size];
+        for (y, row) in map.iter().enumerate() {
+            for (x, &infected) in row.iter().enumerate() {
+                if infected {
+                    grid[y + offset][x + offset] = 2;
+                }
+            }
+        }
+        let middle = offset + map.len() / 2;
+        let (mut x, mut y, mut dx, mut dy) = (middle as i32, middle as i32, 0, -1);
+        let mut infections = 0;
+        for _ in 0..bursts {
+            let node = &mut grid[y as usize][x as usize];
+            (dx, dy) = match *node {
+                0 => (dy, -dx),
+                1 => (dx, dy),
+                2 => (-dy, dx),
+                _ => (-dx, -dy),
+            };
+            *node = match (*node, evolved) {
+                (0, false) => 2,
+                (2, false) => 0,
+                (state, _) => (state + 1) % 4,
+            };
+            if *node == 2 {
+                infections += 1;
+            }
+            x += dx;
+            y += dy;
+        }
+        infections
+    }
+
+    fn map_strategy() -> impl Strategy<Value = Vec<Vec<bool>>> {
+        (0usize..5).prop_flat_map(|half| {
+            let size = 2 * half + 1;
+            prop::collection::vec(prop::collection::vec(any::<bool>(), size), size)
+        })
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day22>(22, 24);
+        assert_eq!(input.lines().count(), 25);
+        let grid = parse(&input).unwrap();
+        assert!(grid
+            .iter()
+            .all(|(point, _)| point.x.abs() <= 12 && point.y.abs() <= 12));
+        assert!(count_infections(&mut grid.clone(), 1000, false) > 0);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_naive(map in map_strategy(), bursts in 0usize..200) {
+            let input = map
+                .iter()
+                .map(|row| row.iter().map(|&e| if e { '#' } else { '.' }).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n");
+            let grid = parse(&input).unwrap();
+            for evolved in [false, true] {
+                prop_assert_eq!(
+                    count_infections(&mut grid.clone(), bursts, evolved),
+                    naive_infections(&map, bursts, evolved)
+                );
+            }
+        }
+    }
+}
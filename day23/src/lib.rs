@@ -0,0 +1,518 @@
+//! Day 23: Coprocessor Conflagration.
+
+use aoc_common::parse::Line;
+use aoc_common::trace::NoTrace;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer};
+use vm::{Flow, Machine, Operand, Program, Register, RegisterFile};
+
+/// The registers `a` to `h`.
+pub type Registers = RegisterFile<i64>;
+
+/// An instruction of the coprocessor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Set(Register, Operand<i64>),
+    Sub(Register, Operand<i64>),
+    Mul(Register, Operand<i64>),
+    Jnz(Operand<i64>, Operand<i64>),
+}
+
+impl vm::Instruction for Instruction {
+    type Value = i64;
+    /// The number of times `mul` was executed.
+    type State = u64;
+
+    fn parse(line: &Line, registers: &mut Registers) -> Result<Instruction, ParseError> {
+        let name = line.text.split(' ').next().unwrap();
+        if !matches!(name, "set" | "sub" | "mul" | "jnz") {
+            return Err(line.error(name, "unknown instruction"));
+        }
+        let arguments = vm::split_arguments(line, name, 2)?;
+        let y = vm::operand(line, arguments[1], registers)?;
+        if name == "jnz" {
+            return Ok(Instruction::Jnz(
+                vm::operand(line, arguments[0], registers)?,
+                y,
+            ));
+        }
+        let x = vm::register(line, arguments[0], registers)?;
+        Ok(match name {
+            "set" => Instruction::Set(x, y),
+            "sub" => Instruction::Sub(x, y),
+            _ => Instruction::Mul(x, y),
+        })
+    }
+
+    fn execute(&self, registers: &mut Registers, muls: &mut u64) -> Flow {
+        match *self {
+            Instruction::Set(x, y) => registers[x] = y.get(registers),
+            Instruction::Sub(x, y) => registers[x] -= y.get(registers),
+            Instruction::Mul(x, y) => {
+                registers[x] *= y.get(registers);
+                *muls += 1;
+            }
+            Instruction::Jnz(x, y) => {
+                if x.get(registers) != 0 {
+                    return Flow::Jump(y.get(registers));
+                }
+            }
+        }
+        Flow::Next
+    }
+}
+
+/// Parses the program, with the registers `a` to `h`.
+pub fn parse(input: &str) -> Result<Program<Instruction>, ParseError> {
+    Program::parse(input, Registers::letters('h'))
+}
+
+/// Runs the instructions on the registers until the program jumps out,
+/// returning how many times `mul` was executed.
+pub fn execute(instructions: &[Instruction], registers: &mut Registers) -> u64 {
+    execute_traced(instructions, registers, &mut NoTrace)
+}
+
+/// Like `execute`, passing each instruction executed and each register it
+/// changed to the tracer.
+pub fn execute_traced(
+    instructions: &[Instruction],
+    registers: &mut Registers,
+    tracer: &mut dyn Tracer,
+) -> u64 {
+    let mut machine = Machine::new(instructions, registers.clone(), 0);
+    machine.run_traced(tracer);
+    *registers = machine.registers;
+    machine.state
+}
+
+/// The most instructions the setup before the main loop may take, which is
+/// only a handful in any real program.
+pub const SETUP_STEPS: usize = 1000;
+
+// The instruction as it appears in the program, to point errors at.
+fn text(program: &Program<Instruction>, instruction: &Instruction) -> String {
+    let registers = &program.registers;
+    let operand = |operand: &Operand<i64>| match *operand {
+        Operand::Register(register) => registers.name(register).to_owned(),
+        Operand::Value(value) => value.to_string(),
+    };
+    match instruction {
+        Instruction::Set(x, y) => format!("set {} {}", registers.name(*x), operand(y)),
+        Instruction::Sub(x, y) => format!("sub {} {}", registers.name(*x), operand(y)),
+        Instruction::Mul(x, y) => format!("mul {} {}", registers.name(*x), operand(y)),
+        Instruction::Jnz(x, y) => format!("jnz {} {}", operand(x), operand(y)),
+    }
+}
+
+/// Finds the range of numbers the program checks when run with `a` set to 1,
+/// returning the start, end and step of the range. The setup before the main
+/// loop (everything until `f` is first set) is run for up to `SETUP_STEPS`
+/// instructions, the step is read from the last `sub b` instruction. Fails
+/// unless the range counts up from above 2 and ends on a step, as the loop
+/// would never finish otherwise, pointing at the instruction to blame.
+pub fn part2_range(program: &Program<Instruction>) -> Result<(i64, i64, i64), ParseError> {
+    let register = |name| program.registers.find(name).unwrap();
+    let (b, c, f) = (register("b"), register("c"), register("f"));
+    let error = |i: usize, message: &str| {
+        ParseError::new(i + 1, 1, text(program, &program.instructions[i]), message)
+    };
+    let setup = program
+        .instructions
+        .iter()
+        .position(|&e| matches!(e, Instruction::Set(x, _) if x == f))
+        .unwrap_or(program.instructions.len());
+    let mut registers = program.registers.clone();
+    registers[register("a")] = 1;
+    // The instruction that last changed each register, to blame for its value.
+    let mut changed = vec![None; registers.len()];
+    let mut pc = 0i64;
+    let mut steps = 0;
+    while let Some(i) = usize::try_from(pc).ok().filter(|&i| i < setup) {
+        if steps == SETUP_STEPS {
+            let message = format!("the setup doesn't finish in {} steps", SETUP_STEPS);
+            return Err(error(i, &message));
+        }
+        steps += 1;
+        let overflow = || error(i, "overflows");
+        match program.instructions[i] {
+            Instruction::Set(x, y) => registers[x] = y.get(&registers),
+            Instruction::Sub(x, y) => {
+                registers[x] = registers[x]
+                    .checked_sub(y.get(&registers))
+                    .ok_or_else(overflow)?
+            }
+            Instruction::Mul(x, y) => {
+                registers[x] = registers[x]
+                    .checked_mul(y.get(&registers))
+                    .ok_or_else(overflow)?
+            }
+            Instruction::Jnz(x, y) => {
+                if x.get(&registers) != 0 {
+                    pc = pc.checked_add(y.get(&registers)).ok_or_else(overflow)?;
+                    continue;
+                }
+            }
+        }
+        if let Instruction::Set(x, _) | Instruction::Sub(x, _) | Instruction::Mul(x, _) =
+            program.instructions[i]
+        {
+            changed[x.0] = Some(i);
+        }
+        pc += 1;
+    }
+    let (line, step) = program
+        .instructions
+        .iter()
+        .enumerate()
+        .rev()
+        .find_map(|(i, e)| match *e {
+            Instruction::Sub(x, Operand::Value(step)) if x == b => Some((i, -step)),
+            _ => None,
+        })
+        .ok_or_else(|| ParseError::new(1, 1, "", "missing `sub b` stepping through the range"))?;
+    if step <= 0 {
+        return Err(error(line, "the step must be positive"));
+    }
+    // Blame the line setting the register, or the start of the program if
+    // nothing did.
+    let blame = |register: Register, message: &str| match changed[register.0] {
+        Some(i) => error(i, message),
+        None => ParseError::new(1, 1, "", message),
+    };
+    let (start, end) = (registers[b], registers[c]);
+    if start < 3 {
+        let message = format!("the range must start above 2, not {}", start);
+        return Err(blame(b, &message));
+    }
+    if end < start || (end - start) % step != 0 {
+        let message = format!(
+            "the range from {} to {} must end on a step of {}",
+            start, end, step
+        );
+        return Err(blame(c, &message));
+    }
+    Ok((start, end, step))
+}
+
+/// Counts the numbers from `b` to `c`, stepping by `step`, that aren't prime.
+/// This is what the program computes when `a` is 1, just a lot faster.
+pub fn count_composites(b: i64, c: i64, step: i64) -> i64 {
+    count_composites_traced(b, c, step, &mut NoTrace)
+}
+
+/// Same as `count_composites`, reporting each number checked and the count
+/// of composites so far.
+pub fn count_composites_traced(b: i64, c: i64, step: i64, tracer: &mut dyn Tracer) -> i64 {
+    // The assembly translated into code, with all the useless instructions and
+    // registers remove, to make it run really really fast.
+    let mut b = b;
+    let mut h = 0;
+    loop {
+        let mut f = 1;
+        let mut d = 2;
+        // e and g is never used for anything, so skip it.
+        loop {
+            if b % d == 0 {
+                f = 0;
+            }
+            d += 1;
+            if d != b {
+                continue;
+            }
+            if f == 0 {
+                h += 1;
+            }
+            if tracer.enabled() {
+                tracer.event(
+                    Event::new("number")
+                        .with("number", b)
+                        .with("composite", u8::from(f == 0))
+                        .with("count", h),
+                );
+            }
+            if b == c {
+                return h;
+            }
+            b += step;
+            break;
+        }
+    }
+}
+
+/// Day 23: Coprocessor Conflagration.
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input = Program<Instruction>;
+
+    fn parse(input: &str) -> Result<Program<Instruction>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Program<Instruction>) -> Answer {
+        execute(&input.instructions, &mut input.registers.clone()).into()
+    }
+
+    /// No answer when the program doesn't check a range of numbers the way
+    /// `part2_range` expects.
+    fn part2(input: &Program<Instruction>) -> Answer {
+        part2_range(input).map_or(Answer::None, |(b, c, step)| {
+            count_composites(b, c, step).into()
+        })
+    }
+}
+
+impl Generate for Day23 {
+    const SIZE: usize = 1001;
+
+    /// The usual program, counting the composites among `size` numbers
+    /// from a random start.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        format!(
+            "set b {}
+set c b
+jnz a 2
+jnz 1 5
+mul b 100
+sub b -100000
+set c b
+sub c -{}
+set f 1
+set d 2
+set e 2
+set g d
+mul g e
+sub g b
+jnz g 2
+set f 0
+sub e -1
+set g e
+sub g b
+jnz g -8
+sub d -1
+set g d
+sub g b
+jnz g -13
+jnz f 2
+sub h -1
+set g b
+sub g c
+jnz g 2
+jnz 1 3
+sub b -17
+jnz 1 -23",
+            rng.range(57..=99),
+            17 * (size.max(1) - 1)
+        )
+    }
+}
+
+impl Trace for Day23 {
+    /// Part 1 reports the steps of the program, part 2 each number checked.
+    fn trace(input: &Program<Instruction>, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        match part {
+            1 => execute_traced(&input.instructions, &mut input.registers.clone(), tracer).into(),
+            _ => part2_range(input).map_or(Answer::None, |(b, c, step)| {
+                count_composites_traced(b, c, step, tracer).into()
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_result1() {
+        let program = parse(&get_input()).unwrap();
+        let mut registers = program.registers.clone();
+        assert_eq!(execute(&program.instructions, &mut registers), 8281);
+    }
+
+    #[test]
+    fn test_part2_range() {
+        assert_eq!(
+            part2_range(&parse(&get_input()).unwrap()),
+            Ok((109_300, 126_300, 17))
+        );
+    }
+
+    #[test]
+    fn test_part2_range_error() {
+        let error = |input| part2_range(&parse(input).unwrap()).unwrap_err();
+        // These used to panic or loop forever instead.
+        assert_eq!(
+            error("set a 1"),
+            ParseError::new(1, 1, "", "missing `sub b` stepping through the range")
+        );
+        assert_eq!(
+            error(""),
+            ParseError::new(1, 1, "", "missing `sub b` stepping through the range")
+        );
+        assert_eq!(
+            error("set b 10\nset c 20\nset f 1\nsub b 0"),
+            ParseError::new(4, 1, "sub b 0", "the step must be positive")
+        );
+        assert_eq!(
+            error("set b 10\nset c 11\nset f 1\nsub b -17"),
+            ParseError::new(
+                2,
+                1,
+                "set c 11",
+                "the range from 10 to 11 must end on a step of 17"
+            )
+        );
+        assert_eq!(
+            error("set b 44\nset c b\nsub c 34\nset f 1\nsub b -17"),
+            ParseError::new(
+                3,
+                1,
+                "sub c 34",
+                "the range from 44 to 10 must end on a step of 17"
+            )
+        );
+        assert_eq!(
+            error("set b 2\nset c 2\nset f 1\nsub b -1"),
+            ParseError::new(1, 1, "set b 2", "the range must start above 2, not 2")
+        );
+        assert_eq!(
+            error("set f 1\nsub b -1"),
+            ParseError::new(1, 1, "", "the range must start above 2, not 0")
+        );
+        // The setup isn't trusted to finish or to stay in range.
+        assert_eq!(
+            error("jnz 1 0\nset f 1\nsub b -17"),
+            ParseError::new(1, 1, "jnz 1 0", "the setup doesn't finish in 1000 steps")
+        );
+        assert_eq!(
+            error("set a 9223372036854775807\nmul a a\nset f 1\nsub b -17"),
+            ParseError::new(2, 1, "mul a a", "overflows")
+        );
+        assert_eq!(
+            part2_range(&parse("set b 10\nset c 44\nset f 1\nsub b -17").unwrap()),
+            Ok((10, 44, 17))
+        );
+        // Part 1 still runs whatever the shape of the program.
+        let program = Day23::parse("set b 2\nmul b b").unwrap();
+        assert_eq!(Day23::part1(&program), Answer::Number(1));
+        assert_eq!(Day23::part2(&program), Answer::None);
+    }
+
+    #[test]
+    fn test_result2() {
+        assert_eq!(count_composites(109_300, 126_300, 17), 911);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let program = parse("set a 2\nmul a 3\njnz 0 5\nsub a 1").unwrap();
+        assert_eq!(Day23::trace(&program, 1, &mut events), Answer::Number(1));
+        let step = |pc: i64| Event::new("step").with("pc", pc);
+        let set = |from: i64, to: i64| {
+            Event::new("set")
+                .with("register", "a")
+                .with("from", from)
+                .with("to", to)
+        };
+        assert_eq!(
+            events,
+            [
+                step(0),
+                set(0, 2),
+                step(1),
+                set(2, 6),
+                step(2),
+                step(3),
+                set(6, 5)
+            ]
+        );
+
+        let mut events = vec![];
+        let program = parse("set b 3\nset c 5\nset f 1\nsub b -1").unwrap();
+        assert_eq!(Day23::trace(&program, 2, &mut events), Answer::Number(1));
+        let number = |number: i64, composite: u8, count: i64| {
+            Event::new("number")
+                .with("number", number)
+                .with("composite", composite)
+                .with("count", count)
+        };
+        assert_eq!(events, [number(3, 0, 0), number(4, 1, 1), number(5, 0, 1)]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day23::parse("set b 67\nmod b 2").unwrap_err(),
+            ParseError::new(2, 1, "mod", "unknown instruction")
+        );
+        // Registers past `h` used to be indexed out of bounds.
+        assert_eq!(
+            Day23::parse("set i 1").unwrap_err(),
+            ParseError::new(1, 5, "i", "expected a register")
+        );
+        assert_eq!(
+            Day23::parse("jnz 1 x").unwrap_err(),
+            ParseError::new(1, 7, "x", "expected a register or a number")
+        );
+        assert_eq!(
+            Day23::parse("jnz 1").unwrap_err(),
+            ParseError::new(1, 1, "jnz 1", "expected two arguments")
+        );
+    }
+
+    fn is_prime(n: i64) -> bool {
+        n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+    }
+
+    #[test]
+    fn test_generate() {
+        let (b, c, step) = part2_range(&parse(&generate::<Day23>(23, 100)).unwrap()).unwrap();
+        assert_eq!(((c - b) / step, step), (99, 17));
+        assert!(count_composites(b, c, step) <= 100);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_composites_match_naive(b in 3i64..5000, step in 1i64..20, count in 0i64..50) {
+            // The program only ever checks numbers above 2.
+            let c = b + step * count;
+            let expected = (0..=count).filter(|i| !is_prime(b + step * i)).count() as i64;
+            prop_assert_eq!(count_composites(b, c, step), expected);
+        }
+
+        #[test]
+        fn prop_counts_mul(ops in prop::collection::vec((0usize..3, 0usize..8, -5i64..5), 0..30)) {
+            let mut expected = [0; 8];
+            let mut muls = 0;
+            let mut program = vec![];
+            for &(op, register, value) in &ops {
+                let name = (b'a' + register as u8) as char;
+                match op {
+                    0 => {
+                        program.push(format!("set {} {}", name, value));
+                        expected[register] = value;
+                    }
+                    1 => {
+                        program.push(format!("sub {} {}", name, value));
+                        expected[register] -= value;
+                    }
+                    _ => {
+                        program.push(format!("mul {} {}", name, value));
+                        expected[register] *= value;
+                        muls += 1;
+                    }
+                }
+            }
+            let program = parse(&program.join("\n")).unwrap();
+            let mut registers = program.registers.clone();
+            prop_assert_eq!(execute(&program.instructions, &mut registers), muls);
+            prop_assert_eq!(registers.values(), &expected[..]);
+        }
+    }
+}
@@ -0,0 +1,281 @@
+//! Day 24: Electromagnetic Moat.
+
+use aoc_common::parse::lines;
+use aoc_common::trace::NoTrace;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::BuildHasherDefault;
+
+/// A component with a port on each side.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Component {
+    left: u64,
+    right: u64,
+}
+
+/// The components left to build with. They are hashed without a random key,
+/// so they are tried in the same order on every run.
+pub type Components = HashSet<Component, BuildHasherDefault<DefaultHasher>>;
+
+/// Day 24: Electromagnetic Moat.
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input = Components;
+
+    fn parse(input: &str) -> Result<Components, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Components) -> Answer {
+        iter_components(0, &[], &mut input.clone(), false).0.into()
+    }
+
+    fn part2(input: &Components) -> Answer {
+        iter_components(0, &[], &mut input.clone(), true).0.into()
+    }
+}
+
+/// Parses the components, one per line like `0/2`.
+pub fn parse_input(input: &str) -> Result<Components, ParseError> {
+    let mut all = Components::default();
+    for line in lines(input).filter(|e| !e.text.is_empty()) {
+        let (left, right) = line.split_once("/")?;
+        all.insert(Component {
+            left: line.parse(left, "port")?,
+            right: line.parse(right, "port")?,
+        });
+    }
+    Ok(all)
+}
+
+/// The strength and length of the best bridge starting at port `start`,
+/// extending `path` with the remaining `components`. The best bridge is the
+/// strongest, or with `only_accept_longest` the strongest of the longest.
+pub fn iter_components(
+    start: u64,
+    path: &[Component],
+    components: &mut Components,
+    only_accept_longest: bool,
+) -> (u64, usize) {
+    iter_components_traced(start, path, components, only_accept_longest, &mut NoTrace)
+}
+
+/// Like `iter_components`, passing each component tried to the tracer as a
+/// `choose` event with the length of the bridge before it, the component and
+/// the port it leaves open.
+pub fn iter_components_traced(
+    start: u64,
+    path: &[Component],
+    components: &mut Components,
+    only_accept_longest: bool,
+    tracer: &mut (impl Tracer + ?Sized),
+) -> (u64, usize) {
+    let mut result = path.iter().map(|c| c.left + c.right).sum::<u64>();
+    let mut length = path.len();
+    for c in components.iter() {
+        if c.left == start || c.right == start {
+            let mut new_components = components.clone();
+            new_components.remove(c);
+            let mut new_path = path.to_vec();
+            new_path.push(*c);
+            let port = if c.left == start { c.right } else { c.left };
+            if tracer.enabled() {
+                tracer.event(
+                    Event::new("choose")
+                        .with("length", path.len())
+                        .with("component", format!("{}/{}", c.left, c.right))
+                        .with("port", port),
+                );
+            }
+            // Recurse, looking for the best result.
+            let (new_result, new_length) = iter_components_traced(
+                port,
+                &new_path,
+                &mut new_components,
+                only_accept_longest,
+                tracer,
+            );
+            if only_accept_longest {
+                // Part 2
+                if new_length > length {
+                    // Always use longer lengths, even if the result is lower.
+                    result = new_result;
+                    length = new_length;
+                } else if new_length == length && new_result >= result {
+                    // If the length is equal but the result is higher, use it.
+                    result = new_result;
+                    length = new_length;
+                }
+            } else if new_result > result {
+                // Part 1
+                // Result is king, ignore the length.
+                result = new_result;
+                length = new_length;
+            }
+        }
+    }
+    (result, length)
+}
+
+impl Generate for Day24 {
+    const SIZE: usize = 56;
+
+    /// `size` different components, with ports of up to 50 or as many as
+    /// needed for that many of them.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut ports = 50;
+        while (ports + 1) * (ports + 2) / 2 < size as i64 {
+            ports += 1;
+        }
+        let mut components = vec![];
+        let mut seen = HashSet::new();
+        while components.len() < size {
+            let (left, right) = (rng.range(0..=ports), rng.range(0..=ports));
+            if seen.insert((left.min(right), left.max(right))) {
+                components.push(format!("{}/{}", left, right));
+            }
+        }
+        components.join("\n")
+    }
+}
+
+impl Trace for Day24 {
+    fn trace(input: &Components, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        iter_components_traced(0, &[], &mut input.clone(), part == 2, tracer)
+            .0
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = "0/2
+2/2
+2/3
+3/4
+3/5
+0/1
+10/1
+9/10";
+
+    #[test]
+    fn test_examples1() {
+        let mut all = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(iter_components(0, &[], &mut all, false).0, 31);
+    }
+
+    #[test]
+    fn test_result1() {
+        let mut all = parse_input(&get_input()).unwrap();
+        assert_eq!(iter_components(0, &[], &mut all, false).0, 1868);
+    }
+
+    #[test]
+    fn test_examples2() {
+        let mut all = parse_input(TEST_INPUT).unwrap();
+        let result = iter_components(0, &[], &mut all, true);
+        assert_eq!(result.1, 4);
+        assert_eq!(result.0, 19);
+    }
+
+    #[test]
+    fn test_result2() {
+        let mut all = parse_input(&get_input()).unwrap();
+        let result = iter_components(0, &[], &mut all, true);
+        assert_eq!(result.1, 40); // Max length is 40
+        assert_eq!(result.0, 1841); // Max value of length 40
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_input("0/2\n2/two").unwrap_err(),
+            ParseError::new(2, 3, "two", "invalid port")
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let components = parse_input("0/1\n1/2").unwrap();
+        assert_eq!(Day24::trace(&components, 1, &mut events), Answer::Number(4));
+        let choose = |length: usize, component: &str, port: u64| {
+            Event::new("choose")
+                .with("length", length)
+                .with("component", component)
+                .with("port", port)
+        };
+        assert_eq!(events, [choose(0, "0/1", 1), choose(1, "1/2", 2)]);
+        // The components are tried in the same order every time.
+        let components = parse_input(TEST_INPUT).unwrap();
+        let mut again = vec![];
+        Day24::trace(&components, 2, &mut events);
+        Day24::trace(&components, 2, &mut again);
+        assert_eq!(events[2..], again);
+    }
+
+    // The strength and length of every bridge, by trying each unused matching
+    // component in turn.
+    fn naive_bridges(
+        port: u64,
+        components: &[(u64, u64)],
+        used: &mut Vec<bool>,
+        bridge: (u64, usize),
+        result: &mut Vec<(u64, usize)>,
+    ) {
+        result.push(bridge);
+        for (i, &(left, right)) in components.iter().enumerate() {
+            if used[i] || (left != port && right != port) {
+                continue;
+            }
+            used[i] = true;
+            let next = if left == port { right } else { left };
+            naive_bridges(
+                next,
+                components,
+                used,
+                (bridge.0 + left + right, bridge.1 + 1),
+                result,
+            );
+            used[i] = false;
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        let components = parse_input(&generate::<Day24>(24, 30)).unwrap();
+        assert_eq!(components.len(), 30);
+        let (strongest, _) = iter_components(0, &[], &mut components.clone(), false);
+        let (longest, _) = iter_components(0, &[], &mut components.clone(), true);
+        assert!(strongest >= longest);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_naive(components in prop::collection::hash_set((0u64..6, 0u64..6), 0..9)) {
+            let components = components.into_iter().collect::<Vec<_>>();
+            let input = components
+                .iter()
+                .map(|(left, right)| format!("{}/{}", left, right))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let mut bridges = vec![];
+            naive_bridges(0, &components, &mut vec![false; components.len()], (0, 0), &mut bridges);
+            let strongest = bridges.iter().map(|e| e.0).max().unwrap();
+            let longest = bridges.iter().max_by_key(|e| (e.1, e.0)).unwrap().0;
+            // A component and its mirror image are the same component.
+            let parsed = parse_input(&input).unwrap();
+            prop_assume!(parsed.len() == components.len());
+            prop_assert_eq!(Day24::part1(&parsed), Answer::Number(strongest as i64));
+            prop_assert_eq!(Day24::part2(&parsed), Answer::Number(longest as i64));
+        }
+    }
+}
@@ -0,0 +1,426 @@
+//! Day 25: The Halting Problem.
+
+use aoc_common::parse::{lines, Line};
+use aoc_common::trace::NoTrace;
+use aoc_common::visualize::highlight;
+use aoc_common::{Answer, Event, Generate, ParseError, Rng, Solution, Trace, Tracer, Visualize};
+use std::collections::{HashMap, HashSet};
+
+// Since the value can only be 1, use a hashset to tell us whether
+// the value is 1.
+type Tape = HashSet<i64>;
+
+/// What to do when reading a given value in a given state.
+#[derive(Debug)]
+pub struct Action {
+    write: bool,
+    offset: i64,
+    state: char,
+}
+
+/// A blueprint for the Turing machine.
+#[derive(Debug)]
+pub struct Blueprint {
+    start: char,
+    steps: usize,
+    // The actions for each state, when the current value is 0 and 1.
+    states: HashMap<char, [Action; 2]>,
+}
+
+// The lines of a blueprint, remembering the states referred to so they can be
+// checked once every state is known.
+struct Lines<'a, I> {
+    lines: I,
+    // The line number just past the end of the input.
+    end: usize,
+    references: Vec<(Line<'a>, &'a str, char)>,
+}
+
+impl<'a, I: Iterator<Item = Line<'a>>> Lines<'a, I> {
+    // Reads the next line, which must look like `prefix<value>suffix`,
+    // returning the line along with the value.
+    fn expect(&mut self, prefix: &str, suffix: &str) -> Result<(Line<'a>, &'a str), ParseError> {
+        let line = self.lines.next().ok_or_else(|| {
+            ParseError::new(self.end, 1, "", format!("missing `{}`", prefix.trim()))
+        })?;
+        let value = line
+            .text
+            .trim()
+            .strip_prefix(prefix)
+            .and_then(|e| e.strip_suffix(suffix))
+            .ok_or_else(|| line.invalid(format!("expected `{}_{}`", prefix, suffix)))?;
+        Ok((line, value))
+    }
+
+    // Reads a state, remembering it if it is referred to rather than defined.
+    fn state(&mut self, prefix: &str, suffix: &str, reference: bool) -> Result<char, ParseError> {
+        let (line, state) = self.expect(prefix, suffix)?;
+        let mut chars = state.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => {
+                if reference {
+                    self.references.push((line, state, c));
+                }
+                Ok(c)
+            }
+            _ => Err(line.error(state, "expected a single letter state")),
+        }
+    }
+
+    fn action(&mut self, value: &str) -> Result<Action, ParseError> {
+        let (line, current) = self.expect("If the current value is ", ":")?;
+        if current != value {
+            return Err(line.error(current, format!("expected {}", value)));
+        }
+        let (line, write) = self.expect("- Write the value ", ".")?;
+        let write = match write {
+            "0" => false,
+            "1" => true,
+            _ => return Err(line.error(write, "expected 0 or 1")),
+        };
+        let (line, direction) = self.expect("- Move one slot to the ", ".")?;
+        let offset = match direction {
+            "left" => -1,
+            "right" => 1,
+            _ => return Err(line.error(direction, "unknown direction")),
+        };
+        let state = self.state("- Continue with state ", ".", true)?;
+        Ok(Action {
+            write,
+            offset,
+            state,
+        })
+    }
+}
+
+/// Parses the blueprint.
+pub fn parse(input: &str) -> Result<Blueprint, ParseError> {
+    let mut lines = Lines {
+        lines: lines(input)
+            .filter(|e| !e.text.trim().is_empty())
+            .peekable(),
+        end: input.lines().count() + 1,
+        references: vec![],
+    };
+    let start = lines.state("Begin in state ", ".", true)?;
+    let (line, steps) = lines.expect("Perform a diagnostic checksum after ", " steps.")?;
+    let steps = line.parse(steps, "number of steps")?;
+    let mut states = HashMap::new();
+    while lines.lines.peek().is_some() {
+        let state = lines.state("In state ", ":", false)?;
+        let zero = lines.action("0")?;
+        let one = lines.action("1")?;
+        states.insert(state, [zero, one]);
+    }
+    // Make sure every state the machine can end up in is defined.
+    for (line, state, c) in lines.references {
+        if !states.contains_key(&c) {
+            return Err(line.error(state, "unknown state"));
+        }
+    }
+    Ok(Blueprint {
+        start,
+        steps,
+        states,
+    })
+}
+
+// Executes a single step of the blueprint, updating the tape, position and
+// state.
+fn step(blueprint: &Blueprint, tape: &mut Tape, position: &mut i64, state: &mut char) {
+    let action = &blueprint.states[state][tape.contains(position) as usize];
+    if action.write {
+        tape.insert(*position);
+    } else {
+        tape.remove(position);
+    }
+    *position += action.offset;
+    *state = action.state;
+}
+
+/// Executes a given blueprint, returns the number of enabled bits on the tape.
+pub fn execute(blueprint: &Blueprint) -> usize {
+    execute_traced(blueprint, &mut NoTrace)
+}
+
+/// Like `execute`, passing each step to the tracer as a `step` event with the
+/// state and position it ends in, and the checksum so far.
+pub fn execute_traced(blueprint: &Blueprint, tracer: &mut dyn Tracer) -> usize {
+    let mut tape = Tape::new();
+    let mut position = 0;
+    let mut state = blueprint.start;
+    for i in 1..=blueprint.steps {
+        step(blueprint, &mut tape, &mut position, &mut state);
+        if tracer.enabled() {
+            tracer.event(
+                Event::new("step")
+                    .with("step", i)
+                    .with("state", state.to_string())
+                    .with("position", position)
+                    .with("checksum", tape.len()),
+            );
+        }
+    }
+    tape.len()
+}
+
+/// Day 25: The Halting Problem.
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    type Input = Blueprint;
+
+    fn parse(input: &str) -> Result<Blueprint, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Blueprint) -> Answer {
+        execute(input).into()
+    }
+
+    fn part2(_input: &Blueprint) -> Answer {
+        Answer::None
+    }
+}
+
+impl Visualize for Day25 {
+    /// Runs the blueprint a step per frame, showing the tape around the
+    /// cursor.
+    fn frames(input: &Blueprint) -> Box<dyn Iterator<Item = String> + '_> {
+        let mut tape = Tape::new();
+        let mut position = 0;
+        let mut state = input.start;
+        Box::new((0..=input.steps).map(move |steps| {
+            if steps > 0 {
+                step(input, &mut tape, &mut position, &mut state);
+            }
+            let mut cells = String::new();
+            for slot in position - 20..=position + 20 {
+                let value = tape.contains(&slot) as u8;
+                if slot == position {
+                    cells += &highlight(value);
+                } else {
+                    cells += &value.to_string();
+                }
+                cells.push(' ');
+            }
+            format!(
+                "Step {}, state {}, checksum {}\n{}\n{:>41}\n",
+                steps,
+                state,
+                tape.len(),
+                cells.trim_end(),
+                '^'
+            )
+        }))
+    }
+}
+
+impl Generate for Day25 {
+    const SIZE: usize = 12425180;
+
+    /// A blueprint of 6 states, running for `size` steps.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let states = ['A', 'B', 'C', 'D', 'E', 'F'];
+        let mut blueprint = format!(
+            "Begin in state A.\nPerform a diagnostic checksum after {} steps.\n",
+            size
+        );
+        for state in states {
+            blueprint += &format!("\nIn state {}:\n", state);
+            for value in 0..2 {
+                blueprint += &format!(
+                    "  If the current value is {}:
+    - Write the value {}.
+    - Move one slot to the {}.
+    - Continue with state {}.
+",
+                    value,
+                    rng.below(2),
+                    rng.choose(&["left", "right"]),
+                    rng.choose(&states)
+                );
+            }
+        }
+        blueprint
+    }
+}
+
+impl Trace for Day25 {
+    /// There's no second part, so it has no steps.
+    fn trace(input: &Blueprint, part: u8, tracer: &mut dyn Tracer) -> Answer {
+        match part {
+            1 => execute_traced(input, tracer).into(),
+            _ => Day25::part2(input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate::generate;
+    use aoc_common::get_input;
+    use aoc_common::visualize::strip_styles;
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = "Begin in state A.
+Perform a diagnostic checksum after 6 steps.
+
+In state A:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state B.
+  If the current value is 1:
+    - Write the value 0.
+    - Move one slot to the left.
+    - Continue with state B.
+
+In state B:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the left.
+    - Continue with state A.
+  If the current value is 1:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state A.
+";
+
+    #[test]
+    fn test_parse() {
+        let blueprint = parse(TEST_INPUT).unwrap();
+        assert_eq!(blueprint.start, 'A');
+        assert_eq!(blueprint.steps, 6);
+        assert_eq!(blueprint.states.len(), 2);
+        let action = &blueprint.states[&'A'][1];
+        assert!(!action.write);
+        assert_eq!(action.offset, -1);
+        assert_eq!(action.state, 'B');
+    }
+
+    #[test]
+    fn test_examples1() {
+        assert_eq!(execute(&parse(TEST_INPUT).unwrap()), 3);
+    }
+
+    #[test]
+    fn test_result1() {
+        assert_eq!(execute(&parse(&get_input()).unwrap()), 3099);
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut events = vec![];
+        let blueprint = parse(TEST_INPUT).unwrap();
+        assert_eq!(Day25::trace(&blueprint, 1, &mut events), Answer::Number(3));
+        assert_eq!(events.len(), 6);
+        assert_eq!(
+            events[0],
+            Event::new("step")
+                .with("step", 1)
+                .with("state", "B")
+                .with("position", 1)
+                .with("checksum", 1)
+        );
+    }
+
+    #[test]
+    fn test_frames() {
+        let blueprint = parse(TEST_INPUT).unwrap();
+        let frames = Day25::frames(&blueprint)
+            .map(|frame| strip_styles(&frame))
+            .collect::<Vec<_>>();
+        assert_eq!(frames.len(), 7);
+        assert!(frames[0].starts_with("Step 0, state A, checksum 0\n"));
+        assert!(frames[6].starts_with("Step 6, state A, checksum 3\n"));
+        // The cursor moved right, past the 1 just written.
+        let rows = frames[1].lines().collect::<Vec<_>>();
+        assert_eq!(rows[0], "Step 1, state B, checksum 1");
+        assert_eq!(&rows[1][36..44], "0 1 0 0 ");
+        assert_eq!(rows[2], format!("{}^", " ".repeat(40)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = TEST_INPUT.replace("slot to the left", "slot to the up");
+        assert_eq!(
+            parse(&input).unwrap_err(),
+            ParseError::new(11, 28, "up", "unknown direction")
+        );
+        let input = TEST_INPUT.replace("Continue with state B", "Continue with state C");
+        assert_eq!(
+            parse(&input).unwrap_err(),
+            ParseError::new(8, 27, "C", "unknown state")
+        );
+        assert_eq!(
+            parse("Begin in state A.").unwrap_err(),
+            ParseError::new(2, 1, "", "missing `Perform a diagnostic checksum after`")
+        );
+    }
+
+    type Rule = (bool, bool, usize);
+
+    fn blueprint(start: usize, steps: usize, states: &[(Rule, Rule)]) -> String {
+        let name = |state: usize| (b'A' + (state % states.len()) as u8) as char;
+        let mut result = format!(
+            "Begin in state {}.\nPerform a diagnostic checksum after {} steps.\n",
+            name(start),
+            steps
+        );
+        for (state, rules) in states.iter().enumerate() {
+            result += &format!("\nIn state {}:\n", name(state));
+            for (value, &(write, right, next)) in [rules.0, rules.1].iter().enumerate() {
+                result += &format!(
+                    "  If the current value is {}:\n    - Write the value {}.\n    \
+                     - Move one slot to the {}.\n    - Continue with state {}.\n",
+                    value,
+                    write as u8,
+                    if right { "right" } else { "left" },
+                    name(next)
+                );
+            }
+        }
+        result
+    }
+
+    fn rule_strategy() -> impl Strategy<Value = Rule> {
+        (any::<bool>(), any::<bool>(), 0usize..6)
+    }
+
+    // Runs the machine on a tape long enough to never walk off.
+    fn naive_execute(start: usize, steps: usize, states: &[(Rule, Rule)]) -> usize {
+        let mut tape = vec![false; 2 * steps + 1];
+        let (mut position, mut state) = (steps, start % states.len());
+        for _ in 0..steps {
+            let rules = states[state];
+            let (write, right, next) = if tape[position] { rules.1 } else { rules.0 };
+            tape[position] = write;
+            position = if right { position + 1 } else { position - 1 };
+            state = next % states.len();
+        }
+        tape.iter().filter(|&&e| e).count()
+    }
+
+    #[test]
+    fn test_generate() {
+        let blueprint = parse(&generate::<Day25>(25, 1000)).unwrap();
+        assert_eq!((blueprint.start, blueprint.steps), ('A', 1000));
+        assert_eq!(blueprint.states.len(), 6);
+        assert!(execute(&blueprint) <= 1000);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_naive(
+            start in 0usize..6,
+            steps in 0usize..500,
+            states in prop::collection::vec((rule_strategy(), rule_strategy()), 1..6),
+        ) {
+            let parsed = parse(&blueprint(start, steps, &states)).unwrap();
+            prop_assert_eq!(execute(&parsed), naive_execute(start, steps, &states));
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Helpers shared by the fuzz targets.
+
+use aoc_common::Solution;
+
+/// Parses arbitrary bytes as the input of a day, returning the input if it is
+/// valid. Invalid input must be reported as an error, never panic.
+pub fn parse<S: Solution>(data: &[u8]) -> Option<S::Input> {
+    let input = std::str::from_utf8(data).ok()?;
+    S::parse(input).ok()
+}
+
+/// Parses the input like `parse`, and solves both parts if it is valid. Only
+/// for days whose parts are quick and always finish, whatever the input.
+pub fn solve<S: Solution>(data: &[u8]) {
+    if let Some(input) = parse::<S>(data) {
+        S::part1(&input);
+        S::part2(&input);
+    }
+}
+
+/// Parses the input like `parse`, and solves the first part if it is valid.
+/// For days whose second part can take too long on arbitrary input.
+pub fn solve_part1<S: Solution>(data: &[u8]) {
+    if let Some(input) = parse::<S>(data) {
+        S::part1(&input);
+    }
+}
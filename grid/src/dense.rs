@@ -0,0 +1,229 @@
+//! A grid holding every cell of a rectangle.
+
+use crate::Point;
+use aoc_common::parse::lines;
+use aoc_common::ParseError;
+use std::ops::{Index, IndexMut};
+
+/// A rectangle of cells, stored row by row. The top left cell is at the
+/// origin.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DenseGrid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> DenseGrid<T> {
+    /// A grid of `width` by `height` cells, all set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> DenseGrid<T> {
+        DenseGrid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses a grid with a cell for each character, a row per line. Lines
+    /// shorter than the longest one are padded with `fill`. `cell` converts
+    /// a character, or says what is wrong with it.
+    pub fn parse<F>(input: &str, fill: T, mut cell: F) -> Result<DenseGrid<T>, ParseError>
+    where
+        F: FnMut(char) -> Result<T, &'static str>,
+    {
+        let mut rows = vec![];
+        for line in lines(input) {
+            let mut row = vec![];
+            for (i, c) in line.text.char_indices() {
+                let value = cell(c)
+                    .map_err(|message| line.error(&line.text[i..i + c.len_utf8()], message))?;
+                row.push(value);
+            }
+            rows.push(row);
+        }
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut rows {
+            row.resize(width, fill.clone());
+        }
+        Ok(DenseGrid::from_rows(rows))
+    }
+}
+
+impl<T> DenseGrid<T> {
+    /// A grid of the given rows, which must all be as long.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> DenseGrid<T> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "the rows of a grid must be as long"
+        );
+        DenseGrid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the point is inside the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        self.offset(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(move |i| &mut self.cells[i])
+    }
+
+    /// The cells of row `y`.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// The rows of the grid, from the top.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// Every point of the grid along with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+    }
+
+    /// Draws the grid with a character per cell, ending each row with a
+    /// newline.
+    pub fn render<F: FnMut(&T) -> char>(&self, mut f: F) -> String {
+        let mut result = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            result.extend(row.iter().map(&mut f));
+            result.push('\n');
+        }
+        result
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        let (x, y) = (
+            usize::try_from(point.x).ok()?,
+            usize::try_from(point.y).ok()?,
+        );
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+}
+
+impl<T> Index<Point> for DenseGrid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside the grid", point),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for DenseGrid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        match self.offset(point) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{:?} is outside the grid", point),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn cell(c: char) -> Result<bool, &'static str> {
+        match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("expected `#` or `.`"),
+        }
+    }
+
+    fn render(grid: &DenseGrid<bool>) -> String {
+        grid.render(|&on| if on { '#' } else { '.' })
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = DenseGrid::parse("#.#\n.#\n", false, cell).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(render(&grid), "#.#\n.#.\n");
+        assert!(grid[Point::new(1, 1)]);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.row(1), [false, true, false]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            DenseGrid::parse("#.\n.é", false, cell).unwrap_err(),
+            ParseError::new(2, 2, "é", "expected `#` or `.`")
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let grid = DenseGrid::parse("", false, cell).unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(render(&grid), "");
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut grid = DenseGrid::new(2, 3, 0);
+        grid[Point::new(1, 2)] = 5;
+        *grid.get_mut(Point::new(0, 0)).unwrap() += 1;
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [&[1, 0], &[0, 0], &[0, 5]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the grid")]
+    fn test_index_outside() {
+        let _ = DenseGrid::new(2, 2, 0)[Point::new(2, 0)];
+    }
+
+    #[test]
+    #[should_panic(expected = "as long")]
+    fn test_ragged_rows() {
+        DenseGrid::from_rows(vec![vec![1, 2], vec![3]]);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_render_parse(rows in prop::collection::vec("[.#]{1,8}", 1..8)) {
+            let width = rows.iter().map(String::len).max().unwrap();
+            let grid = DenseGrid::parse(&rows.join("\n"), false, cell).unwrap();
+            prop_assert_eq!((grid.width(), grid.height()), (width, rows.len()));
+            let padded = rows.iter().map(|row| format!("{:.<1$}\n", row, width)).collect::<String>();
+            prop_assert_eq!(render(&grid), padded);
+            for (point, &on) in grid.iter() {
+                prop_assert!(grid.contains(point));
+                let c = rows[point.y as usize].as_bytes().get(point.x as usize);
+                prop_assert_eq!(on, c == Some(&b'#'));
+            }
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! Points, directions and grids for the days played out on a 2D grid.
+//!
+//! `y` grows downwards, like the lines of the input, so [`Direction::Up`]
+//! decreases it. Grids come in two kinds: a [`DenseGrid`] holds every cell
+//! of a rectangle, and a [`SparseGrid`] only the cells that were set, on an
+//! unbounded plane.
+//!
+//! ```
+//! use grid::{DenseGrid, Direction, Point};
+//!
+//! let grid = DenseGrid::parse("#.\n.#", '.', Ok).unwrap();
+//! let start = Point::new(0, 0);
+//! assert_eq!(grid[start.step(Direction::Right)], '.');
+//! assert_eq!(grid[start.step(Direction::Down).step(Direction::Right)], '#');
+//! assert_eq!(grid.render(|&c| c), "#.\n.#\n");
+//! ```
+
+mod dense;
+mod point;
+mod sparse;
+mod window;
+
+pub use dense::DenseGrid;
+pub use point::{Direction, Point};
+pub use sparse::SparseGrid;
+pub use window::render_window;
@@ -0,0 +1,175 @@
+//! Points on the grid and the directions to move between them.
+
+use std::ops::{Add, Sub};
+
+/// One of the four directions to move on the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The direction after turning 90 degrees counterclockwise.
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    /// The direction after turning 90 degrees clockwise.
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// The opposite direction.
+    pub fn reverse(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    /// The change in `x` and `y` of a step in the direction.
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+}
+
+/// A point on the grid.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    /// The point a single step away in `direction`.
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.delta()
+    }
+
+    /// The Manhattan distance between the points.
+    pub fn manhattan_distance(self, other: Point) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The 4 points next to this one, in the order of [`Direction::ALL`].
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+
+    /// The 8 points around this one, diagonals included, row by row.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| Point::new(dx, dy)))
+            .filter(|&delta| delta != Point::ORIGIN)
+            .map(move |delta| self + delta)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        assert_eq!(Point::ORIGIN.step(Direction::Up), Point::new(0, -1));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let point = Point::new(2, 5);
+        assert_eq!(
+            point.neighbours4().collect::<Vec<_>>(),
+            [
+                Point::new(2, 4),
+                Point::new(3, 5),
+                Point::new(2, 6),
+                Point::new(1, 5)
+            ]
+        );
+        let around = point.neighbours8().collect::<Vec<_>>();
+        assert_eq!(around.len(), 8);
+        assert_eq!(around[0], Point::new(1, 4));
+        assert!(point.neighbours4().all(|p| around.contains(&p)));
+        assert!(around.iter().all(|&p| p != point));
+    }
+
+    fn direction() -> impl Strategy<Value = Direction> {
+        prop::sample::select(Direction::ALL.to_vec())
+    }
+
+    proptest! {
+        #[test]
+        fn prop_turns(direction in direction()) {
+            prop_assert_eq!(direction.turn_left().turn_right(), direction);
+            prop_assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            prop_assert_eq!(direction.reverse().reverse(), direction);
+            let left = direction.turn_left().turn_left().turn_left();
+            prop_assert_eq!(left, direction.turn_right());
+        }
+
+        #[test]
+        fn prop_step_back(x in -100i32..100, y in -100i32..100, direction in direction()) {
+            let point = Point::new(x, y);
+            let next = point.step(direction);
+            prop_assert_eq!(next.step(direction.reverse()), point);
+            prop_assert_eq!(point.manhattan_distance(next), 1);
+            prop_assert!(point.neighbours4().any(|p| p == next));
+        }
+    }
+}
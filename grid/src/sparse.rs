@@ -0,0 +1,198 @@
+//! A grid holding only the cells that were set.
+
+use crate::Point;
+use aoc_common::parse::lines;
+use aoc_common::ParseError;
+use std::collections::hash_map::{self, HashMap};
+use std::iter::FromIterator;
+use std::ops::Index;
+
+/// Cells on an unbounded plane, of which only the ones set are stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Parses a grid with a cell for each character, a row per line. `cell`
+    /// converts a character, giving `None` to leave the cell unset, or says
+    /// what is wrong with it.
+    pub fn parse<F>(input: &str, mut cell: F) -> Result<SparseGrid<T>, ParseError>
+    where
+        F: FnMut(char) -> Result<Option<T>, &'static str>,
+    {
+        let mut grid = SparseGrid::new();
+        for (y, line) in lines(input).enumerate() {
+            for (x, (i, c)) in line.text.char_indices().enumerate() {
+                let value = cell(c)
+                    .map_err(|message| line.error(&line.text[i..i + c.len_utf8()], message))?;
+                if let Some(value) = value {
+                    grid.insert(Point::new(x as i32, y as i32), value);
+                }
+            }
+        }
+        Ok(grid)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    /// Sets the cell, returning what it was set to before.
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.cells.insert(point, value)
+    }
+
+    /// Unsets the cell, returning what it was set to.
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    /// Whether the cell is set.
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// The number of cells set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The cells set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle
+    /// holding every cell set, or `None` if there are none.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        }))
+    }
+
+    /// Draws the rectangle of [`bounds`](SparseGrid::bounds) with a
+    /// character per cell, `empty` for the cells unset, ending each row with
+    /// a newline.
+    pub fn render<F: FnMut(&T) -> char>(&self, empty: char, mut f: F) -> String {
+        let mut result = String::new();
+        if let Some((min, max)) = self.bounds() {
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    result.push(self.get(Point::new(x, y)).map_or(empty, &mut f));
+                }
+                result.push('\n');
+            }
+        }
+        result
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid::new()
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!("{:?} isn't set", point),
+        }
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> SparseGrid<T> {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> IntoIterator for SparseGrid<T> {
+    type Item = (Point, T);
+    type IntoIter = hash_map::IntoIter<Point, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn cell(c: char) -> Result<Option<char>, &'static str> {
+        match c {
+            '.' => Ok(None),
+            'a'..='z' => Ok(Some(c)),
+            _ => Err("expected a letter or `.`"),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = SparseGrid::parse("..a\nb..\n", cell).unwrap();
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.get(Point::new(2, 0)), Some(&'a'));
+        assert_eq!(grid[Point::new(0, 1)], 'b');
+        assert!(!grid.contains(Point::new(1, 1)));
+        assert_eq!(
+            SparseGrid::parse("a\n.A", cell).unwrap_err(),
+            ParseError::new(2, 2, "A", "expected a letter or `.`")
+        );
+    }
+
+    #[test]
+    fn test_bounds_render() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.render('.', |&c| c), "");
+        grid.insert(Point::new(-1, 2), 'x');
+        grid.insert(Point::new(1, 0), 'y');
+        assert_eq!(grid.bounds(), Some((Point::new(-1, 0), Point::new(1, 2))));
+        assert_eq!(grid.render('.', |&c| c), "..y\n...\nx..\n");
+        assert_eq!(grid.remove(Point::new(1, 0)), Some('y'));
+        assert_eq!(grid.render('.', |&c| c), "x\n");
+    }
+
+    proptest! {
+        #[test]
+        fn prop_render_parse(rows in prop::collection::vec("[.a-c]{1,8}", 1..8)) {
+            let grid = SparseGrid::parse(&rows.join("\n"), cell).unwrap();
+            let count = rows.iter().flat_map(|row| row.chars()).filter(|&c| c != '.').count();
+            prop_assert_eq!(grid.len(), count);
+            let again = grid.clone().into_iter().collect::<SparseGrid<_>>();
+            prop_assert_eq!(&again, &grid);
+            if let Some((min, _)) = grid.bounds() {
+                let moved = grid.iter().map(|(p, &c)| (p - min, c)).collect::<SparseGrid<_>>();
+                let reparsed = SparseGrid::parse(&moved.render('.', |&c| c), cell).unwrap();
+                prop_assert_eq!(reparsed, moved);
+            }
+        }
+    }
+}
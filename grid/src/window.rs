@@ -0,0 +1,40 @@
+//! Drawing part of an unbounded plane.
+
+use crate::Point;
+
+/// Draws the `width` by `height` cells centred on `centre`, the text of each
+/// cell given by `cell`, ending each row with a newline. Used to follow
+/// something moving around a grid too large to draw whole.
+pub fn render_window<F, S>(centre: Point, width: usize, height: usize, mut cell: F) -> String
+where
+    F: FnMut(Point) -> S,
+    S: AsRef<str>,
+{
+    let top_left = centre - Point::new(width as i32 / 2, height as i32 / 2);
+    let mut result = String::new();
+    for y in 0..height as i32 {
+        for x in 0..width as i32 {
+            result.push_str(cell(top_left + Point::new(x, y)).as_ref());
+        }
+        result.push('\n');
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_window() {
+        let rendered = render_window(Point::new(10, -3), 3, 2, |p| {
+            if p == Point::new(10, -3) {
+                "@"
+            } else {
+                "."
+            }
+        });
+        assert_eq!(rendered, "...\n.@.\n");
+        assert_eq!(render_window(Point::ORIGIN, 0, 0, |_| "x"), "");
+    }
+}
@@ -0,0 +1,253 @@
+//! The knot hash from day 10, which day 14 builds its disk from.
+//!
+//! A list of numbers is knotted by reversing a sublist for each of the
+//! lengths, over a number of rounds. The full hash knots the 256 numbers 0 to
+//! 255 using the bytes of the input followed by a suffix as the lengths, and
+//! condenses the result into a 128-bit [`Digest`].
+//!
+//! ```
+//! let digest = knot_hash::knot_hash(b"1,2,3");
+//! assert_eq!(digest.to_string(), "3efbe78a8d82f29979031a4aa0b16a9d");
+//! assert!(format!("{:b}", digest).starts_with("00111110"));
+//! ```
+
+use std::fmt;
+
+/// The lengths appended to the input of the full hash.
+pub const STANDARD_SUFFIX: [u8; 5] = [17, 31, 73, 47, 23];
+
+/// The number of rounds of the full hash.
+pub const ROUNDS: usize = 64;
+
+/// A list being knotted. The current position and the skip size carry over
+/// between rounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseHash {
+    list: Vec<u8>,
+    position: usize,
+    skip: usize,
+}
+
+impl SparseHash {
+    /// A list of the numbers from 0 to `size - 1`. There must be 1 to 256 of
+    /// them, so they fit in a byte.
+    pub fn new(size: usize) -> SparseHash {
+        assert!(
+            (1..=256).contains(&size),
+            "the list must hold 1 to 256 numbers, not {}",
+            size
+        );
+        SparseHash {
+            list: (0..size).map(|n| n as u8).collect(),
+            position: 0,
+            skip: 0,
+        }
+    }
+
+    /// Runs a single round, reversing the sublist of each length from the
+    /// current position, wrapping around the end of the list. The lengths
+    /// can't be longer than the list.
+    pub fn round(&mut self, lengths: &[usize]) {
+        let len = self.list.len();
+        for &length in lengths {
+            assert!(length <= len, "length {} is longer than the list", length);
+            for i in 0..length / 2 {
+                self.list.swap(
+                    (self.position + i) % len,
+                    (self.position + length - 1 - i) % len,
+                );
+            }
+            self.position = (self.position + length + self.skip) % len;
+            self.skip += 1;
+        }
+    }
+
+    /// Runs `rounds` rounds with the same lengths.
+    pub fn rounds(&mut self, lengths: &[usize], rounds: usize) {
+        for _ in 0..rounds {
+            self.round(lengths);
+        }
+    }
+
+    /// The list as knotted so far.
+    pub fn list(&self) -> &[u8] {
+        &self.list
+    }
+
+    /// Condenses the list into the dense hash, by XORing each block of 16
+    /// numbers together. The list must hold 256 numbers.
+    pub fn dense(&self) -> Digest {
+        assert_eq!(self.list.len(), 256, "only a list of 256 numbers is dense");
+        let mut digest = [0; 16];
+        for (byte, block) in digest.iter_mut().zip(self.list.chunks(16)) {
+            *byte = block.iter().fold(0, |result, n| result ^ n);
+        }
+        Digest(digest)
+    }
+}
+
+/// The 128-bit dense hash. Formats as 32 lowercase hex digits, or as 128
+/// binary digits with `{:b}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Digest(pub [u8; 16]);
+
+impl Digest {
+    /// The bits of the digest, the most significant bit of each byte first.
+    pub fn bits(&self) -> impl Iterator<Item = bool> + '_ {
+        self.0
+            .iter()
+            .flat_map(|byte| (0..8).rev().map(move |i| byte >> i & 1 == 1))
+    }
+}
+
+impl fmt::LowerHex for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Binary for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{:08b}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+/// The full knot hash of the bytes of `input`, followed by the standard
+/// suffix.
+pub fn knot_hash(input: &[u8]) -> Digest {
+    knot_hash_with_suffix(input, &STANDARD_SUFFIX)
+}
+
+/// The full knot hash of the bytes of `input` followed by `suffix`: 64 rounds
+/// over the 256 numbers, condensed into a digest.
+pub fn knot_hash_with_suffix(input: &[u8], suffix: &[u8]) -> Digest {
+    let lengths = input
+        .iter()
+        .chain(suffix)
+        .map(|&length| length as usize)
+        .collect::<Vec<_>>();
+    let mut sparse = SparseHash::new(256);
+    sparse.rounds(&lengths, ROUNDS);
+    sparse.dense()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_round() {
+        let mut sparse = SparseHash::new(5);
+        sparse.round(&[3, 4, 1, 5]);
+        assert_eq!(sparse.list(), [3, 4, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_vectors() {
+        let vectors = [
+            ("", "a2582a3a0e66e6e86e3812dcb672a272"),
+            ("AoC 2017", "33efeb34ea91902bb2f59c9920caa6cd"),
+            ("1,2,3", "3efbe78a8d82f29979031a4aa0b16a9d"),
+            ("1,2,4", "63960835bcdc130f0b66d7ff4f6a5a8e"),
+        ];
+        for (input, expected) in vectors {
+            assert_eq!(knot_hash(input.as_bytes()).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_binary() {
+        // The first row of the disk in the example of day 14.
+        let digest = knot_hash(b"flqrgnkx-0");
+        assert_eq!(&format!("{:b}", digest)[..8], "11010100");
+        assert_eq!(format!("{:b}", digest).len(), 128);
+    }
+
+    #[test]
+    fn test_formatting() {
+        let mut bytes = [0; 16];
+        bytes[..3].copy_from_slice(&[64, 7, 255]);
+        let digest = Digest(bytes);
+        assert_eq!(digest.to_string(), format!("4007ff{}", "0".repeat(26)));
+        assert_eq!(format!("{:x}", digest), digest.to_string());
+        assert_eq!(
+            format!("{:b}", digest),
+            format!("010000000000011111111111{}", "0".repeat(104))
+        );
+    }
+
+    #[test]
+    fn test_suffix() {
+        assert_eq!(
+            knot_hash_with_suffix(b"AoC 2017", &STANDARD_SUFFIX),
+            knot_hash(b"AoC 2017")
+        );
+        assert_eq!(
+            knot_hash_with_suffix(b"", b"AoC 2017"),
+            knot_hash_with_suffix(b"AoC 2017", b"")
+        );
+        assert_ne!(knot_hash_with_suffix(b"1,2,3", b""), knot_hash(b"1,2,3"));
+    }
+
+    #[test]
+    #[should_panic(expected = "longer than the list")]
+    fn test_length_too_long() {
+        SparseHash::new(5).round(&[6]);
+    }
+
+    // Reverses each sublist by rotating it to the front of the list first.
+    fn naive_hash(lengths: &[usize], runs: usize) -> Vec<u8> {
+        let mut list = (0..=255).collect::<VecDeque<u8>>();
+        let (mut position, mut skip) = (0, 0);
+        for _ in 0..runs {
+            for &length in lengths {
+                list.rotate_left(position);
+                list.make_contiguous()[..length].reverse();
+                list.rotate_right(position);
+                position = (position + length + skip) % 256;
+                skip += 1;
+            }
+        }
+        list.into_iter().collect()
+    }
+
+    proptest! {
+        #[test]
+        fn prop_rounds_match_naive(
+            lengths in prop::collection::vec(0usize..=256, 0..20),
+            rounds in 1usize..4,
+        ) {
+            let mut sparse = SparseHash::new(256);
+            sparse.rounds(&lengths, rounds);
+            prop_assert_eq!(sparse.list(), &naive_hash(&lengths, rounds)[..]);
+            let mut sorted = sparse.list().to_vec();
+            sorted.sort();
+            prop_assert_eq!(sorted, (0..=255).collect::<Vec<u8>>());
+        }
+
+        #[test]
+        fn prop_renderings_agree(input in prop::collection::vec(any::<u8>(), 0..20)) {
+            let digest = knot_hash(&input);
+            let hex = digest.to_string();
+            prop_assert_eq!(hex.len(), 32);
+            prop_assert!(hex.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f')));
+            let binary = digest.bits().map(|bit| if bit { '1' } else { '0' }).collect::<String>();
+            prop_assert_eq!(&binary, &format!("{:b}", digest));
+            prop_assert_eq!(u128::from_str_radix(&hex, 16), u128::from_str_radix(&binary, 2));
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! The register machine of days 8, 18 and 23.
+//!
+//! Each day has its own instruction set, a typed enum implementing
+//! [`Instruction`], which is parsed into a [`Program`] and run on a
+//! [`Machine`]. The machine holds a [`RegisterFile`], with the registers
+//! named by the program or known up front, and whatever state the instruction
+//! set needs besides them.
+//!
+//! ```
+//! use aoc_common::parse::Line;
+//! use aoc_common::ParseError;
+//! use vm::{Flow, Instruction, Operand, Program, Register, RegisterFile, Status};
+//!
+//! // Adds a value to a register, or jumps while a register isn't zero.
+//! enum Toy {
+//!     Add(Register, Operand<i64>),
+//!     Jnz(Operand<i64>, Operand<i64>),
+//! }
+//!
+//! impl Instruction for Toy {
+//!     type Value = i64;
+//!     type State = ();
+//!
+//!     fn parse(line: &Line, registers: &mut RegisterFile<i64>) -> Result<Toy, ParseError> {
+//!         let name = line.text.split(' ').next().unwrap();
+//!         let args = vm::split_arguments(line, name, 2)?;
+//!         let op = |i: usize| vm::operand(line, args[i], registers);
+//!         match name {
+//!             "add" => Ok(Toy::Add(vm::register(line, args[0], registers)?, op(1)?)),
+//!             "jnz" => Ok(Toy::Jnz(op(0)?, op(1)?)),
+//!             _ => Err(line.error(name, "unknown instruction")),
+//!         }
+//!     }
+//!
+//!     fn execute(&self, registers: &mut RegisterFile<i64>, _: &mut ()) -> Flow {
+//!         match *self {
+//!             Toy::Add(x, y) => registers[x] += y.get(registers),
+//!             Toy::Jnz(x, y) if x.get(registers) != 0 => return Flow::Jump(y.get(registers)),
+//!             Toy::Jnz(..) => {}
+//!         }
+//!         Flow::Next
+//!     }
+//! }
+//!
+//! let input = "add a 3\nadd b 2\nadd a -1\njnz a -2";
+//! let program = Program::<Toy>::parse(input, RegisterFile::letters('b')).unwrap();
+//! let mut machine = program.machine(());
+//! assert_eq!(machine.run(), Status::Halted);
+//! assert_eq!(machine.registers.get("b"), Some(6));
+//! ```
+
+mod machine;
+mod operand;
+mod registers;
+
+pub use machine::{Flow, Instruction, Machine, Program, Status};
+pub use operand::{operand, register, split_arguments, Operand};
+pub use registers::{Register, RegisterFile};
@@ -0,0 +1,283 @@
+//! Programs of typed instructions, and the machine running them.
+
+use crate::{Register, RegisterFile};
+use aoc_common::parse::{lines, Line};
+use aoc_common::trace::{Event, Tracer, Value};
+use aoc_common::ParseError;
+
+/// An instruction set: a typed instruction, parsed from a line of the program
+/// and executed against the registers and whatever else the machine has.
+pub trait Instruction: Sized {
+    /// The values held by the registers.
+    type Value: Copy + Default;
+    /// Everything the instructions work on besides the registers, like the
+    /// sound last played or the values waiting to be received.
+    type State;
+
+    /// Parses an instruction, looking up or adding the registers it names.
+    fn parse(line: &Line, registers: &mut RegisterFile<Self::Value>) -> Result<Self, ParseError>;
+
+    /// Executes the instruction, returning where to go next.
+    fn execute(&self, registers: &mut RegisterFile<Self::Value>, state: &mut Self::State) -> Flow;
+}
+
+/// Where to go after an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    /// On to the next instruction.
+    Next,
+    /// Jump by an offset from the instruction.
+    Jump(i64),
+    /// Stay on the instruction, waiting for something to change before it
+    /// can run.
+    Wait,
+    /// Stop the program.
+    Halt,
+}
+
+/// Why the machine stopped running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The program ended, by jumping out of it or halting.
+    Halted,
+    /// An instruction is waiting.
+    Waiting,
+}
+
+/// A parsed program, along with the registers it uses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program<I: Instruction> {
+    pub instructions: Vec<I>,
+    pub registers: RegisterFile<I::Value>,
+}
+
+impl<I: Instruction> Program<I> {
+    /// Parses an instruction per line, starting from the given registers.
+    /// Trailing whitespace is ignored.
+    pub fn parse(
+        input: &str,
+        mut registers: RegisterFile<I::Value>,
+    ) -> Result<Program<I>, ParseError> {
+        let instructions = lines(input.trim_end())
+            .map(|line| I::parse(&line, &mut registers))
+            .collect::<Result<_, _>>()?;
+        Ok(Program {
+            instructions,
+            registers,
+        })
+    }
+
+    /// A machine at the start of the program, with every register at the
+    /// default value.
+    pub fn machine(&self, state: I::State) -> Machine<'_, I> {
+        Machine::new(&self.instructions, self.registers.clone(), state)
+    }
+}
+
+/// Runs the instructions of a program, one at a time.
+#[derive(Debug, Clone)]
+pub struct Machine<'a, I: Instruction> {
+    instructions: &'a [I],
+    pc: i64,
+    halted: bool,
+    pub registers: RegisterFile<I::Value>,
+    pub state: I::State,
+}
+
+impl<'a, I: Instruction> Machine<'a, I> {
+    pub fn new(
+        instructions: &'a [I],
+        registers: RegisterFile<I::Value>,
+        state: I::State,
+    ) -> Machine<'a, I> {
+        Machine {
+            instructions,
+            pc: 0,
+            halted: false,
+            registers,
+            state,
+        }
+    }
+
+    /// The index of the next instruction.
+    pub fn pc(&self) -> i64 {
+        self.pc
+    }
+
+    /// Whether the program has ended.
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Executes a single instruction, returning the status if the machine
+    /// stopped.
+    pub fn step(&mut self) -> Option<Status> {
+        let instruction = match usize::try_from(self.pc)
+            .ok()
+            .and_then(|pc| self.instructions.get(pc))
+        {
+            Some(instruction) if !self.halted => instruction,
+            _ => {
+                self.halted = true;
+                return Some(Status::Halted);
+            }
+        };
+        match instruction.execute(&mut self.registers, &mut self.state) {
+            Flow::Next => self.pc += 1,
+            Flow::Jump(offset) => self.pc += offset,
+            Flow::Wait => return Some(Status::Waiting),
+            Flow::Halt => {
+                self.halted = true;
+                return Some(Status::Halted);
+            }
+        }
+        None
+    }
+
+    /// Executes instructions until the program ends or has to wait. A
+    /// waiting machine picks up from the same instruction when run again.
+    pub fn run(&mut self) -> Status {
+        loop {
+            if let Some(status) = self.step() {
+                return status;
+            }
+        }
+    }
+
+    /// Like `run`, passing a `step` event with the index of each instruction
+    /// executed to the tracer, followed by a `set` event for each register
+    /// the instruction changed. Without a tracer that wants the events, this
+    /// is as fast as `run`.
+    pub fn run_traced(&mut self, tracer: &mut (impl Tracer + ?Sized)) -> Status
+    where
+        I::Value: PartialEq + Into<Value>,
+    {
+        if !tracer.enabled() {
+            return self.run();
+        }
+        loop {
+            let pc = self.pc;
+            let executes =
+                !self.halted && usize::try_from(pc).is_ok_and(|pc| pc < self.instructions.len());
+            let before = self.registers.values().to_vec();
+            let status = self.step();
+            if executes {
+                tracer.event(Event::new("step").with("pc", pc));
+                let after = self.registers.values();
+                for (i, (&from, &to)) in before.iter().zip(after).enumerate() {
+                    if from != to {
+                        tracer.event(
+                            Event::new("set")
+                                .with("register", self.registers.name(Register(i)))
+                                .with("from", from)
+                                .with("to", to),
+                        );
+                    }
+                }
+            }
+            if let Some(status) = status {
+                return status;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Counts down a register, waiting on each step until the state allows it.
+    #[derive(Debug)]
+    enum Countdown {
+        Wait,
+        Dec(crate::Register),
+        Jump(i64),
+    }
+
+    impl Instruction for Countdown {
+        type Value = i64;
+        type State = u32;
+
+        fn parse(line: &Line, registers: &mut RegisterFile<i64>) -> Result<Countdown, ParseError> {
+            match line.text.split_once(' ') {
+                None if line.text == "wait" => Ok(Countdown::Wait),
+                Some(("dec", name)) => Ok(Countdown::Dec(registers.register(name))),
+                Some(("jmp", offset)) => Ok(Countdown::Jump(line.parse(offset, "offset")?)),
+                _ => Err(line.invalid("unknown instruction")),
+            }
+        }
+
+        fn execute(&self, registers: &mut RegisterFile<i64>, allowed: &mut u32) -> Flow {
+            match *self {
+                Countdown::Wait if *allowed == 0 => return Flow::Wait,
+                Countdown::Wait => *allowed -= 1,
+                Countdown::Dec(register) => registers[register] -= 1,
+                Countdown::Jump(offset) => return Flow::Jump(offset),
+            }
+            Flow::Next
+        }
+    }
+
+    #[test]
+    fn test_wait_and_resume() {
+        let program =
+            Program::<Countdown>::parse("wait\ndec x\njmp -2", RegisterFile::new()).unwrap();
+        assert_eq!(program.instructions.len(), 3);
+        let mut machine = program.machine(2);
+        assert_eq!(machine.run(), Status::Waiting);
+        assert_eq!((machine.pc(), machine.registers.get("x")), (0, Some(-2)));
+        machine.state = 1;
+        assert_eq!(machine.run(), Status::Waiting);
+        assert_eq!(machine.registers.get("x"), Some(-3));
+        assert!(!machine.is_halted());
+    }
+
+    #[test]
+    fn test_halt() {
+        let program = Program::<Countdown>::parse("dec y\njmp -5\n", RegisterFile::new()).unwrap();
+        let mut machine = program.machine(0);
+        assert_eq!(machine.step(), None);
+        assert_eq!(machine.run(), Status::Halted);
+        assert_eq!(machine.pc(), -4);
+        assert!(machine.is_halted());
+        assert_eq!(machine.run(), Status::Halted);
+        assert_eq!(machine.registers.get("y"), Some(-1));
+    }
+
+    #[test]
+    fn test_run_traced() {
+        let program =
+            Program::<Countdown>::parse("dec x\nwait\ndec y", RegisterFile::new()).unwrap();
+        let mut machine = program.machine(0);
+        let mut events = vec![];
+        assert_eq!(machine.run_traced(&mut events), Status::Waiting);
+        machine.state = 1;
+        assert_eq!(machine.run_traced(&mut events), Status::Halted);
+        let set = |register: &str, to: i64| {
+            Event::new("set")
+                .with("register", register)
+                .with("from", to + 1)
+                .with("to", to)
+        };
+        let step = |pc: i64| Event::new("step").with("pc", pc);
+        assert_eq!(
+            events,
+            [
+                step(0),
+                set("x", -1),
+                step(1),
+                step(1),
+                step(2),
+                set("y", -1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Program::<Countdown>::parse("dec a\njmp x", RegisterFile::new()).unwrap_err(),
+            ParseError::new(2, 5, "x", "invalid offset")
+        );
+    }
+}
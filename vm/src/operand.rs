@@ -0,0 +1,114 @@
+//! The arguments of instructions, and parsing them.
+
+use crate::{Register, RegisterFile};
+use aoc_common::parse::Line;
+use aoc_common::ParseError;
+use std::str::FromStr;
+
+/// An argument read from, either a register or a literal value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand<T> {
+    Register(Register),
+    Value(T),
+}
+
+impl<T: Copy> Operand<T> {
+    /// The value of the operand.
+    pub fn get(&self, registers: &RegisterFile<T>) -> T {
+        match *self {
+            Operand::Register(register) => registers[register],
+            Operand::Value(value) => value,
+        }
+    }
+}
+
+/// Splits an instruction like `set a 1` on single spaces into its name and
+/// arguments, checking that there are `count` of them.
+pub fn split_arguments<'a>(
+    line: &Line<'a>,
+    name: &'a str,
+    count: usize,
+) -> Result<Vec<&'a str>, ParseError> {
+    let arguments = line.text[name.len()..]
+        .strip_prefix(' ')
+        .map_or(vec![], |rest| rest.split(' ').collect());
+    if arguments.len() != count {
+        let message = match count {
+            1 => "expected one argument".to_owned(),
+            2 => "expected two arguments".to_owned(),
+            _ => format!("expected {} arguments", count),
+        };
+        return Err(line.invalid(message));
+    }
+    Ok(arguments)
+}
+
+/// Parses an argument written to, which must be one of the registers.
+pub fn register<T: Copy + Default>(
+    line: &Line,
+    token: &str,
+    registers: &RegisterFile<T>,
+) -> Result<Register, ParseError> {
+    registers
+        .find(token)
+        .ok_or_else(|| line.error(token, "expected a register"))
+}
+
+/// Parses an argument read from, either one of the registers or a number.
+pub fn operand<T: Copy + Default + FromStr>(
+    line: &Line,
+    token: &str,
+    registers: &RegisterFile<T>,
+) -> Result<Operand<T>, ParseError> {
+    match registers.find(token) {
+        Some(register) => Ok(Operand::Register(register)),
+        None => token
+            .parse()
+            .map(Operand::Value)
+            .map_err(|_| line.error(token, "expected a register or a number")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::parse::lines;
+
+    #[test]
+    fn test_arguments() {
+        let registers = RegisterFile::<i64>::letters('b');
+        let line = lines("jgz b -3").next().unwrap();
+        let arguments = split_arguments(&line, "jgz", 2).unwrap();
+        assert_eq!(
+            operand(&line, arguments[0], &registers),
+            Ok(Operand::Register(Register(1)))
+        );
+        assert_eq!(
+            operand(&line, arguments[1], &registers),
+            Ok(Operand::Value(-3))
+        );
+        assert_eq!(
+            register(&line, arguments[1], &registers).unwrap_err(),
+            ParseError::new(1, 7, "-3", "expected a register")
+        );
+        assert_eq!(
+            split_arguments(&line, "jgz", 1).unwrap_err(),
+            ParseError::new(1, 1, "jgz b -3", "expected one argument")
+        );
+    }
+
+    #[test]
+    fn test_bad_operand() {
+        let registers = RegisterFile::<i64>::letters('h');
+        let line = lines("set a  x").next().unwrap();
+        let arguments = split_arguments(&line, "set", 3).unwrap();
+        assert_eq!(
+            operand(&line, arguments[1], &registers).unwrap_err(),
+            ParseError::new(1, 7, "", "expected a register or a number")
+        );
+        assert_eq!(
+            operand(&line, arguments[2], &registers).unwrap_err(),
+            ParseError::new(1, 8, "x", "expected a register or a number")
+        );
+    }
+}
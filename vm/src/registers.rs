@@ -0,0 +1,132 @@
+//! The registers of a machine, by name or by index.
+
+use std::ops::{Index, IndexMut};
+
+/// A register of a [`RegisterFile`], by its index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Register(pub usize);
+
+/// The value of each register, which all start out at the default value.
+/// Registers are either known up front, like [`letters`](RegisterFile::letters),
+/// or added as the program names them, with [`register`](RegisterFile::register).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisterFile<T> {
+    names: Vec<String>,
+    values: Vec<T>,
+}
+
+impl<T: Copy + Default> RegisterFile<T> {
+    /// No registers, for programs that name their own.
+    pub fn new() -> RegisterFile<T> {
+        RegisterFile {
+            names: vec![],
+            values: vec![],
+        }
+    }
+
+    /// The registers from `a` to `last`, each named by a single letter.
+    pub fn letters(last: char) -> RegisterFile<T> {
+        let mut registers = RegisterFile::new();
+        for name in 'a'..=last {
+            registers.register(&name.to_string());
+        }
+        registers
+    }
+
+    /// The register named `name`, added if there isn't one yet.
+    pub fn register(&mut self, name: &str) -> Register {
+        self.find(name).unwrap_or_else(|| {
+            self.names.push(name.to_owned());
+            self.values.push(T::default());
+            Register(self.values.len() - 1)
+        })
+    }
+
+    /// The register named `name`, if there is one.
+    pub fn find(&self, name: &str) -> Option<Register> {
+        self.names.iter().position(|e| e == name).map(Register)
+    }
+
+    /// The name of the register.
+    pub fn name(&self, register: Register) -> &str {
+        &self.names[register.0]
+    }
+
+    /// The value of the register named `name`, if there is one.
+    pub fn get(&self, name: &str) -> Option<T> {
+        self.find(name).map(|register| self[register])
+    }
+
+    /// The number of registers.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The values of the registers, by index.
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// The name and value of each register, by index.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, T)> {
+        self.names
+            .iter()
+            .map(String::as_str)
+            .zip(self.values.iter().copied())
+    }
+}
+
+impl<T: Copy + Default> Default for RegisterFile<T> {
+    fn default() -> RegisterFile<T> {
+        RegisterFile::new()
+    }
+}
+
+impl<T> Index<Register> for RegisterFile<T> {
+    type Output = T;
+
+    fn index(&self, register: Register) -> &T {
+        &self.values[register.0]
+    }
+}
+
+impl<T> IndexMut<Register> for RegisterFile<T> {
+    fn index_mut(&mut self, register: Register) -> &mut T {
+        &mut self.values[register.0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_letters() {
+        let mut registers = RegisterFile::<i64>::letters('h');
+        assert_eq!(registers.len(), 8);
+        assert_eq!(registers.find("c"), Some(Register(2)));
+        assert_eq!(registers.find("i"), None);
+        registers[Register(7)] = 5;
+        assert_eq!(registers.get("h"), Some(5));
+        assert_eq!(registers.values(), [0, 0, 0, 0, 0, 0, 0, 5]);
+    }
+
+    #[test]
+    fn test_named() {
+        let mut registers = RegisterFile::<i32>::new();
+        assert!(registers.is_empty());
+        let foo = registers.register("foo");
+        let bar = registers.register("bar");
+        assert_eq!(registers.register("foo"), foo);
+        registers[bar] -= 3;
+        assert_eq!(registers.name(bar), "bar");
+        assert_eq!(
+            registers.iter().collect::<Vec<_>>(),
+            [("foo", 0), ("bar", -3)]
+        );
+    }
+}
@@ -0,0 +1,47 @@
+// Loads the solvers built for `wasm32-unknown-unknown` and wraps the exports
+// of `aoc_wasm.wasm` in a `solve(day, part, input)` function returning the
+// answer as a string, which throws an `Error` with the message if the input
+// can't be solved, or if the solver panics.
+//
+//     const bytes = await (await fetch("aoc_wasm.wasm")).arrayBuffer();
+//     const { solve } = await load(bytes);
+//     solve(1, 1, "1122"); // "3"
+
+export async function load(bytes) {
+  const { instance } = await WebAssembly.instantiate(bytes);
+  const exports = instance.exports;
+  const encoder = new TextEncoder();
+  const decoder = new TextDecoder();
+
+  function solve(day, part, input) {
+    const bytes = encoder.encode(input);
+    const ptr = exports.alloc(bytes.length);
+    new Uint8Array(exports.memory.buffer, ptr, bytes.length).set(bytes);
+    let status;
+    let trap;
+    try {
+      status = exports.solve(day, part, ptr, bytes.length);
+    } catch (e) {
+      // A panic traps, after the panic hook has left its message in the
+      // output.
+      if (!(e instanceof WebAssembly.RuntimeError)) {
+        throw e;
+      }
+      trap = e;
+    } finally {
+      exports.dealloc(ptr, bytes.length);
+    }
+    // The memory may have grown while solving, so it is looked up again.
+    const output = new Uint8Array(exports.memory.buffer, exports.output_ptr(), exports.output_len());
+    const text = decoder.decode(output);
+    if (trap !== undefined) {
+      throw new Error(text || `Day ${day} part ${part} crashed: ${trap.message}`);
+    }
+    if (status !== 0) {
+      throw new Error(text);
+    }
+    return text;
+  }
+
+  return { solve };
+}
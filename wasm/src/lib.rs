@@ -0,0 +1,227 @@
+//! The solvers of every day built for `wasm32-unknown-unknown`, to be called
+//! from JavaScript with the input pasted into a page.
+//!
+//! The module exports a plain C ABI, so it needs no bindings generator: the
+//! caller copies the input into memory from [`alloc`], calls [`solve`] and
+//! reads the answer, or the error, from [`output_ptr`] and [`output_len`].
+//! `solve.mjs` wraps that in a `solve(day, part, input)` function returning a
+//! string.
+
+use aoc_common::{Answer, ParseError, Solution};
+use std::sync::{Mutex, Once, PoisonError};
+
+// Parses the input and solves a single part.
+type Solver = fn(&str, u8) -> Result<Answer, ParseError>;
+
+fn solver<S: Solution>(input: &str, part: u8) -> Result<Answer, ParseError> {
+    Ok(S::solve(&S::parse(input)?, part))
+}
+
+const fn day<S: Solution>() -> (u8, Solver) {
+    (S::DAY, solver::<S>)
+}
+
+const DAYS: [(u8, Solver); 25] = [
+    day::<day01::Day01>(),
+    day::<day02::Day02>(),
+    day::<day03::Day03>(),
+    day::<day04::Day04>(),
+    day::<day05::Day05>(),
+    day::<day06::Day06>(),
+    day::<day07::Day07>(),
+    day::<day08::Day08>(),
+    day::<day09::Day09>(),
+    day::<day10::Day10>(),
+    day::<day11::Day11>(),
+    day::<day12::Day12>(),
+    day::<day13::Day13>(),
+    day::<day14::Day14>(),
+    day::<day15::Day15>(),
+    day::<day16::Day16>(),
+    day::<day17::Day17>(),
+    day::<day18::Day18>(),
+    day::<day19::Day19>(),
+    day::<day20::Day20>(),
+    day::<day21::Day21>(),
+    day::<day22::Day22>(),
+    day::<day23::Day23>(),
+    day::<day24::Day24>(),
+    day::<day25::Day25>(),
+];
+
+/// Solves a part of a day, returning the answer as it is printed, or why it
+/// couldn't be solved.
+pub fn solve_str(day: u32, part: u32, input: &str) -> Result<String, String> {
+    let solver = DAYS
+        .iter()
+        .find(|&&(d, _)| u32::from(d) == day)
+        .map(|&(_, solver)| solver)
+        .ok_or_else(|| format!("There is no day {}, expected 1 to 25", day))?;
+    if !(1..=2).contains(&part) {
+        return Err(format!("There is no part {}, expected 1 or 2", part));
+    }
+    solver(input, part as u8)
+        .map(|answer| answer.to_string())
+        .map_err(|e| format!("Unable to parse the input for day {}: {}", day, e))
+}
+
+// The answer or error of the last call to `solve`.
+static OUTPUT: Mutex<String> = Mutex::new(String::new());
+
+// The day and part of the last call to `solve`, for the panic hook.
+static SOLVING: Mutex<(u32, u32)> = Mutex::new((0, 0));
+
+// Leaves the message of a panic in the output. Panics abort in WebAssembly,
+// so `solve` traps instead of returning, and the caller only gets the message
+// from the output.
+fn report_panics() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let payload = info.payload();
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            let (day, part) = *SOLVING.lock().unwrap_or_else(PoisonError::into_inner);
+            // The output is only locked outside of solving, but a panic
+            // there mustn't deadlock.
+            if let Ok(mut output) = OUTPUT.try_lock() {
+                *output = format!("Day {} part {} panicked: {}", day, part, message);
+            }
+            previous(info);
+        }));
+    });
+}
+
+/// Allocates `len` bytes for the caller to copy the input into.
+#[no_mangle]
+pub extern "C" fn alloc(len: usize) -> *mut u8 {
+    let mut buffer = Vec::<u8>::with_capacity(len);
+    let ptr = buffer.as_mut_ptr();
+    std::mem::forget(buffer);
+    ptr
+}
+
+/// Frees memory from [`alloc`].
+///
+/// # Safety
+///
+/// `ptr` must come from `alloc(len)`, and not have been freed already.
+#[no_mangle]
+pub unsafe extern "C" fn dealloc(ptr: *mut u8, len: usize) {
+    drop(Vec::from_raw_parts(ptr, 0, len));
+}
+
+/// Solves a part of a day on the UTF-8 input at `input`, returning 0 if it
+/// was solved and 1 if not. Either way the answer or the error is left at
+/// [`output_ptr`] until the next call. If the solver panics, the module traps
+/// with the panic message left there instead.
+///
+/// # Safety
+///
+/// `input` must point to `len` bytes, like the memory from `alloc(len)`.
+#[no_mangle]
+pub unsafe extern "C" fn solve(day: u32, part: u32, input: *const u8, len: usize) -> u32 {
+    report_panics();
+    *SOLVING.lock().unwrap() = (day, part);
+    OUTPUT.lock().unwrap().clear();
+    let bytes = std::slice::from_raw_parts(input, len);
+    let result = match std::str::from_utf8(bytes) {
+        Ok(input) => solve_str(day, part, input),
+        Err(e) => Err(format!("The input is not valid UTF-8: {}", e)),
+    };
+    let (status, output) = match result {
+        Ok(answer) => (0, answer),
+        Err(e) => (1, e),
+    };
+    *OUTPUT.lock().unwrap() = output;
+    status
+}
+
+/// Where the output of the last call to [`solve`] starts.
+#[no_mangle]
+pub extern "C" fn output_ptr() -> *const u8 {
+    OUTPUT.lock().unwrap().as_ptr()
+}
+
+/// The length of the output of the last call to [`solve`], in bytes.
+#[no_mangle]
+pub extern "C" fn output_len() -> usize {
+    OUTPUT.lock().unwrap().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Calls the exports the way `solve.mjs` does, through raw memory.
+    fn call(day: u32, part: u32, input: &str) -> (u32, String) {
+        unsafe {
+            let ptr = alloc(input.len());
+            std::ptr::copy_nonoverlapping(input.as_ptr(), ptr, input.len());
+            let status = solve(day, part, ptr, input.len());
+            dealloc(ptr, input.len());
+            let output = std::slice::from_raw_parts(output_ptr(), output_len());
+            (status, String::from_utf8(output.to_vec()).unwrap())
+        }
+    }
+
+    #[test]
+    fn test_days_in_order() {
+        for (i, &(day, _)) in DAYS.iter().enumerate() {
+            assert_eq!(day as usize, i + 1);
+        }
+    }
+
+    #[test]
+    fn test_solve_str() {
+        assert_eq!(solve_str(1, 1, "1122"), Ok("3".to_owned()));
+        assert_eq!(solve_str(7, 1, "pbga (66)"), Ok("pbga".to_owned()));
+        assert_eq!(
+            solve_str(26, 1, ""),
+            Err("There is no day 26, expected 1 to 25".to_owned())
+        );
+        assert_eq!(
+            solve_str(1, 3, "1122"),
+            Err("There is no part 3, expected 1 or 2".to_owned())
+        );
+        assert_eq!(
+            solve_str(1, 1, "12a"),
+            Err(
+                "Unable to parse the input for day 1: line 1, column 3: not a digit: `a`"
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn test_exports() {
+        // The output is shared, so the calls are made one after the other.
+        assert_eq!(call(6, 2, "0 2 7 0"), (0, "4".to_owned()));
+        assert_eq!(
+            call(13, 1, "0: 3\n1: x"),
+            (
+                1,
+                "Unable to parse the input for day 13: line 2, column 4: invalid range: `x`"
+                    .to_owned()
+            )
+        );
+        let (status, output) = unsafe {
+            let bytes = [b'1', 0xff];
+            let status = solve(1, 1, bytes.as_ptr(), bytes.len());
+            let output = std::slice::from_raw_parts(output_ptr(), output_len());
+            (status, String::from_utf8(output.to_vec()).unwrap())
+        };
+        assert_eq!(status, 1);
+        assert!(output.starts_with("The input is not valid UTF-8"));
+        // A panic can't unwind out of `solve`, so it is raised right after
+        // the hook is set up by a call.
+        assert_eq!(call(7, 2, "pbga (66)"), (0, "-".to_owned()));
+        assert!(std::panic::catch_unwind(|| panic!("out of cheese")).is_err());
+        let output = unsafe { std::slice::from_raw_parts(output_ptr(), output_len()) };
+        assert_eq!(output, b"Day 7 part 2 panicked: out of cheese");
+    }
+}
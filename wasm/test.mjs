@@ -0,0 +1,51 @@
+// Solves the author's input of every day with the WebAssembly build, checking
+// the answers against `answers.toml`, without a browser:
+//
+//     cargo build --release -p aoc-wasm --target wasm32-unknown-unknown
+//     node wasm/test.mjs [days...]
+
+import { readFileSync } from "node:fs";
+import { fileURLToPath } from "node:url";
+import { load } from "./solve.mjs";
+
+const root = fileURLToPath(new URL("..", import.meta.url));
+const wasm = readFileSync(`${root}target/wasm32-unknown-unknown/release/aoc_wasm.wasm`);
+const { solve } = await load(wasm);
+
+// The answers file only has tables of numbers and strings, so a full TOML
+// parser isn't needed.
+const answers = new Map();
+let table;
+for (const line of readFileSync(`${root}answers.toml`, "utf8").split("\n")) {
+  const header = line.match(/^\[day(\d+)\]/);
+  const answer = line.match(/^part([12])\s*=\s*"?([^"]*)"?\s*$/);
+  if (header) {
+    table = Number(header[1]);
+  } else if (answer) {
+    answers.set(`${table}/${answer[1]}`, answer[2]);
+  }
+}
+
+const days = process.argv.length > 2
+  ? process.argv.slice(2).map(Number)
+  : Array.from({ length: 25 }, (_, i) => i + 1);
+let failed = 0;
+for (const day of days) {
+  const input = readFileSync(`${root}day${String(day).padStart(2, "0")}/input.txt`, "utf8");
+  for (const part of [1, 2]) {
+    const expected = answers.get(`${day}/${part}`);
+    let answer;
+    try {
+      answer = solve(day, part, input);
+    } catch (e) {
+      answer = `error: ${e.message}`;
+    }
+    const ok = expected === undefined || answer === expected;
+    failed += ok ? 0 : 1;
+    console.log(`day ${day} part ${part}: ${answer}${ok ? "" : ` (expected ${expected})`}`);
+  }
+}
+if (failed > 0) {
+  console.error(`${failed} answer(s) are wrong`);
+  process.exit(1);
+}